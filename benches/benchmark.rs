@@ -101,7 +101,8 @@ fn benchmark_2D_multi() {
     let mut rng = StdRng::seed_from_u64(RANDOM_SEED);
     // Generate interpolator data (same as hardcoded benchmark)
     let grid_data: Array1<f64> = (0..100).map(|x| x as f64).collect();
-    let values_data = Array2::random_using((100, 100), Uniform::new(0., 1.).unwrap(), &mut rng).into_dyn();
+    let values_data =
+        Array2::random_using((100, 100), Uniform::new(0., 1.).unwrap(), &mut rng).into_dyn();
     // Create an N-D interpolator with 100x100 data (10,000 points)
     let interp_2d_multi = InterpND::new(
         vec![grid_data.view(), grid_data.view()],
@@ -125,7 +126,8 @@ fn benchmark_3D() {
     let mut rng = StdRng::seed_from_u64(RANDOM_SEED);
     let grid_data: Array1<f64> = (0..100).map(|x| x as f64).collect();
     // Generate interpolator data (same as N-D benchmark) and arrange into `Vec<Vec<Vec<f64>>>`
-    let values_data = Array3::random_using((100, 100, 100), Uniform::new(0., 1.).unwrap(), &mut rng);
+    let values_data =
+        Array3::random_using((100, 100, 100), Uniform::new(0., 1.).unwrap(), &mut rng);
     // Create a 3-D interpolator with 100x100x100 data (1,000,000 points)
     let interp_3d = Interp3D::new(
         grid_data.view(),
@@ -153,6 +155,9 @@ fn benchmark_3D() {
 
 #[allow(non_snake_case)]
 /// 3-D interpolation (multilinear interpolator)
+///
+/// Uses identical data and points to [`benchmark_3D`], so the unrolled trilinear path
+/// can be compared directly against the generic N-D reduction.
 fn benchmark_3D_multi() {
     let mut rng = StdRng::seed_from_u64(RANDOM_SEED);
     // Generate interpolator data (same as hardcoded benchmark)
@@ -182,6 +187,31 @@ fn benchmark_3D_multi() {
     }
 }
 
+#[allow(non_snake_case)]
+/// 3-D interpolation along a smooth path (multilinear interpolator, reusing bracket indices)
+fn benchmark_3D_path(interp: &InterpNDViewed<&f64, strategy::Linear>, path: &Array2<f64>) {
+    interp.interpolate_path(black_box(&path.view())).unwrap();
+}
+
+#[allow(non_snake_case)]
+/// 3-D interpolation at the same points as [`benchmark_3D_path`], evaluated individually
+fn benchmark_3D_path_pointwise(
+    interp: &InterpNDViewed<&f64, strategy::Linear>,
+    path: &Array2<f64>,
+) {
+    for point in path.rows() {
+        interp
+            .interpolate(black_box(point.as_slice().unwrap()))
+            .unwrap();
+    }
+}
+
+#[allow(non_snake_case)]
+/// 3-D interpolation along a path of random points (no locality to reuse)
+fn benchmark_3D_path_random(interp: &InterpNDViewed<&f64, strategy::Linear>, points: &Array2<f64>) {
+    interp.interpolate_path(black_box(&points.view())).unwrap();
+}
+
 pub fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("0-D hardcoded", |b| b.iter(benchmark_0D));
     c.bench_function("0-D multilinear", |b| b.iter(benchmark_0D_multi));
@@ -191,7 +221,66 @@ pub fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("2-D multilinear", |b| b.iter(benchmark_2D_multi));
     c.bench_function("3-D hardcoded", |b| b.iter(benchmark_3D));
     c.bench_function("3-D multilinear", |b| b.iter(benchmark_3D_multi));
+
+    let mut rng = StdRng::seed_from_u64(RANDOM_SEED);
+    let grid_data: Array1<f64> = (0..100).map(|x| x as f64).collect();
+    let values_data =
+        Array3::random_using((100, 100, 100), Uniform::new(0., 1.).unwrap(), &mut rng).into_dyn();
+    let interp = InterpND::new(
+        vec![grid_data.view(), grid_data.view(), grid_data.view()],
+        values_data.view(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // Smooth trajectory of 1,000 points through the table
+    let smooth_path = Array2::from_shape_fn((1_000, 3), |(i, j)| {
+        let t = i as f64 / 999.;
+        49.5 + 45. * (t * std::f64::consts::TAU + j as f64).sin()
+    });
+    let random_points = Array2::from_shape_fn((1_000, 3), |_| rng.random::<f64>() * 99.);
+    c.bench_function("3-D smooth path", |b| {
+        b.iter(|| benchmark_3D_path(&interp, &smooth_path))
+    });
+    c.bench_function("3-D smooth path (pointwise)", |b| {
+        b.iter(|| benchmark_3D_path_pointwise(&interp, &smooth_path))
+    });
+    c.bench_function("3-D random path", |b| {
+        b.iter(|| benchmark_3D_path_random(&interp, &random_points))
+    });
+}
+
+#[cfg(feature = "rayon")]
+pub fn criterion_benchmark_eval_grid(c: &mut Criterion) {
+    let grid_data: Array1<f64> = (0..100).map(|x| x as f64).collect();
+    let values_data = Array2::from_shape_fn((100, 100), |(i, j)| ((i * j) as f64).sin());
+    let interp = Interp2D::new(
+        grid_data.view(),
+        grid_data.view(),
+        values_data.view(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // 1000x1000 output grid
+    let samples = Array1::linspace(0., 99., 1_000);
+    let mut group = c.benchmark_group("2-D eval grid 1000x1000");
+    group.sample_size(10);
+    group.bench_function("serial", |b| {
+        b.iter(|| interp.eval_grid(black_box(samples.view()), samples.view()))
+    });
+    group.bench_function("parallel", |b| {
+        b.iter(|| interp.par_eval_grid(black_box(samples.view()), samples.view()))
+    });
+    group.finish();
 }
 
+#[cfg(feature = "rayon")]
+criterion_group!(
+    benchmarks,
+    criterion_benchmark,
+    criterion_benchmark_eval_grid
+);
+#[cfg(not(feature = "rayon"))]
 criterion_group!(benchmarks, criterion_benchmark);
 criterion_main!(benchmarks);
@@ -22,10 +22,33 @@ pub enum ValidateError {
     Monotonicity(usize),
     #[error("supplied grid and values are not compatible shapes: dim {0}")]
     IncompatibleShapes(usize),
+    #[error("supplied values are invalid: {0}")]
+    InvalidValues(String),
     #[error("{0}")]
     Other(String),
 }
 
+impl ValidateError {
+    /// Is the error caused by the supplied data, e.g. unsorted or mismatched arrays,
+    /// and thus fixable by correcting the data?
+    ///
+    /// Errors in interpolator configuration, e.g. an inapplicable
+    /// [`Extrapolate`](crate::interpolator::Extrapolate) variant,
+    /// indicate a programming error and return `false`,
+    /// as does the catch-all [`ValidateError::Other`], whose cause is unknown.
+    pub fn is_recoverable(&self) -> bool {
+        match self {
+            ValidateError::EmptyGrid(_)
+            | ValidateError::Monotonicity(_)
+            | ValidateError::IncompatibleShapes(_)
+            | ValidateError::InvalidValues(_) => true,
+            #[allow(deprecated)]
+            ValidateError::StrategySelection(_) => false,
+            ValidateError::ExtrapolateSelection(_) | ValidateError::Other(_) => false,
+        }
+    }
+}
+
 impl fmt::Debug for ValidateError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -40,6 +63,10 @@ pub enum InterpolateError {
     ExtrapolateError(String),
     #[error("supplied point slice should have length {0} for {0}-D interpolation")]
     PointLength(usize),
+    #[error("strategy must be initialized before interpolating, see `Interpolator::validate`")]
+    Uninitialized,
+    #[error("interpolation was cancelled after {0} points")]
+    Cancelled(usize),
     #[error("{0}")]
     Other(String),
 }
@@ -49,3 +76,43 @@ impl fmt::Debug for InterpolateError {
         fmt::Display::fmt(self, f)
     }
 }
+
+/// Error in reading or writing `.npz` archives
+#[cfg(feature = "npz")]
+#[allow(missing_docs)]
+#[derive(Error)]
+pub enum NpzError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+    #[error("invalid `.npz` contents: {0}")]
+    Format(String),
+    #[error(transparent)]
+    Validate(#[from] ValidateError),
+}
+
+#[cfg(feature = "npz")]
+impl fmt::Debug for NpzError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_recoverable() {
+        assert!(ValidateError::EmptyGrid(0).is_recoverable());
+        assert!(ValidateError::Monotonicity(1).is_recoverable());
+        assert!(ValidateError::IncompatibleShapes(2).is_recoverable());
+        assert!(ValidateError::InvalidValues("sigma".into()).is_recoverable());
+        assert!(!ValidateError::ExtrapolateSelection("Clamp".into()).is_recoverable());
+        assert!(!ValidateError::Other("unknown".into()).is_recoverable());
+        #[allow(deprecated)]
+        let strategy_selection = ValidateError::StrategySelection("Linear");
+        assert!(!strategy_selection.is_recoverable());
+    }
+}
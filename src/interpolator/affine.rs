@@ -0,0 +1,244 @@
+//! 2-D interpolation on a regular lattice that is not aligned with the coordinate axes.
+
+use super::*;
+
+/// A 2-D interpolator over values sampled on a sheared or rotated regular lattice,
+/// e.g. an image or raster with an affine georeference.
+///
+/// Value `values[[i, j]]` lies at world coordinates `origin + i * basis[0] + j * basis[1]`.
+/// Points are mapped by the inverse transform into logical grid index space,
+/// where the wrapped [`Interp2D`] (with grids `0, 1, ..., n - 1`) is evaluated,
+/// so [`Extrapolate`] settings apply to the lattice rather than to a world-space rectangle.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::affine::Interp2DAffine;
+/// // Lattice with spacing 2 along x, and sheared along y
+/// let interp = Interp2DAffine::new(
+///     [1., 0.],
+///     [[2., 0.], [1., 1.]],
+///     array![[0., 1.], [2., 3.]],
+///     strategy::Linear,
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[2., 0.]).unwrap(), 1.);
+/// assert_eq!(interp.interpolate(&[3.5, 0.5]).unwrap(), 2.5);
+/// assert!(interp.interpolate(&[1., 1.]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interp2DAffine<T, S>
+where
+    T: Clone + PartialEq + Debug,
+    S: Strategy2D<OwnedRepr<T>> + Clone,
+{
+    /// Interpolator in logical grid index space.
+    pub interpolator: Interp2DOwned<T, S>,
+    /// World coordinates of `values[[0, 0]]`.
+    pub origin: [T; 2],
+    /// World-space step between consecutive values along each axis of `values`.
+    pub basis: [[T; 2]; 2],
+}
+
+impl<T, S> Interp2DAffine<T, S>
+where
+    T: Float + Debug,
+    S: Strategy2D<OwnedRepr<T>> + Clone,
+{
+    /// Construct and validate an affine interpolator.
+    ///
+    /// Errors if the basis vectors are linearly dependent, i.e. the transform is not invertible,
+    /// or if the wrapped [`Interp2D`] is invalid.
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn new(
+        origin: [T; 2],
+        basis: [[T; 2]; 2],
+        values: Array2<T>,
+        strategy: S,
+        extrapolate: Extrapolate<T>,
+    ) -> Result<Self, ValidateError> {
+        let (nx, ny) = values.dim();
+        let indices = |n: usize| Array1::range(T::zero(), T::from(n).unwrap(), T::one());
+        let interp = Self {
+            interpolator: Interp2D::new(indices(nx), indices(ny), values, strategy, extrapolate)?,
+            origin,
+            basis,
+        };
+        interp.check_basis()?;
+        Ok(interp)
+    }
+
+    /// Determinant of the matrix with the basis vectors as columns.
+    fn determinant(&self) -> T {
+        let [[a_x, a_y], [b_x, b_y]] = self.basis;
+        a_x * b_y - b_x * a_y
+    }
+
+    /// Check that the transform is invertible.
+    fn check_basis(&self) -> Result<(), ValidateError> {
+        let det = self.determinant();
+        if det.is_zero() || !det.is_finite() {
+            return Err(ValidateError::Other(format!(
+                "basis vectors must be linearly independent: {:?}",
+                self.basis
+            )));
+        }
+        Ok(())
+    }
+
+    /// Map a point in world coordinates to logical grid index space.
+    pub fn to_index_space(&self, point: &[T; 2]) -> [T; 2] {
+        let [[a_x, a_y], [b_x, b_y]] = self.basis;
+        let det = self.determinant();
+        let (d_x, d_y) = (point[0] - self.origin[0], point[1] - self.origin[1]);
+        [(b_y * d_x - b_x * d_y) / det, (a_x * d_y - a_y * d_x) / det]
+    }
+
+    /// Map a point in logical grid index space to world coordinates.
+    pub fn to_world_space(&self, index: &[T; 2]) -> [T; 2] {
+        let [[a_x, a_y], [b_x, b_y]] = self.basis;
+        [
+            self.origin[0] + index[0] * a_x + index[1] * b_x,
+            self.origin[1] + index[0] * a_y + index[1] * b_y,
+        ]
+    }
+}
+
+impl<T, S> Interpolator<T> for Interp2DAffine<T, S>
+where
+    T: Float + Euclid + Debug,
+    S: Strategy2D<OwnedRepr<T>> + Clone,
+{
+    /// Returns `2`.
+    #[inline]
+    fn ndim(&self) -> usize {
+        2
+    }
+
+    fn validate(&mut self) -> Result<(), ValidateError> {
+        self.check_basis()?;
+        self.interpolator.validate()
+    }
+
+    fn interpolate(&self, point: &[T]) -> Result<T, InterpolateError> {
+        let point: &[T; 2] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(2))?;
+        self.interpolator.interpolate(&self.to_index_space(point))
+    }
+
+    /// Clamps to the lattice, in grid index space.
+    fn interpolate_clamped(&self, point: &[T]) -> Result<T, InterpolateError> {
+        let point: &[T; 2] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(2))?;
+        self.interpolator
+            .interpolate_clamped(&self.to_index_space(point))
+    }
+
+    fn set_extrapolate(&mut self, extrapolate: Extrapolate<T>) -> Result<(), ValidateError> {
+        self.interpolator.set_extrapolate(extrapolate)
+    }
+
+    fn is_contiguous(&self) -> bool {
+        self.interpolator.is_contiguous()
+    }
+
+    /// Bounds of the world-space bounding box of the lattice.
+    fn domain_bounds(&self, dim: usize) -> Option<(T, T)> {
+        if dim > 1 {
+            return None;
+        }
+        let (nx, ny) = self.interpolator.data.values.dim();
+        let (i_max, j_max) = (T::from(nx - 1).unwrap(), T::from(ny - 1).unwrap());
+        [
+            [T::zero(), T::zero()],
+            [i_max, T::zero()],
+            [T::zero(), j_max],
+            [i_max, j_max],
+        ]
+        .iter()
+        .map(|index| self.to_world_space(index)[dim])
+        .fold(None, |bounds, x| match bounds {
+            None => Some((x, x)),
+            Some((min, max)) => Some((min.min(x), max.max(x))),
+        })
+    }
+
+    /// Whether `point` lies within the lattice, in grid index space,
+    /// rather than within the world-space bounding box of [`Interpolator::domain_bounds`].
+    fn contains(&self, point: &[T]) -> bool {
+        let Ok(point): Result<&[T; 2], _> = point.try_into() else {
+            return false;
+        };
+        self.interpolator.contains(&self.to_index_space(point))
+    }
+
+    fn values_view(&self) -> Option<ArrayViewD<'_, T>> {
+        self.interpolator.values_view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotated() {
+        let values = array![[0., 1., 4.], [2., 3., 5.], [7., 6., 8.]];
+        let plain = Interp2D::new(
+            array![0., 1., 2.],
+            array![0., 1., 2.],
+            values.clone(),
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        // Unit lattice rotated by 45 degrees about `origin`
+        let s = std::f64::consts::FRAC_1_SQRT_2;
+        let rotated = Interp2DAffine::new(
+            [1., -2.],
+            [[s, s], [-s, s]],
+            values,
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        for index in [[0., 0.], [0.5, 1.25], [1., 2.], [1.8, 0.3], [2., 2.]] {
+            let world = rotated.to_world_space(&index);
+            let back = rotated.to_index_space(&world);
+            assert_approx_eq!(back[0], index[0]);
+            assert_approx_eq!(back[1], index[1]);
+            assert_approx_eq!(
+                rotated.interpolate(&world).unwrap(),
+                plain.interpolate(&index).unwrap()
+            );
+        }
+        // Within the bounding box, but outside the rotated lattice
+        assert!(rotated.interpolate(&[2.2, -1.9]).is_err());
+        assert!(!rotated.contains(&[2.2, -1.9]));
+        assert!(rotated.contains(&rotated.to_world_space(&[1.8, 0.3])));
+        assert!(!rotated.contains(&[1.]));
+        let (min, max) = rotated.domain_bounds(0).unwrap();
+        assert_approx_eq!(min, 1. - 2. * s);
+        assert_approx_eq!(max, 1. + 2. * s);
+        let (min, max) = rotated.domain_bounds(1).unwrap();
+        assert_approx_eq!(min, -2.);
+        assert_approx_eq!(max, -2. + 4. * s);
+        assert_eq!(rotated.domain_bounds(2), None);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(Interp2DAffine::new(
+            [0., 0.],
+            [[1., 2.], [2., 4.]],
+            array![[0., 1.], [2., 3.]],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,461 @@
+//! Builders for constructing interpolators with named, chained arguments.
+//!
+//! Each builder is equivalent to the corresponding positional constructor, e.g. [`Interp2D::new`],
+//! with [`strategy::Linear`] and [`Extrapolate::Error`] applied if omitted.
+
+use super::*;
+
+/// Error for a required builder argument that was not supplied.
+fn missing(argument: &str) -> ValidateError {
+    ValidateError::Other(format!(
+        "interpolator builder is missing required argument `{argument}`"
+    ))
+}
+
+/// Implements the `strategy` and `extrapolate` methods,
+/// and [`Default`] with [`strategy::Linear`] and [`Extrapolate::Error`], for a builder.
+macro_rules! builder_common_impl {
+    ($Builder:ident, $Strategy:ident, { $($field:ident),+ }) => {
+        impl<D> Default for $Builder<D, strategy::Linear>
+        where
+            D: Data + RawDataClone,
+            D::Elem: PartialEq + Debug,
+        {
+            fn default() -> Self {
+                Self {
+                    $($field: None,)+
+                    strategy: strategy::Linear,
+                    extrapolate: Extrapolate::Error,
+                }
+            }
+        }
+
+        impl<D> $Builder<D, strategy::Linear>
+        where
+            D: Data + RawDataClone,
+            D::Elem: PartialEq + Debug,
+        {
+            /// New builder, with [`strategy::Linear`] and [`Extrapolate::Error`].
+            pub fn new() -> Self {
+                Self::default()
+            }
+        }
+
+        impl<D, S> $Builder<D, S>
+        where
+            D: Data + RawDataClone,
+            D::Elem: PartialEq + Debug,
+        {
+            /// Set the interpolation strategy, [`strategy::Linear`] if omitted.
+            pub fn strategy<S2>(self, strategy: S2) -> $Builder<D, S2>
+            where
+                S2: $Strategy<D> + Clone,
+            {
+                $Builder {
+                    $($field: self.$field,)+
+                    strategy,
+                    extrapolate: self.extrapolate,
+                }
+            }
+
+            /// Set the extrapolation setting, [`Extrapolate::Error`] if omitted.
+            pub fn extrapolate(mut self, extrapolate: Extrapolate<D::Elem>) -> Self {
+                self.extrapolate = extrapolate;
+                self
+            }
+        }
+    };
+}
+
+/// Builder for [`Interp1D`], see [`Interp1D::builder`].
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// let interp = Interp1D::builder()
+///     .x(array![0., 1., 2.])
+///     .values(array![0., 10., 20.])
+///     .extrapolate(Extrapolate::Enable)
+///     .build()
+///     .unwrap();
+/// assert_eq!(interp.interpolate(&[3.]).unwrap(), 30.);
+/// ```
+#[derive(Debug)]
+pub struct Interp1DBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    x: Option<ArrayBase<D, Ix1>>,
+    values: Option<ArrayBase<D, Ix1>>,
+    strategy: S,
+    extrapolate: Extrapolate<D::Elem>,
+}
+
+builder_common_impl!(Interp1DBuilder, Strategy1D, { x, values });
+
+impl<D, S> Interp1DBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    /// Set the x grid.
+    pub fn x(mut self, x: ArrayBase<D, Ix1>) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Set the values `f(x)`.
+    pub fn values(mut self, f_x: ArrayBase<D, Ix1>) -> Self {
+        self.values = Some(f_x);
+        self
+    }
+
+    /// Construct and validate the interpolator, see [`Interp1D::new`].
+    ///
+    /// Errors if the grid or values were not supplied, or for the same reasons as [`Interp1D::new`].
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn build(self) -> Result<Interp1D<D, S>, ValidateError>
+    where
+        D::Elem: Num + PartialOrd + Copy,
+        S: Strategy1D<D> + Clone,
+    {
+        Interp1D::new(
+            self.x.ok_or_else(|| missing("x"))?,
+            self.values.ok_or_else(|| missing("values"))?,
+            self.strategy,
+            self.extrapolate,
+        )
+    }
+}
+
+/// Builder for [`Interp2D`], see [`Interp2D::builder`].
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// let interp = Interp2D::builder()
+///     .x(array![0., 1.])
+///     .y(array![0., 1., 2.])
+///     .values(array![[0., 1., 2.], [3., 4., 5.]])
+///     .strategy(strategy::Nearest)
+///     .build()
+///     .unwrap();
+/// assert_eq!(interp.interpolate(&[0.8, 1.2]).unwrap(), 4.);
+/// assert!(interp.interpolate(&[2., 0.]).is_err());
+/// ```
+#[derive(Debug)]
+pub struct Interp2DBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    x: Option<ArrayBase<D, Ix1>>,
+    y: Option<ArrayBase<D, Ix1>>,
+    values: Option<ArrayBase<D, Ix2>>,
+    strategy: S,
+    extrapolate: Extrapolate<D::Elem>,
+}
+
+builder_common_impl!(Interp2DBuilder, Strategy2D, { x, y, values });
+
+impl<D, S> Interp2DBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    /// Set the x grid.
+    pub fn x(mut self, x: ArrayBase<D, Ix1>) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Set the y grid.
+    pub fn y(mut self, y: ArrayBase<D, Ix1>) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Set the values `f(x, y)`.
+    pub fn values(mut self, f_xy: ArrayBase<D, Ix2>) -> Self {
+        self.values = Some(f_xy);
+        self
+    }
+
+    /// Construct and validate the interpolator, see [`Interp2D::new`].
+    ///
+    /// Errors if the grids or values were not supplied, or for the same reasons as [`Interp2D::new`].
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn build(self) -> Result<Interp2D<D, S>, ValidateError>
+    where
+        D::Elem: Num + PartialOrd + Copy,
+        S: Strategy2D<D> + Clone,
+    {
+        Interp2D::new(
+            self.x.ok_or_else(|| missing("x"))?,
+            self.y.ok_or_else(|| missing("y"))?,
+            self.values.ok_or_else(|| missing("values"))?,
+            self.strategy,
+            self.extrapolate,
+        )
+    }
+}
+
+/// Builder for [`Interp3D`], see [`Interp3D::builder`].
+#[derive(Debug)]
+pub struct Interp3DBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    x: Option<ArrayBase<D, Ix1>>,
+    y: Option<ArrayBase<D, Ix1>>,
+    z: Option<ArrayBase<D, Ix1>>,
+    values: Option<ArrayBase<D, Ix3>>,
+    strategy: S,
+    extrapolate: Extrapolate<D::Elem>,
+}
+
+builder_common_impl!(Interp3DBuilder, Strategy3D, { x, y, z, values });
+
+impl<D, S> Interp3DBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    /// Set the x grid.
+    pub fn x(mut self, x: ArrayBase<D, Ix1>) -> Self {
+        self.x = Some(x);
+        self
+    }
+
+    /// Set the y grid.
+    pub fn y(mut self, y: ArrayBase<D, Ix1>) -> Self {
+        self.y = Some(y);
+        self
+    }
+
+    /// Set the z grid.
+    pub fn z(mut self, z: ArrayBase<D, Ix1>) -> Self {
+        self.z = Some(z);
+        self
+    }
+
+    /// Set the values `f(x, y, z)`.
+    pub fn values(mut self, f_xyz: ArrayBase<D, Ix3>) -> Self {
+        self.values = Some(f_xyz);
+        self
+    }
+
+    /// Construct and validate the interpolator, see [`Interp3D::new`].
+    ///
+    /// Errors if the grids or values were not supplied, or for the same reasons as [`Interp3D::new`].
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn build(self) -> Result<Interp3D<D, S>, ValidateError>
+    where
+        D::Elem: Num + PartialOrd + Copy,
+        S: Strategy3D<D> + Clone,
+    {
+        Interp3D::new(
+            self.x.ok_or_else(|| missing("x"))?,
+            self.y.ok_or_else(|| missing("y"))?,
+            self.z.ok_or_else(|| missing("z"))?,
+            self.values.ok_or_else(|| missing("values"))?,
+            self.strategy,
+            self.extrapolate,
+        )
+    }
+}
+
+/// Builder for [`InterpND`], see [`InterpND::builder`].
+#[derive(Debug)]
+pub struct InterpNDBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    grid: Option<Vec<ArrayBase<D, Ix1>>>,
+    values: Option<ArrayBase<D, IxDyn>>,
+    strategy: S,
+    extrapolate: Extrapolate<D::Elem>,
+}
+
+builder_common_impl!(InterpNDBuilder, StrategyND, { grid, values });
+
+impl<D, S> InterpNDBuilder<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+{
+    /// Set the grid, one array per axis.
+    pub fn grid(mut self, grid: Vec<ArrayBase<D, Ix1>>) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Set the values.
+    pub fn values(mut self, values: ArrayBase<D, IxDyn>) -> Self {
+        self.values = Some(values);
+        self
+    }
+
+    /// Construct and validate the interpolator, see [`InterpND::new`].
+    ///
+    /// Errors if the grid or values were not supplied, or for the same reasons as [`InterpND::new`].
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn build(self) -> Result<InterpND<D, S>, ValidateError>
+    where
+        D::Elem: Num + PartialOrd + Copy,
+        S: StrategyND<D> + Clone,
+    {
+        InterpND::new(
+            self.grid.ok_or_else(|| missing("grid"))?,
+            self.values.ok_or_else(|| missing("values"))?,
+            self.strategy,
+            self.extrapolate,
+        )
+    }
+}
+
+/// Implements `builder` for an interpolator with the default [`strategy::Linear`].
+macro_rules! builder_fn_impl {
+    ($InterpType:ident, $Builder:ident) => {
+        impl<D> $InterpType<D, strategy::Linear>
+        where
+            D: Data + RawDataClone,
+            D::Elem: Num + PartialOrd + Copy + Debug,
+        {
+            #[doc = concat!("Builder for constructing a [`", stringify!($InterpType), "`] with named arguments,")]
+            #[doc = concat!("see [`", stringify!($Builder), "`].")]
+            pub fn builder() -> $Builder<D, strategy::Linear> {
+                $Builder::new()
+            }
+        }
+    };
+}
+
+builder_fn_impl!(Interp1D, Interp1DBuilder);
+builder_fn_impl!(Interp2D, Interp2DBuilder);
+builder_fn_impl!(Interp3D, Interp3DBuilder);
+builder_fn_impl!(InterpND, InterpNDBuilder);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_matches_new() {
+        let x = array![0., 1., 2.];
+        let y = array![0., 1.];
+        let z = array![-1., 1.];
+        let f_xy = array![[0., 1.], [2., 3.], [4., 5.]];
+        let f_xyz = Array3::from_shape_fn((3, 2, 2), |(i, j, k)| (i + 2 * j + 4 * k) as f64);
+        assert_eq!(
+            Interp1D::builder().x(x.clone()).values(x.clone()).build(),
+            Interp1D::new(x.clone(), x.clone(), strategy::Linear, Extrapolate::Error)
+        );
+        assert_eq!(
+            Interp2D::builder()
+                .values(f_xy.clone())
+                .y(y.clone())
+                .x(x.clone())
+                .strategy(strategy::Nearest)
+                .extrapolate(Extrapolate::Clamp)
+                .build(),
+            Interp2D::new(
+                x.clone(),
+                y.clone(),
+                f_xy.clone(),
+                strategy::Nearest,
+                Extrapolate::Clamp
+            )
+        );
+        assert_eq!(
+            Interp3D::builder()
+                .x(x.clone())
+                .y(y.clone())
+                .z(z.clone())
+                .values(f_xyz.clone())
+                .extrapolate(Extrapolate::Fill(f64::NAN))
+                .build()
+                .unwrap()
+                .interpolate(&[0.5, 0.5, 0.])
+                .unwrap(),
+            Interp3D::new(
+                x.clone(),
+                y.clone(),
+                z.clone(),
+                f_xyz.clone(),
+                strategy::Linear,
+                Extrapolate::Fill(f64::NAN)
+            )
+            .unwrap()
+            .interpolate(&[0.5, 0.5, 0.])
+            .unwrap()
+        );
+        assert_eq!(
+            InterpND::builder()
+                .grid(vec![x.clone(), y.clone(), z.clone()])
+                .values(f_xyz.clone().into_dyn())
+                .strategy(strategy::Nearest)
+                .build(),
+            InterpND::new(
+                vec![x, y, z],
+                f_xyz.into_dyn(),
+                strategy::Nearest,
+                Extrapolate::Error
+            )
+        );
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        // Same validation errors as `new`
+        let f_xy = array![[0., 1.], [2., 3.]];
+        assert_eq!(
+            Interp2D::builder()
+                .x(array![0., 1., 2.])
+                .y(array![0., 1.])
+                .values(f_xy.clone())
+                .build()
+                .unwrap_err(),
+            Interp2D::new(
+                array![0., 1., 2.],
+                array![0., 1.],
+                f_xy.clone(),
+                strategy::Linear,
+                Extrapolate::Error
+            )
+            .unwrap_err()
+        );
+        assert!(matches!(
+            Interp2D::builder()
+                .x(array![0., 1.])
+                .y(array![0., 1.])
+                .values(f_xy.clone())
+                .strategy(strategy::Nearest)
+                .extrapolate(Extrapolate::Enable)
+                .build(),
+            Err(ValidateError::ExtrapolateSelection(_))
+        ));
+        assert!(matches!(
+            Interp1D::builder()
+                .x(array![0., 1.])
+                .values(array![0., 1.])
+                .extrapolate(Extrapolate::EnableBounded(f64::NAN))
+                .build(),
+            Err(ValidateError::ExtrapolateSelection(_))
+        ));
+        // Missing arguments
+        assert!(Interp2D::builder()
+            .x(array![0., 1.])
+            .values(f_xy)
+            .build()
+            .unwrap_err()
+            .to_string()
+            .contains("`y`"));
+        assert!(Interp1D::<OwnedRepr<f64>, _>::builder().build().is_err());
+    }
+}
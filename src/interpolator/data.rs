@@ -2,15 +2,17 @@
 
 use super::*;
 
-pub use n::{InterpDataND, InterpDataNDOwned, InterpDataNDViewed};
-pub use one::{InterpData1D, InterpData1DOwned, InterpData1DViewed};
-pub use three::{InterpData3D, InterpData3DOwned, InterpData3DViewed};
-pub use two::{InterpData2D, InterpData2DOwned, InterpData2DViewed};
+pub use n::{InterpDataND, InterpDataNDCow, InterpDataNDOwned, InterpDataNDViewed};
+pub use one::{
+    InterpData1D, InterpData1DCow, InterpData1DOwned, InterpData1DViewed, PlateauPolicy,
+};
+pub use three::{InterpData3D, InterpData3DCow, InterpData3DOwned, InterpData3DViewed};
+pub use two::{InterpData2D, InterpData2DCow, InterpData2DOwned, InterpData2DViewed};
 
 /// Interpolator data for interpolators of concrete dimensionality `const N: usize`.
 ///
 /// See [`InterpDataND`] for the N-dimensional interpolator data struct.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(
     feature = "serde",
@@ -31,7 +33,7 @@ pub use two::{InterpData2D, InterpData2DOwned, InterpData2DViewed};
 pub struct InterpData<D, const N: usize>
 where
     Dim<[Ix; N]>: Dimension,
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Coordinate grid: an `N`-length array of 1-dimensional [`ArrayBase<D, Ix1>`].
@@ -46,11 +48,28 @@ where
 pub type InterpDataViewed<T, const N: usize> = InterpData<ViewRepr<T>, N>;
 /// [`InterpData`] that owns data.
 pub type InterpDataOwned<T, const N: usize> = InterpData<OwnedRepr<T>, N>;
+/// [`InterpData`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type InterpDataCow<'a, T, const N: usize> = InterpData<CowRepr<'a, T>, N>;
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`
+impl<D, const N: usize> Clone for InterpData<D, N>
+where
+    Dim<[Ix; N]>: Dimension,
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
 
 impl<D, const N: usize> PartialEq for InterpData<D, N>
 where
     Dim<[Ix; N]>: Dimension,
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
     ArrayBase<D, Ix1>: PartialEq,
 {
@@ -62,7 +81,7 @@ where
 impl<D, const N: usize> InterpData<D, N>
 where
     Dim<[Ix; N]>: Dimension,
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Validate interpolator data.
@@ -88,6 +107,70 @@ where
         Ok(())
     }
 
+    /// Validate interpolator data, with additional checks for mismatched or malformed views,
+    /// e.g. from slicing a larger array.
+    ///
+    /// Beyond [`InterpData::validate`], requires that grid points are strictly increasing,
+    /// that no view has a zero stride along a non-singleton axis (as from broadcasting),
+    /// and that the number of values equals the product of the grid lengths.
+    pub fn validate_strict(&self) -> Result<(), ValidateError>
+    where
+        D::Elem: PartialOrd,
+    {
+        self.validate()?;
+        validate_views(&self.grid, &self.values)
+    }
+
+    /// Coordinate grid, one 1-D array per axis.
+    pub fn grid(&self) -> &[ArrayBase<D, Ix1>] {
+        &self.grid
+    }
+
+    /// View of the grid along axis `dim`, or `None` if out of range.
+    pub fn axis(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        self.grid.get(dim).map(|g| g.view())
+    }
+
+    /// View of the function values, of dynamic dimensionality.
+    pub fn values(&self) -> ArrayViewD<'_, D::Elem> {
+        self.values.view().into_dyn()
+    }
+
+    /// Shape of the function values, i.e. the grid length along each axis.
+    pub fn shape(&self) -> Vec<usize> {
+        self.values.shape().to_vec()
+    }
+
+    /// Are the grid and values stored in standard (C-order, contiguous) layout?
+    ///
+    /// Data constructed from sliced, transposed, or reversed (strictly decreasing) arrays may not be,
+    /// which slows interpolation until repacked (see [`InterpData::into_contiguous`]).
+    pub fn is_contiguous(&self) -> bool {
+        self.grid.iter().all(|g| g.is_standard_layout()) && self.values.is_standard_layout()
+    }
+
+    /// Is the grid identical to that of `other`?
+    ///
+    /// Operations combining the values of two interpolators, e.g. arithmetic, require this.
+    pub fn same_grid<D2>(&self, other: &InterpData<D2, N>) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+    {
+        self.grid.iter().zip(&other.grid).all(|(a, b)| a == b)
+    }
+
+    /// Is the grid identical to that of `other`, to within `epsilon`?
+    pub fn same_grid_approx<D2>(&self, other: &InterpData<D2, N>, epsilon: D::Elem) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        D::Elem: Num + PartialOrd + Copy,
+    {
+        self.grid
+            .iter()
+            .zip(&other.grid)
+            .all(|(a, b)| approx_eq(a, b, epsilon))
+    }
+
     /// View interpolator data.
     pub fn view(&self) -> InterpDataViewed<&D::Elem, N> {
         InterpDataViewed {
@@ -108,3 +191,116 @@ where
         }
     }
 }
+
+impl<T, const N: usize> InterpDataOwned<T, N>
+where
+    Dim<[Ix; N]>: Dimension,
+    T: PartialEq + Debug + Clone,
+{
+    /// Repack the grid and values into standard (C-order, contiguous) layout,
+    /// only copying arrays that are not already.
+    pub fn into_contiguous(self) -> Self {
+        Self {
+            grid: self.grid.map(into_standard_layout),
+            values: into_standard_layout(self.values),
+        }
+    }
+}
+
+/// Is the grid axis strictly decreasing, and thus reversed by [`orient_ascending`]?
+pub(crate) fn is_descending<S>(grid: &ArrayBase<S, Ix1>) -> bool
+where
+    S: Data,
+    S::Elem: PartialOrd,
+{
+    grid.len() > 1 && grid.windows(2).into_iter().all(|w| w[0] > w[1])
+}
+
+/// Reverse each strictly decreasing grid axis, and the corresponding axis of `values`,
+/// so that all grid axes are increasing.
+///
+/// Reversal negates strides rather than copying, so works for views as well as owned data,
+/// but leaves reversed arrays out of standard layout (see [`InterpData::is_contiguous`]).
+pub(crate) fn orient_ascending<S, Dm>(grid: &mut [ArrayBase<S, Ix1>], values: &mut ArrayBase<S, Dm>)
+where
+    S: Data,
+    S::Elem: PartialOrd,
+    Dm: Dimension,
+{
+    for (dim, g) in grid.iter_mut().enumerate() {
+        if dim < values.ndim() && is_descending(g) {
+            g.invert_axis(Axis(0));
+            values.invert_axis(Axis(dim));
+        }
+    }
+}
+
+/// Checks of [`InterpData::validate_strict`] and [`InterpDataND::validate_strict`]
+/// beyond those of `validate`.
+pub(crate) fn validate_views<S, Dm>(
+    grid: &[ArrayBase<S, Ix1>],
+    values: &ArrayBase<S, Dm>,
+) -> Result<(), ValidateError>
+where
+    S: Data,
+    S::Elem: PartialOrd,
+    Dm: Dimension,
+{
+    for (dim, g) in grid.iter().enumerate() {
+        if !g.windows(2).into_iter().all(|w| w[0] < w[1]) {
+            return Err(ValidateError::Monotonicity(dim));
+        }
+        if g.len() > 1 && g.strides()[0] == 0 {
+            return Err(ValidateError::Other(format!(
+                "grid view has zero stride: dim {dim}"
+            )));
+        }
+    }
+    if let Some(dim) =
+        (0..values.ndim()).find(|&dim| values.shape()[dim] > 1 && values.strides()[dim] == 0)
+    {
+        return Err(ValidateError::Other(format!(
+            "values view has zero stride: dim {dim}"
+        )));
+    }
+    let expected: usize = grid.iter().map(|g| g.len()).product();
+    if values.len() != expected {
+        return Err(ValidateError::Other(format!(
+            "number of values {} does not equal product of grid lengths {expected}",
+            values.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Are `a` and `b` the same shape, with all elements within `epsilon` of each other?
+pub(crate) fn approx_eq<S1, S2, Dm>(
+    a: &ArrayBase<S1, Dm>,
+    b: &ArrayBase<S2, Dm>,
+    epsilon: S1::Elem,
+) -> bool
+where
+    S1: Data,
+    S2: Data<Elem = S1::Elem>,
+    S1::Elem: Num + PartialOrd + Copy,
+    Dm: Dimension,
+{
+    a.shape() == b.shape()
+        && a.iter().zip(b).all(|(&x, &y)| {
+            let diff = if x > y { x - y } else { y - x };
+            diff <= epsilon
+        })
+}
+
+/// Repack an owned array into standard layout, if it is not already.
+pub(crate) fn into_standard_layout<T, Dm>(arr: Array<T, Dm>) -> Array<T, Dm>
+where
+    T: Clone,
+    Dm: Dimension,
+{
+    if arr.is_standard_layout() {
+        arr
+    } else {
+        arr.as_standard_layout().into_owned()
+    }
+}
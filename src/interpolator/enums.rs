@@ -62,7 +62,7 @@ use strategy::enums::*;
 /// ```
 /// See also: `examples/dynamic_interpolator.rs`
 #[allow(missing_docs)]
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(feature = "serde", serde(untagged))]
 #[cfg_attr(
@@ -77,7 +77,7 @@ use strategy::enums::*;
 )]
 pub enum InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     Interp0D(Interp0D<D::Elem>),
@@ -90,10 +90,29 @@ where
 pub type InterpolatorEnumViewed<T> = InterpolatorEnum<ViewRepr<T>>;
 /// [`InterpolatorEnum`] that owns data.
 pub type InterpolatorEnumOwned<T> = InterpolatorEnum<OwnedRepr<T>>;
+/// [`InterpolatorEnum`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type InterpolatorEnumCow<'a, T> = InterpolatorEnum<CowRepr<'a, T>>;
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`
+impl<D> Clone for InterpolatorEnum<D>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Interp0D(interp) => Self::Interp0D(interp.clone()),
+            Self::Interp1D(interp) => Self::Interp1D(interp.clone()),
+            Self::Interp2D(interp) => Self::Interp2D(interp.clone()),
+            Self::Interp3D(interp) => Self::Interp3D(interp.clone()),
+            Self::InterpND(interp) => Self::InterpND(interp.clone()),
+        }
+    }
+}
 
 impl<D> PartialEq for InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
     ArrayBase<D, Ix1>: PartialEq,
 {
@@ -111,17 +130,19 @@ where
 
 impl<D> InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Create [`InterpolatorEnum::Interp0D`], internally calling [`Interp0D::new`].
     #[inline]
+    #[must_use]
     pub fn new_0d(value: D::Elem) -> Self {
         Self::Interp0D(Interp0D::new(value))
     }
 
     /// Create [`InterpolatorEnum::Interp1D`], internally calling [`Interp1D::new`].
     #[inline]
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new_1d(
         x: ArrayBase<D, Ix1>,
         f_x: ArrayBase<D, Ix1>,
@@ -138,6 +159,7 @@ where
 
     /// Create [`InterpolatorEnum::Interp2D`], internally calling [`Interp2D::new`].
     #[inline]
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new_2d(
         x: ArrayBase<D, Ix1>,
         y: ArrayBase<D, Ix1>,
@@ -156,6 +178,7 @@ where
 
     /// Create [`InterpolatorEnum::Interp3D`], internally calling [`Interp3D::new`].
     #[inline]
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new_3d(
         x: ArrayBase<D, Ix1>,
         y: ArrayBase<D, Ix1>,
@@ -176,6 +199,7 @@ where
 
     /// Create [`InterpolatorEnum::InterpND`], internally calling [`InterpND::new`].
     #[inline]
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new_nd(
         grid: Vec<ArrayBase<D, Ix1>>,
         values: ArrayBase<D, IxDyn>,
@@ -214,11 +238,79 @@ where
             InterpolatorEnum::InterpND(interp) => InterpolatorEnum::InterpND(interp.into_owned()),
         }
     }
+
+    /// Reset the strategy to [`strategy::Linear`], regardless of dimensionality,
+    /// revalidating the extrapolation setting against it.
+    /// A no-op for [`InterpolatorEnum::Interp0D`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let mut interp = InterpolatorEnum::new_1d(
+    ///     array![0., 1.],
+    ///     array![0., 1.],
+    ///     strategy::Nearest,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.interpolate(&[0.25]).unwrap(), 0.);
+    /// interp.reset_strategy().unwrap();
+    /// assert_eq!(interp.interpolate(&[0.25]).unwrap(), 0.25);
+    /// ```
+    pub fn reset_strategy(&mut self) -> Result<(), ValidateError> {
+        match self {
+            InterpolatorEnum::Interp0D(_) => Ok(()),
+            InterpolatorEnum::Interp1D(interp) => interp.set_strategy(strategy::Linear),
+            InterpolatorEnum::Interp2D(interp) => interp.set_strategy(strategy::Linear),
+            InterpolatorEnum::Interp3D(interp) => interp.set_strategy(strategy::Linear),
+            InterpolatorEnum::InterpND(interp) => interp.set_strategy(strategy::Linear),
+        }
+    }
+
+    /// Name of the strategy variant currently held, e.g. `Some("Nearest")`.
+    ///
+    /// Returns [`None`] for [`InterpolatorEnum::Interp0D`], which has no strategy.
+    /// See [`Strategy1DEnum::variant_name`](crate::strategy::enums::Strategy1DEnum::variant_name).
+    pub fn current_strategy_name(&self) -> Option<&'static str> {
+        match self {
+            InterpolatorEnum::Interp0D(_) => None,
+            InterpolatorEnum::Interp1D(interp) => Some(interp.strategy.variant_name()),
+            InterpolatorEnum::Interp2D(interp) => Some(interp.strategy.variant_name()),
+            InterpolatorEnum::Interp3D(interp) => Some(interp.strategy.variant_name()),
+            InterpolatorEnum::InterpND(interp) => Some(interp.strategy.variant_name()),
+        }
+    }
+}
+
+impl<T> InterpolatorEnumOwned<T>
+where
+    T: Num + PartialOrd + Copy + Debug,
+{
+    /// Repack interpolator data into standard (C-order, contiguous) layout,
+    /// see [`Interpolator::is_contiguous`].
+    pub fn into_contiguous(self) -> Self {
+        match self {
+            InterpolatorEnum::Interp0D(interp) => InterpolatorEnum::Interp0D(interp),
+            InterpolatorEnum::Interp1D(interp) => {
+                InterpolatorEnum::Interp1D(interp.into_contiguous())
+            }
+            InterpolatorEnum::Interp2D(interp) => {
+                InterpolatorEnum::Interp2D(interp.into_contiguous())
+            }
+            InterpolatorEnum::Interp3D(interp) => {
+                InterpolatorEnum::Interp3D(interp.into_contiguous())
+            }
+            InterpolatorEnum::InterpND(interp) => {
+                InterpolatorEnum::InterpND(interp.into_contiguous())
+            }
+        }
+    }
 }
 
 impl<D> Interpolator<D::Elem> for InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + Euclid + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -254,6 +346,17 @@ where
         }
     }
 
+    #[inline]
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        match self {
+            InterpolatorEnum::Interp0D(interp) => interp.interpolate_clamped(point),
+            InterpolatorEnum::Interp1D(interp) => interp.interpolate_clamped(point),
+            InterpolatorEnum::Interp2D(interp) => interp.interpolate_clamped(point),
+            InterpolatorEnum::Interp3D(interp) => interp.interpolate_clamped(point),
+            InterpolatorEnum::InterpND(interp) => interp.interpolate_clamped(point),
+        }
+    }
+
     #[inline]
     fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
         match self {
@@ -264,11 +367,62 @@ where
             InterpolatorEnum::InterpND(interp) => interp.set_extrapolate(extrapolate),
         }
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        match self {
+            InterpolatorEnum::Interp0D(interp) => interp.is_contiguous(),
+            InterpolatorEnum::Interp1D(interp) => interp.is_contiguous(),
+            InterpolatorEnum::Interp2D(interp) => interp.is_contiguous(),
+            InterpolatorEnum::Interp3D(interp) => interp.is_contiguous(),
+            InterpolatorEnum::InterpND(interp) => interp.is_contiguous(),
+        }
+    }
+
+    #[inline]
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        match self {
+            InterpolatorEnum::Interp0D(interp) => interp.domain_bounds(dim),
+            InterpolatorEnum::Interp1D(interp) => interp.domain_bounds(dim),
+            InterpolatorEnum::Interp2D(interp) => interp.domain_bounds(dim),
+            InterpolatorEnum::Interp3D(interp) => interp.domain_bounds(dim),
+            InterpolatorEnum::InterpND(interp) => interp.domain_bounds(dim),
+        }
+    }
+
+    #[inline]
+    fn grid(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        match self {
+            InterpolatorEnum::Interp0D(interp) => interp.grid(dim),
+            InterpolatorEnum::Interp1D(interp) => interp.grid(dim),
+            InterpolatorEnum::Interp2D(interp) => interp.grid(dim),
+            InterpolatorEnum::Interp3D(interp) => interp.grid(dim),
+            InterpolatorEnum::InterpND(interp) => interp.grid(dim),
+        }
+    }
+
+    #[inline]
+    fn values_view(&self) -> Option<ArrayViewD<'_, D::Elem>> {
+        match self {
+            InterpolatorEnum::Interp0D(interp) => interp.values_view(),
+            InterpolatorEnum::Interp1D(interp) => interp.values_view(),
+            InterpolatorEnum::Interp2D(interp) => interp.values_view(),
+            InterpolatorEnum::Interp3D(interp) => interp.values_view(),
+            InterpolatorEnum::InterpND(interp) => interp.values_view(),
+        }
+    }
+
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<D::Elem>>>
+    where
+        D::Elem: 'static,
+    {
+        Some(Box::new(self.view().into_owned()))
+    }
 }
 
 impl<D> From<Interp0D<D::Elem>> for InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -279,7 +433,7 @@ where
 
 impl<D> From<Interp1D<D, Strategy1DEnum>> for InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -290,7 +444,7 @@ where
 
 impl<D> From<Interp2D<D, Strategy2DEnum>> for InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -301,7 +455,7 @@ where
 
 impl<D> From<Interp3D<D, Strategy3DEnum>> for InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -312,7 +466,7 @@ where
 
 impl<D> From<InterpND<D, StrategyNDEnum>> for InterpolatorEnum<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -332,6 +486,65 @@ mod tests {
         struct MyStruct(InterpolatorEnumOwned<f64>);
     }
 
+    #[test]
+    fn test_reset_strategy() {
+        let mut interp = InterpolatorEnum::new_2d(
+            array![0., 1.],
+            array![0., 1.],
+            array![[0., 1.], [2., 3.]],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let InterpolatorEnum::Interp2D(interp_2d) = &mut interp else {
+            unreachable!()
+        };
+        interp_2d.set_strategy(strategy::Nearest).unwrap();
+        assert_eq!(interp.interpolate(&[0.2, 0.4]).unwrap(), 0.);
+        interp.reset_strategy().unwrap();
+        assert_eq!(interp.interpolate(&[0.2, 0.4]).unwrap(), 0.8);
+        // 0-D is a no-op
+        let mut interp = InterpolatorEnumOwned::new_0d(0.5);
+        interp.reset_strategy().unwrap();
+        assert_eq!(interp.interpolate(&[]).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_current_strategy_name() {
+        let mut interp = InterpolatorEnum::new_1d(
+            array![0., 1.],
+            array![0., 1.],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert_eq!(interp.current_strategy_name(), Some("Linear"));
+        let InterpolatorEnum::Interp1D(interp_1d) = &mut interp else {
+            unreachable!()
+        };
+        interp_1d.set_strategy(strategy::Nearest).unwrap();
+        assert_eq!(interp.current_strategy_name(), Some("Nearest"));
+        let mut interp = InterpolatorEnum::new_2d(
+            array![0., 1.],
+            array![0., 1.],
+            array![[0., 1.], [2., 3.]],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let InterpolatorEnum::Interp2D(interp_2d) = &mut interp else {
+            unreachable!()
+        };
+        interp_2d.set_strategy(strategy::Nearest).unwrap();
+        assert_eq!(interp.current_strategy_name(), Some("Nearest"));
+        interp.reset_strategy().unwrap();
+        assert_eq!(interp.current_strategy_name(), Some("Linear"));
+        assert_eq!(
+            InterpolatorEnumOwned::new_0d(0.5).current_strategy_name(),
+            None
+        );
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serde() {
@@ -0,0 +1,227 @@
+//! N-D interpolation with a shared, thread-safe bracket index hint.
+
+use super::*;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// An [`InterpND`] that remembers the lower bracket indices of its most recent query,
+/// reusing them via [`StrategyND::interpolate_with_hint`] when the next point falls in the same cell.
+///
+/// Unlike [`InterpND::interpolate_path`], the hint persists across [`Interpolator::interpolate`] calls
+/// and is updated through `&self`, with one [`AtomicUsize`] per dimension.
+/// This makes it suitable for many threads querying a shared `Arc<HintedInterpND<_, _>>`.
+///
+/// Hints are advisory only: each hinted index is checked to bracket the point before it is used,
+/// falling back to a search otherwise. Concurrent queries may overwrite each other's hints
+/// (including per-dimension interleaving), which can only cost a search, never change a result.
+/// Results are identical to calling [`Interpolator::interpolate`] on the wrapped [`InterpND`].
+///
+/// # Example:
+/// ```
+/// use std::sync::Arc;
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::hinted::HintedInterpND;
+/// let interp = Arc::new(HintedInterpND::new(
+///     InterpND::new(
+///         vec![array![0., 1., 2.], array![0., 1.]],
+///         array![[0., 1.], [2., 3.], [4., 5.]].into_dyn(),
+///         strategy::Linear,
+///         Extrapolate::Error,
+///     )
+///     .unwrap(),
+/// ));
+/// let handles: Vec<_> = (0..4)
+///     .map(|i| {
+///         let interp = Arc::clone(&interp);
+///         std::thread::spawn(move || interp.interpolate(&[i as f64 / 2., 0.5]).unwrap())
+///     })
+///     .collect();
+/// let results: Vec<f64> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+/// assert_eq!(results, vec![0.5, 1.5, 2.5, 3.5]);
+/// ```
+#[derive(Debug)]
+pub struct HintedInterpND<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+    S: StrategyND<D> + Clone,
+{
+    /// Wrapped interpolator.
+    pub interp: InterpND<D, S>,
+    hint: Vec<AtomicUsize>,
+}
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`
+impl<D, S> Clone for HintedInterpND<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug + Clone,
+    S: StrategyND<D> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            interp: self.interp.clone(),
+            hint: self
+                .hint
+                .iter()
+                .map(|h| AtomicUsize::new(h.load(Ordering::Relaxed)))
+                .collect(),
+        }
+    }
+}
+
+impl<D, S> HintedInterpND<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+    S: StrategyND<D> + Clone,
+{
+    /// Wrap an [`InterpND`], starting with a hint of the first cell along every dimension.
+    #[must_use]
+    pub fn new(interp: InterpND<D, S>) -> Self {
+        let hint = (0..interp.data.grid.len())
+            .map(|_| AtomicUsize::new(0))
+            .collect();
+        Self { interp, hint }
+    }
+
+    /// Unwrap the contained [`InterpND`].
+    pub fn into_inner(self) -> InterpND<D, S> {
+        self.interp
+    }
+}
+
+impl<D, S> Interpolator<D::Elem> for HintedInterpND<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
+    S: StrategyND<D> + Clone,
+{
+    fn ndim(&self) -> usize {
+        self.interp.ndim()
+    }
+
+    /// Validate the wrapped interpolator, resizing the hint to its dimensionality.
+    fn validate(&mut self) -> Result<(), ValidateError> {
+        self.interp.validate()?;
+        self.hint = (0..self.interp.ndim())
+            .map(|_| AtomicUsize::new(0))
+            .collect();
+        Ok(())
+    }
+
+    fn interpolate(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let n = self.interp.ndim();
+        if point.len() != n || self.hint.len() != n {
+            return self.interp.interpolate(point);
+        }
+        let in_bounds = point
+            .iter()
+            .zip(&self.interp.data.grid)
+            .all(|(pt, grid)| (grid.first().unwrap()..=grid.last().unwrap()).contains(&pt));
+        if !in_bounds || !self.interp.strategy.is_initialized() {
+            return self.interp.interpolate(point);
+        }
+        let mut hint: Vec<usize> = self
+            .hint
+            .iter()
+            .map(|h| h.load(Ordering::Relaxed))
+            .collect();
+        let result =
+            self.interp
+                .strategy
+                .interpolate_with_hint(&self.interp.data, point, &mut hint)?;
+        for (shared, lower) in self.hint.iter().zip(hint) {
+            shared.store(lower, Ordering::Relaxed);
+        }
+        Ok(result)
+    }
+
+    #[inline]
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        self.interp.interpolate_clamped(point)
+    }
+
+    #[inline]
+    fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
+        self.interp.set_extrapolate(extrapolate)
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.interp.is_contiguous()
+    }
+
+    #[inline]
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        self.interp.domain_bounds(dim)
+    }
+
+    #[inline]
+    fn grid(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        self.interp.grid(dim)
+    }
+
+    #[inline]
+    fn values_view(&self) -> Option<ArrayViewD<'_, D::Elem>> {
+        self.interp.values_view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    #[test]
+    fn test_concurrent() {
+        let x = Array1::linspace(0., 10., 11);
+        let y = Array1::linspace(-5., 5., 21);
+        let values = Array2::from_shape_fn((11, 21), |(i, j)| (i * 21 + j) as f64).into_dyn();
+        let plain =
+            InterpND::new(vec![x, y], values, strategy::Linear, Extrapolate::Clamp).unwrap();
+        let hinted = Arc::new(HintedInterpND::new(plain.clone()));
+        let plain = Arc::new(plain);
+        let handles: Vec<_> = (0..8)
+            .map(|t| {
+                let (hinted, plain) = (Arc::clone(&hinted), Arc::clone(&plain));
+                std::thread::spawn(move || {
+                    // Each thread walks a different path, so shared hints are frequently stale
+                    for i in 0..500 {
+                        let s = (i * (t + 1)) as f64 / 50.;
+                        let point = [s % 11. - 0.5, 5. - (s * 1.3) % 10.5];
+                        assert_eq!(
+                            hinted.interpolate(&point).unwrap(),
+                            plain.interpolate(&point).unwrap()
+                        );
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_stale_hint() {
+        let interp = HintedInterpND::new(
+            InterpND::new(
+                vec![array![0., 1., 2., 3.]],
+                array![0., 10., 20., 30.].into_dyn(),
+                strategy::Linear,
+                Extrapolate::Error,
+            )
+            .unwrap(),
+        );
+        // Out-of-range hints must be ignored
+        interp.hint[0].store(100, Ordering::Relaxed);
+        assert_eq!(interp.interpolate(&[2.5]).unwrap(), 25.);
+        assert_eq!(interp.hint[0].load(Ordering::Relaxed), 2);
+        assert_eq!(interp.interpolate(&[0.5]).unwrap(), 5.);
+        assert_eq!(interp.hint[0].load(Ordering::Relaxed), 0);
+        assert!(interp.interpolate(&[4.]).is_err());
+    }
+}
@@ -0,0 +1,292 @@
+//! Inverse distance weighting interpolation of scattered data.
+
+use super::*;
+use num_traits::float::TotalOrder;
+use rbf::{check_scattered, check_scattered_extrapolate, distance, scattered_bounds};
+
+/// An interpolator over scattered (non-gridded) data, using Shepard's inverse distance weighting:
+/// a weighted mean of the sample values with weights `1 / r^power`,
+/// where `r` is the distance from each sample point.
+///
+/// A lighter-weight alternative to [`InterpRBF`](rbf::InterpRBF), with nothing to solve upon construction,
+/// but with flat spots at the sample points. Points coincident with a sample point give its value.
+/// Larger [`InterpIDW::power`] weights nearer samples more heavily,
+/// approaching nearest-neighbor interpolation.
+///
+/// Out-of-bounds points, those outside the bounding box of the sample points, follow the
+/// [`Extrapolate`] setting. Only [`Extrapolate::Enable`], [`Extrapolate::Fill`],
+/// [`Extrapolate::Clamp`], and [`Extrapolate::Error`] are applicable.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::idw::InterpIDW;
+/// let interp = InterpIDW::new(
+///     array![[0., 0.], [2., 0.], [0., 2.], [2., 2.]],
+///     array![0., 4., 8., 12.],
+///     2.,
+///     None,
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[2., 0.]).unwrap(), 4.);
+/// // Equidistant from all samples
+/// assert_eq!(interp.interpolate(&[1., 1.]).unwrap(), 6.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpIDW<T>
+where
+    T: Float + TotalOrder + Debug,
+{
+    /// Sample point coordinates, one point per row.
+    pub points: Array2<T>,
+    /// Sample values, one per point.
+    pub values: Array1<T>,
+    /// Exponent of inverse distance weights.
+    pub power: T,
+    /// If set, only the given number of nearest sample points contribute to each interpolated value.
+    pub neighbors: Option<usize>,
+    /// Setting for points outside the bounding box of [`InterpIDW::points`].
+    pub extrapolate: Extrapolate<T>,
+}
+
+impl<T> InterpIDW<T>
+where
+    T: Float + TotalOrder + Debug,
+{
+    /// Construct and validate an IDW interpolator.
+    ///
+    /// Errors if `points` is empty, contains non-finite coordinates, or does not have one row per value,
+    /// if `power` is not positive, if `neighbors` is zero,
+    /// or if the [`Extrapolate`] setting is inapplicable.
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn new(
+        points: Array2<T>,
+        values: Array1<T>,
+        power: T,
+        neighbors: Option<usize>,
+        extrapolate: Extrapolate<T>,
+    ) -> Result<Self, ValidateError> {
+        let mut interp = Self {
+            points,
+            values,
+            power,
+            neighbors,
+            extrapolate,
+        };
+        interp.validate()?;
+        Ok(interp)
+    }
+
+    /// Weighted mean of the sample values at `point`, regardless of bounds.
+    fn evaluate(&self, point: &[T]) -> T {
+        let point = ArrayView1::from(point);
+        let mut distances: Vec<(T, T)> = self
+            .points
+            .outer_iter()
+            .map(|p| distance(p, point))
+            .zip(self.values.iter().copied())
+            .collect();
+        if let Some((_, value)) = distances.iter().find(|(r, _)| r.is_zero()) {
+            return *value;
+        }
+        if let Some(k) = self.neighbors.filter(|k| *k < distances.len()) {
+            distances.select_nth_unstable_by(k - 1, |(a, _), (b, _)| a.total_cmp(b));
+            distances.truncate(k);
+        }
+        let (sum, weights) =
+            distances
+                .iter()
+                .fold((T::zero(), T::zero()), |(sum, weights), (r, value)| {
+                    let weight = r.powf(-self.power);
+                    (sum + weight * *value, weights + weight)
+                });
+        sum / weights
+    }
+}
+
+impl<T> Interpolator<T> for InterpIDW<T>
+where
+    T: Float + TotalOrder + Debug,
+{
+    /// Number of columns of [`InterpIDW::points`].
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.points.ncols()
+    }
+
+    fn validate(&mut self) -> Result<(), ValidateError> {
+        check_scattered(&self.points, &self.values, &self.extrapolate)?;
+        if let Some(x) = self.points.iter().find(|x| !x.is_finite()) {
+            return Err(ValidateError::InvalidValues(format!(
+                "points must be finite: found {x:?}"
+            )));
+        }
+        if !(self.power > T::zero() && self.power.is_finite()) {
+            return Err(ValidateError::Other(format!(
+                "power must be positive and finite: power = {:?}",
+                self.power
+            )));
+        }
+        if self.neighbors == Some(0) {
+            return Err(ValidateError::Other(
+                "number of neighbors must be positive".into(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn interpolate(&self, point: &[T]) -> Result<T, InterpolateError> {
+        if point.len() != self.ndim() {
+            return Err(InterpolateError::PointLength(self.ndim()));
+        }
+        if !self.contains(point) {
+            match &self.extrapolate {
+                Extrapolate::Enable => {}
+                Extrapolate::Fill(value) => return Ok(*value),
+                Extrapolate::Clamp => return self.interpolate_clamped(point),
+                _ => {
+                    return Err(InterpolateError::ExtrapolateError(format!(
+                        "\n    point = {point:?} is outside the bounding box of the sample points"
+                    )))
+                }
+            }
+        }
+        Ok(self.evaluate(point))
+    }
+
+    /// Clamps to the bounding box of the sample points.
+    fn interpolate_clamped(&self, point: &[T]) -> Result<T, InterpolateError> {
+        if point.len() != self.ndim() {
+            return Err(InterpolateError::PointLength(self.ndim()));
+        }
+        let clamped: Vec<T> = point
+            .iter()
+            .zip(self.bounds())
+            .map(|(x, (min, max))| clamp(*x, min, max))
+            .collect();
+        Ok(self.evaluate(&clamped))
+    }
+
+    fn set_extrapolate(&mut self, extrapolate: Extrapolate<T>) -> Result<(), ValidateError> {
+        check_scattered_extrapolate(&extrapolate)?;
+        self.extrapolate = extrapolate;
+        Ok(())
+    }
+
+    fn is_contiguous(&self) -> bool {
+        self.points.is_standard_layout() && self.values.is_standard_layout()
+    }
+
+    /// Bounds of the bounding box of the sample points.
+    fn domain_bounds(&self, dim: usize) -> Option<(T, T)> {
+        scattered_bounds(&self.points, dim)
+    }
+
+    /// Returns a view of the sample values.
+    fn values_view(&self) -> Option<ArrayViewD<'_, T>> {
+        Some(self.values.view().into_dyn())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interp(power: f64, neighbors: Option<usize>) -> InterpIDW<f64> {
+        InterpIDW::new(
+            array![[0., 0.], [1., 0.], [0., 1.], [1., 1.], [3., 3.]],
+            array![1., 2., 3., 4., 100.],
+            power,
+            neighbors,
+            Extrapolate::Error,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_exact_hit() {
+        let interp = interp(2., None);
+        for (p, v) in interp.points.outer_iter().zip(&interp.values) {
+            assert_eq!(interp.interpolate(p.as_slice().unwrap()).unwrap(), *v);
+        }
+    }
+
+    #[test]
+    fn test_weighted_average() {
+        let interp = InterpIDW::new(
+            array![[0.], [4.]],
+            array![10., 20.],
+            1.,
+            None,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        assert_eq!(interp.interpolate(&[2.]).unwrap(), 15.);
+        // Weights 1/1 and 1/3
+        assert_approx_eq!(interp.interpolate(&[1.]).unwrap(), 12.5);
+        // Bounded by the sample values, even when extrapolating
+        assert!((10.0..=20.0).contains(&interp.interpolate(&[-5.]).unwrap()));
+    }
+
+    #[test]
+    fn test_power_sharpens() {
+        let point = [0.2, 0.3];
+        let mut previous = f64::INFINITY;
+        for power in [1., 2., 4., 8., 16.] {
+            let error = (interp(power, None).interpolate(&point).unwrap() - 1.).abs();
+            assert!(error < previous);
+            previous = error;
+        }
+        assert!(previous < 1e-3);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        // Distant outlier excluded by the neighbor cutoff
+        let point = [0.5, 0.5];
+        assert!(interp(2., None).interpolate(&point).unwrap() > 3.);
+        assert_eq!(interp(2., Some(4)).interpolate(&point).unwrap(), 2.5);
+        assert_eq!(interp(2., Some(1)).interpolate(&[0.1, 0.2]).unwrap(), 1.);
+        // Cutoff above the number of samples uses all of them
+        assert_eq!(
+            interp(2., Some(10)).interpolate(&point).unwrap(),
+            interp(2., None).interpolate(&point).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        let new = |power, neighbors| {
+            InterpIDW::new(
+                array![[0.], [1.]],
+                array![0., 1.],
+                power,
+                neighbors,
+                Extrapolate::Error,
+            )
+        };
+        assert!(new(0., None).is_err());
+        assert!(new(2., Some(0)).is_err());
+        assert!(matches!(
+            InterpIDW::new(
+                array![[0.], [f64::NAN]],
+                array![0., 1.],
+                2.,
+                Some(1),
+                Extrapolate::Error,
+            ),
+            Err(ValidateError::InvalidValues(_))
+        ));
+        assert!(matches!(
+            InterpIDW::new(array![[0.]], array![0., 1.], 2., None, Extrapolate::Error),
+            Err(ValidateError::IncompatibleShapes(0))
+        ));
+        assert!(matches!(
+            new(2., None).unwrap().with_extrapolate(Extrapolate::Wrap),
+            Err(ValidateError::ExtrapolateSelection(_))
+        ));
+        assert!(new(2., None).unwrap().interpolate(&[2.]).is_err());
+    }
+}
@@ -8,13 +8,26 @@ mod three;
 mod two;
 mod zero;
 
+pub mod affine;
+pub mod builder;
 pub mod data;
 pub mod enums;
+pub mod hinted;
+pub mod idw;
+#[cfg(feature = "npz")]
+pub mod npz;
+pub mod pipeline;
+pub mod rbf;
+#[cfg(feature = "polars")]
+pub mod series;
+pub mod stacked;
+pub mod tensor;
+pub mod uncertain;
 
-pub use n::{InterpND, InterpNDOwned, InterpNDViewed};
-pub use one::{Interp1D, Interp1DOwned, Interp1DViewed};
-pub use three::{Interp3D, Interp3DOwned, Interp3DViewed};
-pub use two::{Interp2D, Interp2DOwned, Interp2DViewed};
+pub use n::{ConstructionReport, InterpND, InterpNDCow, InterpNDOwned, InterpNDViewed};
+pub use one::{Interp1D, Interp1DCow, Interp1DOwned, Interp1DViewed, Rounding};
+pub use three::{Interp3D, Interp3DCow, Interp3DOwned, Interp3DViewed};
+pub use two::{Interp2D, Interp2DCow, Interp2DOwned, Interp2DViewed};
 pub use zero::Interp0D;
 
 /// An interpolator of data type `T`
@@ -26,16 +39,395 @@ pub trait Interpolator<T>: DynClone {
     /// Interpolator dimensionality.
     fn ndim(&self) -> usize;
     /// Validate interpolator data.
+    #[must_use = "validation errors should be handled"]
     fn validate(&mut self) -> Result<(), ValidateError>;
     /// Interpolate at supplied point.
     fn interpolate(&self, point: &[T]) -> Result<T, InterpolateError>;
+    /// Interpolate at supplied point, clamping it to the grid bounds
+    /// regardless of the stored [`Extrapolate`] setting.
+    ///
+    /// By default, each coordinate is clamped to its [`Interpolator::domain_bounds`]
+    /// before calling [`Interpolator::interpolate`],
+    /// erroring if the bounds of any axis are unavailable.
+    fn interpolate_clamped(&self, point: &[T]) -> Result<T, InterpolateError>
+    where
+        T: PartialOrd + Clone,
+    {
+        if point.len() != self.ndim() {
+            return Err(InterpolateError::PointLength(self.ndim()));
+        }
+        let clamped = point
+            .iter()
+            .enumerate()
+            .map(|(dim, x)| {
+                let (min, max) = self.domain_bounds(dim).ok_or_else(|| {
+                    InterpolateError::Other(format!("bounds of axis {dim} are unavailable"))
+                })?;
+                Ok(clamp(x.clone(), min, max))
+            })
+            .collect::<Result<Vec<T>, InterpolateError>>()?;
+        self.interpolate(&clamped)
+    }
     /// Set [`Extrapolate`] variant, checking validity.
+    #[must_use = "the `Extrapolate` variant can be inapplicable, which should be handled"]
     fn set_extrapolate(&mut self, extrapolate: Extrapolate<T>) -> Result<(), ValidateError>;
+    /// Builder-style [`Interpolator::set_extrapolate`], returning the updated interpolator.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2.],
+    ///     array![0., 10., 20.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap()
+    /// .with_extrapolate(Extrapolate::Clamp)
+    /// .unwrap();
+    /// assert_eq!(interp.interpolate(&[3.]).unwrap(), 20.);
+    /// ```
+    fn with_extrapolate(mut self, extrapolate: Extrapolate<T>) -> Result<Self, ValidateError>
+    where
+        Self: Sized,
+    {
+        self.set_extrapolate(extrapolate)?;
+        Ok(self)
+    }
+    /// Is interpolator data stored in standard (C-order, contiguous) layout?
+    ///
+    /// Interpolators constructed from sliced or transposed arrays may not be,
+    /// which slows interpolation until repacked with e.g. [`Interp2D::into_contiguous`].
+    ///
+    /// By default, checks the layout of [`Interpolator::values_view`],
+    /// returning `true` if it is unavailable.
+    fn is_contiguous(&self) -> bool {
+        self.values_view()
+            .is_none_or(|values| values.is_standard_layout())
+    }
+
+    /// Bounds `(min, max)` of the interpolation domain along axis `dim`,
+    /// or `None` if `dim` is out of range or the bounds are unavailable.
+    ///
+    /// By default, bounds are unavailable.
+    fn domain_bounds(&self, _dim: usize) -> Option<(T, T)> {
+        None
+    }
+
+    /// View of the grid along axis `dim`,
+    /// or `None` if `dim` is out of range or the interpolator is not backed by a single grid.
+    fn grid(&self, _dim: usize) -> Option<ArrayView1<'_, T>> {
+        None
+    }
+
+    /// View of the values array,
+    /// or `None` if the interpolator is not backed by a single values array.
+    fn values_view(&self) -> Option<ArrayViewD<'_, T>> {
+        None
+    }
+
+    /// Values flattened in row-major (C) order, regardless of memory layout,
+    /// e.g. for export to formats expecting a flat buffer.
+    ///
+    /// [`Interp0D`] gives a single element. Empty if [`Interpolator::values_view`] is `None`.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1., 2.],
+    ///     array![[0., 1., 2.], [3., 4., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.values_flat(), vec![0., 1., 2., 3., 4., 5.]);
+    /// assert_eq!(Interp0D(0.5).values_flat(), vec![0.5]);
+    /// ```
+    fn values_flat(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.values_view()
+            .map_or_else(Vec::new, |values| values.iter().cloned().collect())
+    }
+
+    /// Clone into a boxed interpolator that owns its data,
+    /// detached from any borrowed source arrays, e.g. to outlive them.
+    ///
+    /// Returns `None` if this interpolator does not support detaching.
+    /// Concrete interpolators, e.g. [`Interp1D`], support detaching if their strategy
+    /// has a strategy enum variant (e.g. [`strategy::Linear`]), as the strategy type is otherwise
+    /// tied to the data representation: call e.g. [`Interp1D::into_owned`] on others before boxing.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let owned = {
+    ///     let x = array![0., 1., 2.];
+    ///     let f_x = array![0., 10., 0.];
+    ///     let viewed: Box<dyn Interpolator<f64> + '_> = Box::new(
+    ///         InterpolatorEnum::new_1d(x.view(), f_x.view(), strategy::Linear, Extrapolate::Error)
+    ///             .unwrap(),
+    ///     );
+    ///     viewed.to_owned_boxed().unwrap()
+    /// };
+    /// assert_eq!(owned.interpolate(&[0.5]).unwrap(), 5.);
+    /// ```
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<T>>>
+    where
+        T: 'static,
+    {
+        None
+    }
+
+    /// Extent of the interpolation domain along axis `dim`, i.e. `max - min` of its grid,
+    /// or `None` if `dim` is out of range.
+    fn domain_span(&self, dim: usize) -> Option<T>
+    where
+        T: Num,
+    {
+        self.domain_bounds(dim).map(|(min, max)| max - min)
+    }
+
+    /// Hypervolume of the interpolation domain, i.e. the product of [`Interpolator::domain_span`]
+    /// over all axes, e.g. for normalization or Monte-Carlo sampling within the domain.
+    ///
+    /// Returns `1` for 0-D interpolators.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![1., 3.],
+    ///     array![0., 1., 3.],
+    ///     array![[0., 1., 2.], [3., 4., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.domain_bounds(1), Some((0., 3.)));
+    /// assert_eq!(interp.domain_span(1), Some(3.));
+    /// assert_eq!(interp.domain_span(2), None);
+    /// assert_eq!(interp.domain_volume(), 6.);
+    /// ```
+    fn domain_volume(&self) -> T
+    where
+        T: Num,
+    {
+        (0..self.ndim()).fold(T::one(), |volume, dim| {
+            volume * self.domain_span(dim).unwrap_or_else(T::one)
+        })
+    }
+
+    /// `(min, max)` of the interpolation domain along each axis, i.e. [`Interpolator::domain_bounds`]
+    /// collected over all axes.
+    ///
+    /// Returns an empty vector for 0-D interpolators.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![1., 3.],
+    ///     array![0., 1., 3.],
+    ///     array![[0., 1., 2.], [3., 4., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.bounds(), vec![(1., 3.), (0., 3.)]);
+    /// assert!(interp.contains(&[2., 3.]));
+    /// assert!(!interp.contains(&[0., 1.]));
+    /// ```
+    fn bounds(&self) -> Vec<(T, T)> {
+        (0..self.ndim())
+            .filter_map(|dim| self.domain_bounds(dim))
+            .collect()
+    }
+
+    /// Whether `point` lies within the interpolation domain (inclusive of its edges),
+    /// i.e. whether it can be interpolated without extrapolating.
+    ///
+    /// Returns `false` if `point` does not have one coordinate per axis,
+    /// or if any axis has unavailable [`Interpolator::domain_bounds`].
+    /// 0-D interpolators contain the empty point.
+    fn contains(&self, point: &[T]) -> bool
+    where
+        T: PartialOrd,
+    {
+        let bounds = self.bounds();
+        point.len() == self.ndim()
+            && bounds.len() == point.len()
+            && bounds
+                .iter()
+                .zip(point)
+                .all(|((min, max), x)| min <= x && x <= max)
+    }
+
+    /// Draw `n` points uniformly at random within the interpolation domain, as rows of an array,
+    /// e.g. for Monte-Carlo integration or generating test points.
+    ///
+    /// Each axis is sampled independently and uniformly within its [`Interpolator::domain_bounds`].
+    ///
+    /// # Panics
+    /// Panics if the bounds of any axis are unavailable.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![-2., 2.],
+    ///     array![[0., 1.], [2., 3.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let points = interp.sample_domain(&mut StdRng::seed_from_u64(0), 100);
+    /// assert_eq!(points.shape(), &[100, 2]);
+    /// for point in points.rows() {
+    ///     assert!(interp.interpolate(&point.to_vec()).is_ok());
+    /// }
+    /// ```
+    #[cfg(feature = "rand")]
+    fn sample_domain(&self, rng: &mut impl rand::Rng, n: usize) -> Array2<T>
+    where
+        Self: Sized,
+        T: rand::distr::uniform::SampleUniform + PartialOrd + Copy,
+    {
+        let bounds: Vec<(T, T)> = (0..self.ndim())
+            .map(|dim| {
+                self.domain_bounds(dim)
+                    .expect("domain bounds of every axis are required for sampling")
+            })
+            .collect();
+        Array2::from_shape_fn((n, bounds.len()), |(_, dim)| {
+            let (min, max) = bounds[dim];
+            rng.random_range(min..=max)
+        })
+    }
+
+    /// Interpolate at each row of `points`, pushing results onto `results`,
+    /// with cooperative cancellation for long-running batches, e.g. from a UI.
+    ///
+    /// `should_continue(i)` is called before interpolating row `i`.
+    /// If it returns `false`, interpolation stops with [`InterpolateError::Cancelled`],
+    /// and `results` holds the `i` results computed so far.
+    /// Likewise, if interpolating a row fails, `results` holds the results preceding it.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// use ninterp::error::InterpolateError;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1.],
+    ///     array![0., 2.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let points = array![[0.], [0.25], [0.5], [0.75], [1.]];
+    /// let mut results = Vec::new();
+    /// let outcome = interp.interpolate_many_with(points.view(), &mut results, |i| i < 3);
+    /// assert_eq!(outcome, Err(InterpolateError::Cancelled(3)));
+    /// assert_eq!(results, vec![0., 0.5, 1.]);
+    /// ```
+    fn interpolate_many_with(
+        &self,
+        points: ArrayView2<T>,
+        results: &mut Vec<T>,
+        mut should_continue: impl FnMut(usize) -> bool,
+    ) -> Result<(), InterpolateError>
+    where
+        Self: Sized,
+        T: Clone,
+    {
+        results.reserve(points.nrows());
+        for (i, point) in points.rows().into_iter().enumerate() {
+            if !should_continue(i) {
+                return Err(InterpolateError::Cancelled(i));
+            }
+            results.push(self.interpolate(&point.to_vec())?);
+        }
+        Ok(())
+    }
+
+    /// Evaluate a 2-D interpolator on the grid spanned by `xs` and `ys`,
+    /// where element `[i, j]` of the result corresponds to `(xs[i], ys[j])`,
+    /// e.g. for dense resampling onto a display grid.
+    ///
+    /// Fails on the first point that fails to interpolate, in row-major order.
+    /// See `Interpolator::par_eval_grid` (requires the `rayon` feature) for a parallel equivalent.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1.],
+    ///     array![[0., 1.], [2., 3.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let grid = interp
+    ///     .eval_grid(array![0., 0.5].view(), array![0.5, 1.].view())
+    ///     .unwrap();
+    /// assert_eq!(grid, array![[0.5, 1.], [1.5, 2.]]);
+    /// ```
+    fn eval_grid(&self, xs: ArrayView1<T>, ys: ArrayView1<T>) -> Result<Array2<T>, InterpolateError>
+    where
+        Self: Sized,
+        T: Copy,
+    {
+        let values = xs
+            .iter()
+            .flat_map(|&x| ys.iter().map(move |&y| self.interpolate(&[x, y])))
+            .collect::<Result<Vec<T>, _>>()?;
+        Ok(Array2::from_shape_vec((xs.len(), ys.len()), values).unwrap())
+    }
+
+    /// Parallel [`Interpolator::eval_grid`], evaluating rows of the result in parallel.
+    ///
+    /// Results are identical to, and in the same order as, [`Interpolator::eval_grid`].
+    /// If several points fail to interpolate, which of their errors is returned is unspecified.
+    #[cfg(feature = "rayon")]
+    fn par_eval_grid(
+        &self,
+        xs: ArrayView1<T>,
+        ys: ArrayView1<T>,
+    ) -> Result<Array2<T>, InterpolateError>
+    where
+        Self: Sized + Sync,
+        T: Copy + Send + Sync,
+    {
+        use rayon::prelude::*;
+        let rows = (0..xs.len())
+            .into_par_iter()
+            .map(|i| {
+                ys.iter()
+                    .map(|&y| self.interpolate(&[xs[i], y]))
+                    .collect::<Result<Vec<T>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Array2::from_shape_vec((xs.len(), ys.len()), rows.concat()).unwrap())
+    }
 }
 
 clone_trait_object!(<T> Interpolator<T>);
 
-impl<T> Interpolator<T> for Box<dyn Interpolator<T>> {
+impl<T> Interpolator<T> for Box<dyn Interpolator<T> + '_> {
     fn ndim(&self) -> usize {
         (**self).ndim()
     }
@@ -45,24 +437,243 @@ impl<T> Interpolator<T> for Box<dyn Interpolator<T>> {
     fn interpolate(&self, point: &[T]) -> Result<T, InterpolateError> {
         (**self).interpolate(point)
     }
+    fn interpolate_clamped(&self, point: &[T]) -> Result<T, InterpolateError>
+    where
+        T: PartialOrd + Clone,
+    {
+        (**self).interpolate_clamped(point)
+    }
     fn set_extrapolate(&mut self, extrapolate: Extrapolate<T>) -> Result<(), ValidateError> {
         (**self).set_extrapolate(extrapolate)
     }
+    fn is_contiguous(&self) -> bool {
+        (**self).is_contiguous()
+    }
+    fn domain_bounds(&self, dim: usize) -> Option<(T, T)> {
+        (**self).domain_bounds(dim)
+    }
+    fn contains(&self, point: &[T]) -> bool
+    where
+        T: PartialOrd,
+    {
+        (**self).contains(point)
+    }
+    fn grid(&self, dim: usize) -> Option<ArrayView1<'_, T>> {
+        (**self).grid(dim)
+    }
+    fn values_view(&self) -> Option<ArrayViewD<'_, T>> {
+        (**self).values_view()
+    }
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<T>>>
+    where
+        T: 'static,
+    {
+        (**self).to_owned_boxed()
+    }
+}
+
+/// Whether two interpolators hold the same grid and values, to within `epsilon`,
+/// regardless of their strategy and extrapolation settings.
+///
+/// Returns `false` if either interpolator is not backed by a single grid and values array
+/// (see [`Interpolator::grid`] and [`Interpolator::values_view`]), or if their shapes differ.
+///
+/// # Example
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::interpolator::data_approx_eq;
+/// use ninterp::prelude::*;
+///
+/// let linear = Interp1D::new(
+///     array![0., 1., 2.],
+///     array![0., 1., 4.],
+///     strategy::Linear,
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// let nearest = Interp1D::new(
+///     array![0., 1., 2.],
+///     array![0., 1., 4. + 1e-9],
+///     strategy::Nearest,
+///     Extrapolate::Clamp,
+/// )
+/// .unwrap();
+/// assert!(data_approx_eq(&linear, &nearest, 1e-6));
+/// ```
+pub fn data_approx_eq<T>(a: &dyn Interpolator<T>, b: &dyn Interpolator<T>, epsilon: T) -> bool
+where
+    T: Num + PartialOrd + Copy,
+{
+    let (Some(a_values), Some(b_values)) = (a.values_view(), b.values_view()) else {
+        return false;
+    };
+    a.ndim() == b.ndim()
+        && (0..a.ndim()).all(|dim| match (a.grid(dim), b.grid(dim)) {
+            (Some(a_grid), Some(b_grid)) => approx_eq(&a_grid, &b_grid, epsilon),
+            _ => false,
+        })
+        && approx_eq(&a_values, &b_values, epsilon)
+}
+
+/// Analytic gradient of the multilinear interpolant over `grid` and `values` at `point`,
+/// one partial derivative per axis.
+///
+/// Each partial derivative is the finite slope along its axis of the enclosing cell,
+/// multilinearly weighted by the position along the other axes.
+///
+/// Out-of-bounds points follow the [`Extrapolate`] setting, differentiating what
+/// the interpolator would return:
+/// - [`Extrapolate::Enable`], [`Extrapolate::EnableBounded`]: gradient of the nearest edge cell
+/// - [`Extrapolate::Fill`], [`Extrapolate::NearestValue`]: zero, as the value is constant
+/// - [`Extrapolate::Clamp`]: gradient at the clamped point, zero along clamped axes
+/// - [`Extrapolate::Wrap`]: gradient at the wrapped point
+/// - [`Extrapolate::Error`], [`Extrapolate::SaturatingFill`]: an error
+pub(crate) fn multilinear_gradient<S>(
+    grid: &[ArrayBase<S, Ix1>],
+    values: ArrayViewD<S::Elem>,
+    point: &[S::Elem],
+    extrapolate: &Extrapolate<S::Elem>,
+) -> Result<Vec<S::Elem>, InterpolateError>
+where
+    S: Data,
+    S::Elem: Num + Euclid + PartialOrd + Copy + Debug,
+{
+    let n = grid.len();
+    if point.len() != n {
+        return Err(InterpolateError::PointLength(n));
+    }
+    let mut point = point.to_vec();
+    // The value does not vary along clamped axes
+    let mut clamped = vec![false; n];
+    let mut errors = Vec::new();
+    for dim in 0..n {
+        let (min, max) = (*grid[dim].first().unwrap(), *grid[dim].last().unwrap());
+        if (min..=max).contains(&point[dim]) {
+            continue;
+        }
+        match extrapolate {
+            Extrapolate::Enable => {}
+            Extrapolate::EnableBounded(limit) => {
+                if beyond_limit(point[dim], min, max, *limit) {
+                    errors.push(format!(
+                        "\n    point[{dim}] = {:?} is more than {limit:?} beyond grid[{dim}] = {:?}",
+                        point[dim], grid[dim],
+                    ));
+                }
+            }
+            Extrapolate::Fill(_) | Extrapolate::NearestValue => {
+                return Ok(vec![S::Elem::zero(); n])
+            }
+            Extrapolate::SaturatingFill { .. } => {
+                return Err(InterpolateError::Other(format!(
+                    "{extrapolate:?} is only applicable for 1-D interpolators"
+                )))
+            }
+            Extrapolate::Clamp => {
+                point[dim] = *clamp(&point[dim], &min, &max);
+                clamped[dim] = true;
+            }
+            Extrapolate::Wrap => point[dim] = wrap(point[dim], min, max),
+            Extrapolate::Error => {
+                errors.push(format!(
+                    "\n    point[{dim}] = {:?} is out of bounds for grid[{dim}] = {:?}",
+                    point[dim], grid[dim],
+                ));
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err(InterpolateError::ExtrapolateError(errors.join("")));
+    }
+    check_brackets(grid)?;
+    let one = S::Elem::one();
+    let lowers: Vec<usize> = (0..n)
+        .map(|dim| {
+            if &point[dim] < grid[dim].first().unwrap() {
+                0
+            } else if &point[dim] > grid[dim].last().unwrap() {
+                grid[dim].len() - 2
+            } else {
+                find_nearest_index(grid[dim].view(), &point[dim])
+            }
+        })
+        .collect();
+    let widths: Vec<S::Elem> = (0..n)
+        .map(|dim| grid[dim][lowers[dim] + 1] - grid[dim][lowers[dim]])
+        .collect();
+    let diffs: Vec<S::Elem> = (0..n)
+        .map(|dim| (point[dim] - grid[dim][lowers[dim]]) / widths[dim])
+        .collect();
+    let mut gradient = vec![S::Elem::zero(); n];
+    let mut index = vec![0; n];
+    // Sum over the 2^n cell corners, bit `dim` selecting the upper corner along `dim`
+    for corner in 0..1usize << n {
+        for dim in 0..n {
+            index[dim] = lowers[dim] + (corner >> dim & 1);
+        }
+        let value = values[index.as_slice()];
+        for (k, partial) in gradient.iter_mut().enumerate() {
+            let mut term = value / widths[k];
+            for dim in (0..n).filter(|&dim| dim != k) {
+                term = term
+                    * if corner >> dim & 1 == 1 {
+                        diffs[dim]
+                    } else {
+                        one - diffs[dim]
+                    };
+            }
+            *partial = if corner >> k & 1 == 1 {
+                *partial + term
+            } else {
+                *partial - term
+            };
+        }
+    }
+    for (partial, _) in gradient.iter_mut().zip(clamped).filter(|(_, c)| *c) {
+        *partial = S::Elem::zero();
+    }
+    Ok(gradient)
 }
 
 /// Extrapolation strategy
 ///
 /// Controls what happens when supplied interpolation point
 /// is outside the bounds of the coordinate grid.
+///
+/// This enum is `#[non_exhaustive]`, as new settings may be added.
+/// Use [`Extrapolate::kind`] (or predicates such as [`Extrapolate::is_error`])
+/// to branch on the setting without matching every variant.
 #[derive(Clone, Copy, Debug, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[non_exhaustive]
 pub enum Extrapolate<T> {
     /// Evaluate beyond the grid limits. Not applicable for all strategies.
     Enable,
+    /// Evaluate beyond the grid limits, as with [`Extrapolate::Enable`],
+    /// but return an error for points more than this distance beyond the grid limits (per dimension),
+    /// guarding against absurd values far outside the data while allowing modest overshoot.
+    /// The limit must be finite and non-negative.
+    EnableBounded(T),
     /// If point is beyond grid limits, return this value instead.
     Fill(T),
+    /// Beyond grid limits, move linearly from the edge value toward `value`
+    /// at `rate` per unit distance from the grid edge, saturating once `value` is reached.
+    /// `rate` must be finite and non-negative.
+    ///
+    /// Only applicable for 1-D interpolators.
+    SaturatingFill {
+        /// Value approached (and eventually returned) beyond the grid limits.
+        value: T,
+        /// Change in value per unit distance beyond the grid limits.
+        rate: T,
+    },
     /// Restrict interpolant point to the grid limits using [`num_traits::clamp`].
     Clamp,
+    /// Return the value at the grid point nearest to the supplied point, skipping the strategy.
+    ///
+    /// Unlike [`Extrapolate::Clamp`], this never evaluates the strategy at the grid edge,
+    /// so the result is always exactly a data value.
+    NearestValue,
     /// Wrap around to other end of (periodic) data.
     /// Does NOT check that first and last values are equal.
     Wrap,
@@ -71,11 +682,115 @@ pub enum Extrapolate<T> {
     Error,
 }
 
+impl<T> Extrapolate<T> {
+    /// Classify this setting by its behavior beyond the grid limits.
+    pub fn kind(&self) -> ExtrapolateKind {
+        match self {
+            Extrapolate::Enable | Extrapolate::EnableBounded(_) => ExtrapolateKind::Evaluate,
+            Extrapolate::Fill(_) | Extrapolate::SaturatingFill { .. } => ExtrapolateKind::Fill,
+            Extrapolate::Clamp | Extrapolate::NearestValue => ExtrapolateKind::Clamp,
+            Extrapolate::Wrap => ExtrapolateKind::Wrap,
+            Extrapolate::Error => ExtrapolateKind::Error,
+        }
+    }
+
+    /// Is this setting [`ExtrapolateKind::Evaluate`]?
+    pub fn is_evaluate(&self) -> bool {
+        self.kind() == ExtrapolateKind::Evaluate
+    }
+
+    /// Is this setting [`ExtrapolateKind::Fill`]?
+    pub fn is_fill(&self) -> bool {
+        self.kind() == ExtrapolateKind::Fill
+    }
+
+    /// Is this setting [`ExtrapolateKind::Clamp`]?
+    pub fn is_clamp(&self) -> bool {
+        self.kind() == ExtrapolateKind::Clamp
+    }
+
+    /// Is this setting [`ExtrapolateKind::Wrap`]?
+    pub fn is_wrap(&self) -> bool {
+        self.kind() == ExtrapolateKind::Wrap
+    }
+
+    /// Is this setting [`ExtrapolateKind::Error`]?
+    pub fn is_error(&self) -> bool {
+        self.kind() == ExtrapolateKind::Error
+    }
+}
+
+impl<T> Extrapolate<T>
+where
+    T: Num + PartialOrd + Copy + Debug,
+{
+    /// Check that the limit of [`Extrapolate::EnableBounded`]
+    /// and the rate of [`Extrapolate::SaturatingFill`] are finite and non-negative.
+    pub(crate) fn check_limit(&self) -> Result<(), ValidateError> {
+        let (name, limit) = match *self {
+            Extrapolate::EnableBounded(limit) => ("limit", limit),
+            Extrapolate::SaturatingFill { rate, .. } => ("rate", rate),
+            _ => return Ok(()),
+        };
+        if !is_finite(limit) || limit < T::zero() {
+            return Err(ValidateError::ExtrapolateSelection(format!(
+                "{self:?}: {name} must be finite and non-negative"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Coarse classification of [`Extrapolate`] settings, see [`Extrapolate::kind`].
+///
+/// Unlike [`Extrapolate`], this enum can be matched exhaustively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExtrapolateKind {
+    /// The strategy evaluates beyond the grid limits:
+    /// [`Extrapolate::Enable`], [`Extrapolate::EnableBounded`].
+    Evaluate,
+    /// A value independent of the strategy is returned:
+    /// [`Extrapolate::Fill`], [`Extrapolate::SaturatingFill`].
+    Fill,
+    /// The point is restricted to the grid limits:
+    /// [`Extrapolate::Clamp`], [`Extrapolate::NearestValue`].
+    Clamp,
+    /// The point wraps around to the other end of the grid: [`Extrapolate::Wrap`].
+    Wrap,
+    /// An error is returned: [`Extrapolate::Error`].
+    Error,
+}
+
+/// How an interpolator would handle a query point, see e.g. [`Interp2D::extrapolation_used`].
+///
+/// Non-interior variants hold the first grid dimension for which the point is out of bounds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExtrapolationUsed {
+    /// Point is within the grid bounds.
+    Interior,
+    /// Strategy evaluates beyond the grid bounds,
+    /// per [`Extrapolate::Enable`] or [`Extrapolate::EnableBounded`].
+    Evaluated(usize),
+    /// A fill value is returned, per [`Extrapolate::Fill`] or [`Extrapolate::SaturatingFill`].
+    Filled(usize),
+    /// Point is below the grid bounds and restricted to them,
+    /// per [`Extrapolate::Clamp`] or [`Extrapolate::NearestValue`].
+    ClampedLow(usize),
+    /// Point is above the grid bounds and restricted to them,
+    /// per [`Extrapolate::Clamp`] or [`Extrapolate::NearestValue`].
+    ClampedHigh(usize),
+    /// Point wraps around to the other end of the grid, per [`Extrapolate::Wrap`].
+    Wrapped(usize),
+    /// Interpolation would return an error,
+    /// per [`Extrapolate::Error`] or beyond the limit of [`Extrapolate::EnableBounded`].
+    Rejected(usize),
+}
+
 macro_rules! extrapolate_impl {
     ($InterpType:ident, $Strategy:ident) => {
         impl<D, S> $InterpType<D, S>
         where
-            D: Data + RawDataClone + Clone,
+            D: Data + RawDataClone,
             D::Elem: PartialEq + Debug,
             S: $Strategy<D> + Clone,
         {
@@ -83,18 +798,37 @@ macro_rules! extrapolate_impl {
             pub fn check_extrapolate(
                 &self,
                 extrapolate: &Extrapolate<D::Elem>,
-            ) -> Result<(), ValidateError> {
+            ) -> Result<(), ValidateError>
+            where
+                D::Elem: Num + PartialOrd + Copy,
+            {
+                extrapolate.check_limit()?;
                 // Check applicability of strategy and extrapolate setting
-                if matches!(extrapolate, Extrapolate::Enable) && !self.strategy.allow_extrapolate()
+                if matches!(
+                    extrapolate,
+                    Extrapolate::Enable | Extrapolate::EnableBounded(_)
+                ) && !self.strategy.allow_extrapolate()
+                {
+                    return Err(ValidateError::ExtrapolateSelection(format!(
+                        "{:?}",
+                        extrapolate
+                    )));
+                }
+                // `Extrapolate::SaturatingFill` is currently only implemented for 1-D
+                if matches!(extrapolate, Extrapolate::SaturatingFill { .. })
+                    && self.data.grid.len() != 1
                 {
                     return Err(ValidateError::ExtrapolateSelection(format!(
                         "{:?}",
-                        self.extrapolate
+                        extrapolate
                     )));
                 }
-                // If using Extrapolate::Enable,
+                // If using Extrapolate::Enable or Extrapolate::EnableBounded,
                 // check that each grid dimension has at least two elements
-                if matches!(self.extrapolate, Extrapolate::Enable) {
+                if matches!(
+                    extrapolate,
+                    Extrapolate::Enable | Extrapolate::EnableBounded(_)
+                ) {
                     for (i, g) in self.data.grid.iter().enumerate() {
                         if g.len() < 2 {
                             return Err(ValidateError::Other(format!(
@@ -105,16 +839,100 @@ macro_rules! extrapolate_impl {
                 }
                 Ok(())
             }
+
+            /// Classify how a query at `point` would be handled under the current [`Extrapolate`] setting,
+            /// without interpolating, e.g. to log how often and where a simulation leaves the grid.
+            ///
+            /// Errors if the point length does not match the interpolator dimensionality.
+            pub fn extrapolation_used(
+                &self,
+                point: &[D::Elem],
+            ) -> Result<ExtrapolationUsed, InterpolateError>
+            where
+                D::Elem: Num + Euclid + PartialOrd + Copy,
+            {
+                let n = self.ndim();
+                if point.len() != n {
+                    return Err(InterpolateError::PointLength(n));
+                }
+                for (dim, pt) in point.iter().enumerate() {
+                    let grid = &self.data.grid[dim];
+                    let below = pt < grid.first().unwrap();
+                    if !below && pt <= grid.last().unwrap() {
+                        continue;
+                    }
+                    if let Extrapolate::EnableBounded(limit) = &self.extrapolate {
+                        let (min, max) = (*grid.first().unwrap(), *grid.last().unwrap());
+                        if beyond_limit(*pt, min, max, *limit) {
+                            return Ok(ExtrapolationUsed::Rejected(dim));
+                        }
+                    }
+                    return Ok(match self.extrapolate.kind() {
+                        ExtrapolateKind::Evaluate => ExtrapolationUsed::Evaluated(dim),
+                        ExtrapolateKind::Fill => ExtrapolationUsed::Filled(dim),
+                        ExtrapolateKind::Clamp if below => ExtrapolationUsed::ClampedLow(dim),
+                        ExtrapolateKind::Clamp => ExtrapolationUsed::ClampedHigh(dim),
+                        ExtrapolateKind::Wrap => ExtrapolationUsed::Wrapped(dim),
+                        ExtrapolateKind::Error => ExtrapolationUsed::Rejected(dim),
+                    });
+                }
+                Ok(ExtrapolationUsed::Interior)
+            }
         }
     };
 }
 pub(crate) use extrapolate_impl;
 
+macro_rules! index_impl {
+    ($InterpType:ident, $Strategy:ident) => {
+        impl<D, S> std::ops::Index<[usize; N]> for $InterpType<D, S>
+        where
+            D: Data + RawDataClone,
+            D::Elem: PartialEq + Debug,
+            S: $Strategy<D> + Clone,
+        {
+            type Output = D::Elem;
+
+            /// Read function value at the supplied grid indices.
+            ///
+            /// # Panics
+            /// Panics if the index is out of bounds, consistent with [`ndarray`] indexing.
+            #[inline]
+            fn index(&self, index: [usize; N]) -> &D::Elem {
+                &self.data.values[index]
+            }
+        }
+    };
+}
+pub(crate) use index_impl;
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`,
+// which e.g. `CowRepr` does not implement
+macro_rules! clone_impl {
+    ($InterpType:ident, $Strategy:ident) => {
+        impl<D, S> Clone for $InterpType<D, S>
+        where
+            D: Data + RawDataClone,
+            D::Elem: PartialEq + Debug + Clone,
+            S: $Strategy<D> + Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    data: self.data.clone(),
+                    strategy: self.strategy.clone(),
+                    extrapolate: self.extrapolate.clone(),
+                }
+            }
+        }
+    };
+}
+pub(crate) use clone_impl;
+
 macro_rules! partialeq_impl {
     ($InterpType:ident, $Data:ident, $Strategy:ident) => {
         impl<D, S> PartialEq for $InterpType<D, S>
         where
-            D: Data + RawDataClone + Clone,
+            D: Data + RawDataClone,
             D::Elem: PartialEq + Debug,
             S: $Strategy<D> + Clone + PartialEq,
             $Data<D>: PartialEq,
@@ -128,3 +946,527 @@ macro_rules! partialeq_impl {
     };
 }
 pub(crate) use partialeq_impl;
+
+macro_rules! contiguous_impl {
+    ($InterpType:ident, $Strategy:ident) => {
+        impl<T, S> $InterpType<OwnedRepr<T>, S>
+        where
+            T: PartialEq + Debug + Clone,
+            S: $Strategy<OwnedRepr<T>> + Clone,
+        {
+            /// Repack interpolator data into standard (C-order, contiguous) layout,
+            /// only copying arrays that are not already.
+            ///
+            /// Interpolators constructed from sliced or transposed arrays
+            /// may interpolate slowly until repacked, see [`Interpolator::is_contiguous`].
+            pub fn into_contiguous(self) -> Self {
+                Self {
+                    data: self.data.into_contiguous(),
+                    ..self
+                }
+            }
+        }
+    };
+}
+pub(crate) use contiguous_impl;
+
+macro_rules! set_values_impl {
+    ($InterpType:ident, $Strategy:ident, $Dim:ty) => {
+        impl<D, S> $InterpType<D, S>
+        where
+            D: Data + RawDataClone,
+            D::Elem: PartialEq + Debug,
+            S: $Strategy<D> + Clone,
+        {
+            /// Replace the function values, keeping the grid,
+            /// and re-initialize the strategy so that any cached state reflects the new values.
+            ///
+            /// Only the shape of `values` is checked against the grid,
+            /// which is not re-validated.
+            /// `values` must follow the stored grid, which is increasing even if the grid was
+            /// supplied strictly decreasing at construction (see e.g. [`InterpData1D::new`]).
+            /// On error, the interpolator is left unchanged.
+            #[must_use = "the values can be incompatible with the grid, which should be handled"]
+            pub fn set_values(&mut self, values: ArrayBase<D, $Dim>) -> Result<(), ValidateError> {
+                let grid_shape: Vec<usize> = self.data.grid.iter().map(|g| g.len()).collect();
+                if values.shape() != grid_shape.as_slice() {
+                    let dim = (0..grid_shape.len())
+                        .find(|&dim| values.shape().get(dim) != Some(&grid_shape[dim]))
+                        .unwrap_or(grid_shape.len());
+                    return Err(ValidateError::IncompatibleShapes(dim));
+                }
+                let old = std::mem::replace(&mut self.data.values, values);
+                if let Err(err) = self.strategy.init(&self.data) {
+                    self.data.values = old;
+                    // Restore strategy state for the previous values
+                    let _ = self.strategy.init(&self.data);
+                    return Err(err);
+                }
+                Ok(())
+            }
+        }
+    };
+}
+pub(crate) use set_values_impl;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate_kind() {
+        let settings = [
+            (Extrapolate::Enable, ExtrapolateKind::Evaluate),
+            (Extrapolate::EnableBounded(1.), ExtrapolateKind::Evaluate),
+            (Extrapolate::Fill(1.), ExtrapolateKind::Fill),
+            (
+                Extrapolate::SaturatingFill {
+                    value: 1.,
+                    rate: 0.5,
+                },
+                ExtrapolateKind::Fill,
+            ),
+            (Extrapolate::Clamp, ExtrapolateKind::Clamp),
+            (Extrapolate::NearestValue, ExtrapolateKind::Clamp),
+            (Extrapolate::Wrap, ExtrapolateKind::Wrap),
+            (Extrapolate::Error, ExtrapolateKind::Error),
+        ];
+        for (extrapolate, kind) in settings {
+            assert_eq!(extrapolate.kind(), kind);
+            assert_eq!(extrapolate.is_evaluate(), kind == ExtrapolateKind::Evaluate);
+            assert_eq!(extrapolate.is_fill(), kind == ExtrapolateKind::Fill);
+            assert_eq!(extrapolate.is_clamp(), kind == ExtrapolateKind::Clamp);
+            assert_eq!(extrapolate.is_wrap(), kind == ExtrapolateKind::Wrap);
+            assert_eq!(extrapolate.is_error(), kind == ExtrapolateKind::Error);
+        }
+        assert!(Extrapolate::<f64>::default().is_error());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let x = array![0., 1., 2.];
+        let y = array![-1., 0.5];
+        let interps: [Box<dyn Interpolator<f64>>; 2] = [
+            Box::new(
+                Interp1D::new(x.clone(), x.clone(), strategy::Linear, Extrapolate::Error).unwrap(),
+            ),
+            Box::new(
+                Interp2D::new(
+                    x.clone(),
+                    y.clone(),
+                    Array2::zeros((3, 2)),
+                    strategy::Linear,
+                    Extrapolate::Error,
+                )
+                .unwrap(),
+            ),
+        ];
+        assert_eq!(interps[0].bounds(), vec![(0., 2.)]);
+        assert_eq!(interps[1].bounds(), vec![(0., 2.), (-1., 0.5)]);
+        let points = [
+            [0., -1.],
+            [2., 0.5],
+            [1.5, 0.],
+            [-0.1, 0.],
+            [2.1, 0.],
+            [1., -1.5],
+            [1., 0.6],
+        ];
+        for interp in &interps {
+            for point in &points {
+                let point = &point[..interp.ndim()];
+                assert_eq!(interp.contains(point), interp.interpolate(point).is_ok());
+            }
+            assert!(!interp.contains(&[0., 0., 0.]));
+        }
+        let interp = Interp0D(1.);
+        assert!(interp.bounds().is_empty());
+        assert!(interp.contains(&[]));
+        assert!(!interp.contains(&[0.]));
+    }
+
+    /// Interpolator implementing only the required methods, as downstream code might:
+    /// `f(x, y) = x + 2y` over `[0, 1] x [0, 2]`
+    #[derive(Clone)]
+    struct Plane;
+
+    impl Interpolator<f64> for Plane {
+        fn ndim(&self) -> usize {
+            2
+        }
+
+        fn validate(&mut self) -> Result<(), ValidateError> {
+            Ok(())
+        }
+
+        fn interpolate(&self, point: &[f64]) -> Result<f64, InterpolateError> {
+            if !self.contains(point) {
+                return Err(InterpolateError::ExtrapolateError(format!("{point:?}")));
+            }
+            Ok(point[0] + 2. * point[1])
+        }
+
+        fn set_extrapolate(&mut self, _extrapolate: Extrapolate<f64>) -> Result<(), ValidateError> {
+            Ok(())
+        }
+
+        fn domain_bounds(&self, dim: usize) -> Option<(f64, f64)> {
+            [(0., 1.), (0., 2.)].get(dim).copied()
+        }
+    }
+
+    #[test]
+    fn test_provided_methods() {
+        assert_eq!(Plane.interpolate_clamped(&[0.5, 1.]).unwrap(), 2.5);
+        assert_eq!(Plane.interpolate_clamped(&[-1., 3.]).unwrap(), 4.);
+        assert!(Plane.interpolate(&[-1., 3.]).is_err());
+        assert!(matches!(
+            Plane.interpolate_clamped(&[0.]),
+            Err(InterpolateError::PointLength(2))
+        ));
+        assert!(Plane.is_contiguous());
+        assert_eq!(Plane.domain_volume(), 2.);
+    }
+
+    /// Interpolator implementing only the required methods, without reporting its bounds
+    #[derive(Clone)]
+    struct Constant;
+
+    impl Interpolator<f64> for Constant {
+        fn ndim(&self) -> usize {
+            1
+        }
+
+        fn validate(&mut self) -> Result<(), ValidateError> {
+            Ok(())
+        }
+
+        fn interpolate(&self, _point: &[f64]) -> Result<f64, InterpolateError> {
+            Ok(1.)
+        }
+
+        fn set_extrapolate(&mut self, _extrapolate: Extrapolate<f64>) -> Result<(), ValidateError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_unavailable_bounds() {
+        assert_eq!(Constant.domain_bounds(0), None);
+        assert_eq!(Constant.domain_span(0), None);
+        assert!(Constant.bounds().is_empty());
+        assert!(!Constant.contains(&[0.]));
+        assert!(matches!(
+            Constant.interpolate_clamped(&[0.]),
+            Err(InterpolateError::Other(_))
+        ));
+    }
+
+    #[test]
+    fn test_interpolate_many_with() {
+        let interp: Box<dyn Interpolator<f64>> = Box::new(
+            Interp2D::new(
+                array![0., 1.],
+                array![0., 1.],
+                array![[0., 1.], [2., 3.]],
+                strategy::Linear,
+                Extrapolate::Error,
+            )
+            .unwrap(),
+        );
+        let points = Array2::from_shape_fn((10, 2), |(i, j)| (i + j) as f64 / 10.);
+        let mut results = Vec::new();
+        interp
+            .interpolate_many_with(points.view(), &mut results, |_| true)
+            .unwrap();
+        assert_eq!(results.len(), 10);
+        for n in [0, 1, 4, 9] {
+            let mut results = Vec::new();
+            let mut calls = 0;
+            let outcome = interp.interpolate_many_with(points.view(), &mut results, |i| {
+                calls += 1;
+                i < n
+            });
+            assert_eq!(outcome, Err(InterpolateError::Cancelled(n)));
+            assert_eq!(calls, n + 1);
+            assert_eq!(results.len(), n);
+            for (point, result) in points.rows().into_iter().zip(&results) {
+                assert_eq!(*result, interp.interpolate(&point.to_vec()).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_extrapolate() {
+        let x = array![0., 1.];
+        let interps: [Box<dyn Interpolator<f64>>; 5] = [
+            Box::new(Interp0D::new(0.5)),
+            Box::new(
+                Interp1D::new(x.clone(), x.clone(), strategy::Linear, Extrapolate::Error).unwrap(),
+            ),
+            Box::new(
+                Interp2D::new(
+                    x.clone(),
+                    x.clone(),
+                    array![[0., 1.], [2., 3.]],
+                    strategy::Linear,
+                    Extrapolate::Error,
+                )
+                .unwrap(),
+            ),
+            Box::new(
+                Interp3D::new(
+                    x.clone(),
+                    x.clone(),
+                    x.clone(),
+                    Array3::zeros((2, 2, 2)),
+                    strategy::Linear,
+                    Extrapolate::Error,
+                )
+                .unwrap(),
+            ),
+            Box::new(
+                InterpND::new(
+                    vec![x.clone()],
+                    x.clone().into_dyn(),
+                    strategy::Linear,
+                    Extrapolate::Error,
+                )
+                .unwrap(),
+            ),
+        ];
+        for interp in interps {
+            let point = vec![2.; interp.ndim()];
+            let clamped = interp.interpolate_clamped(&point).unwrap();
+            let interp = interp.with_extrapolate(Extrapolate::Clamp).unwrap();
+            assert_eq!(interp.interpolate(&point).unwrap(), clamped);
+        }
+        // Invalid settings are rejected
+        assert!(matches!(
+            Interp1D::new(x.clone(), x.clone(), strategy::Nearest, Extrapolate::Error)
+                .unwrap()
+                .with_extrapolate(Extrapolate::Enable)
+                .unwrap_err(),
+            ValidateError::ExtrapolateSelection(_)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_sample_domain() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let interp = Interp3D::new(
+            array![0., 1.],
+            array![-3., 0., 1.],
+            array![10., 20.],
+            Array3::zeros((2, 3, 2)),
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let n = 10_000;
+        let points = interp.sample_domain(&mut StdRng::seed_from_u64(42), n);
+        assert_eq!(points.shape(), &[n, 3]);
+        for (dim, axis) in points.columns().into_iter().enumerate() {
+            let (min, max) = interp.domain_bounds(dim).unwrap();
+            assert!(axis.iter().all(|x| (min..=max).contains(x)));
+            let span = max - min;
+            let mean = axis.mean().unwrap();
+            assert!((mean - (min + max) / 2.).abs() < 0.02 * span);
+        }
+        // 0-D interpolators sample empty points
+        let points = Interp0D::new(1.).sample_domain(&mut StdRng::seed_from_u64(42), 3);
+        assert_eq!(points.shape(), &[3, 0]);
+    }
+
+    #[test]
+    fn test_data_approx_eq() {
+        let x = array![0., 1., 2.];
+        let y = array![-1., 1.];
+        let f_xy = array![[0., 1.], [2., 3.], [4., 5.]];
+        let linear = Interp2D::new(
+            x.view(),
+            y.view(),
+            f_xy.view(),
+            strategy::Linear,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        let nearest = Interp2D::new(
+            x.view(),
+            y.view(),
+            f_xy.view(),
+            strategy::Nearest,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert!(data_approx_eq(&linear, &nearest, 0.));
+        // Across interpolator types with the same data
+        let nd = InterpND::new(
+            vec![x.view(), y.view()],
+            f_xy.view().into_dyn(),
+            strategy::Nearest,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert!(data_approx_eq(&nd, &linear, 0.));
+        // Tolerance
+        let perturbed = Interp2D::new(
+            x.clone(),
+            y.clone(),
+            &f_xy + 1e-3,
+            strategy::Linear,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        assert!(!data_approx_eq(&linear, &perturbed, 1e-4));
+        assert!(data_approx_eq(&linear, &perturbed, 1e-2));
+        // Differing shapes
+        let transposed = Interp2D::new(
+            y.view(),
+            x.view(),
+            f_xy.t(),
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert!(!data_approx_eq(&linear, &transposed, 1e6));
+        // 0-D
+        assert!(data_approx_eq(&Interp0D::new(1.), &Interp0D::new(1.), 0.));
+        assert!(!data_approx_eq(&Interp0D::new(1.), &linear, 1e6));
+    }
+
+    #[test]
+    fn test_values_flat() {
+        // Logically [[0, 1, 2], [3, 4, 5]], stored column-major
+        let values = array![[0., 3.], [1., 4.], [2., 5.]].reversed_axes();
+        assert!(!values.is_standard_layout());
+        let interp = Interp2D::new(
+            array![0., 1.],
+            array![0., 1., 2.],
+            values,
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let expected = vec![0., 1., 2., 3., 4., 5.];
+        assert_eq!(interp.values_flat(), expected);
+        let boxed: Box<dyn Interpolator<f64>> = Box::new(interp);
+        assert_eq!(boxed.values_flat(), expected);
+        assert_eq!(Interp0D(1.5).values_flat(), vec![1.5]);
+    }
+
+    #[test]
+    fn test_eval_grid() {
+        let interp = Interp2D::new(
+            array![0., 1., 3.],
+            array![0., 2.],
+            array![[0., 2.], [1., 3.], [5., 4.]],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let xs = Array1::linspace(0., 3., 7);
+        let ys = Array1::linspace(0., 2., 5);
+        let grid = interp.eval_grid(xs.view(), ys.view()).unwrap();
+        assert_eq!(grid.dim(), (7, 5));
+        for (i, x) in xs.iter().enumerate() {
+            for (j, y) in ys.iter().enumerate() {
+                assert_eq!(grid[[i, j]], interp.interpolate(&[*x, *y]).unwrap());
+            }
+        }
+        assert!(interp.eval_grid(xs.view(), array![0., 3.].view()).is_err());
+        #[cfg(feature = "rayon")]
+        {
+            let par_grid = interp.par_eval_grid(xs.view(), ys.view()).unwrap();
+            assert!(par_grid
+                .iter()
+                .zip(&grid)
+                .all(|(a, b): (&f64, &f64)| a.to_bits() == b.to_bits()));
+            assert!(interp
+                .par_eval_grid(xs.view(), array![0., 3.].view())
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn test_to_owned_boxed() {
+        let x = array![0., 1., 2.];
+        let y = array![0., 2.];
+        let f_xy = array![[0., 2.], [1., 3.], [2., 4.]];
+        let viewed: Box<dyn Interpolator<f64> + '_> = Box::new(
+            enums::InterpolatorEnum::new_2d(
+                x.view(),
+                y.view(),
+                f_xy.view(),
+                strategy::Linear,
+                Extrapolate::Clamp,
+            )
+            .unwrap(),
+        );
+        let points = [[0.5, 1.], [1.5, 0.25], [3., -1.]];
+        let expected = points.map(|p| viewed.interpolate(&p).unwrap());
+        let owned = viewed.to_owned_boxed().unwrap();
+        drop(viewed);
+        drop((x, y, f_xy));
+        for (point, expected) in points.iter().zip(expected) {
+            assert_eq!(owned.interpolate(point).unwrap(), expected);
+        }
+        // 0-D
+        let owned = Interp0D::new(0.5).to_owned_boxed().unwrap();
+        assert_eq!(owned.interpolate(&[]).unwrap(), 0.5);
+        // Concrete interpolators with strategy enum variants
+        let x = array![0., 1., 2.];
+        let f_x = array![0., 10., 0.];
+        let f_xyz = Array3::from_shape_fn((3, 3, 3), |(i, j, k)| (i + 2 * j + 3 * k) as f64);
+        let owned: Vec<Box<dyn Interpolator<f64>>> = vec![
+            Interp1D::new(x.view(), f_x.view(), strategy::Linear, Extrapolate::Error)
+                .unwrap()
+                .to_owned_boxed()
+                .unwrap(),
+            Interp2D::new(
+                x.view(),
+                x.view(),
+                f_xyz.slice(s![.., .., 0]),
+                strategy::Linear,
+                Extrapolate::Error,
+            )
+            .unwrap()
+            .to_owned_boxed()
+            .unwrap(),
+            Interp3D::new(
+                x.view(),
+                x.view(),
+                x.view(),
+                f_xyz.view(),
+                strategy::Nearest,
+                Extrapolate::Clamp,
+            )
+            .unwrap()
+            .to_owned_boxed()
+            .unwrap(),
+            InterpND::new(
+                vec![x.view(), x.view(), x.view()],
+                f_xyz.view().into_dyn(),
+                strategy::Linear,
+                Extrapolate::Error,
+            )
+            .unwrap()
+            .to_owned_boxed()
+            .unwrap(),
+        ];
+        let interp = Interp1D::new(
+            x.view(),
+            f_x.view(),
+            strategy::PolyFit::new(1),
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert!(interp.to_owned_boxed().is_none());
+        drop((x, f_x, f_xyz));
+        assert_eq!(owned[0].interpolate(&[0.5]).unwrap(), 5.);
+        assert_eq!(owned[1].interpolate(&[0.5, 1.]).unwrap(), 2.5);
+        assert_eq!(owned[2].interpolate(&[1.2, 3., 0.]).unwrap(), 5.);
+        assert_eq!(owned[3].interpolate(&[0.5, 1., 1.5]).unwrap(), 7.);
+    }
+}
@@ -11,7 +11,7 @@ mod tests;
 /// Interpolator data for N-dimensional interpolators, where N can vary at runtime.
 ///
 /// See [`InterpData`] and its aliases for concrete-dimensionality interpolator data structs.
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(
     feature = "serde",
@@ -25,7 +25,7 @@ mod tests;
 )]
 pub struct InterpDataND<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Coordinate grid: a vector of 1-dimensional [`ArrayBase<D, Ix1>`].
@@ -37,10 +37,26 @@ where
 pub type InterpDataNDViewed<T> = InterpDataND<ViewRepr<T>>;
 /// [`InterpDataND`] that owns data.
 pub type InterpDataNDOwned<T> = InterpDataND<OwnedRepr<T>>;
+/// [`InterpDataND`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type InterpDataNDCow<'a, T> = InterpDataND<CowRepr<'a, T>>;
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`
+impl<D> Clone for InterpDataND<D>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid.clone(),
+            values: self.values.clone(),
+        }
+    }
+}
 
 impl<D> PartialEq for InterpDataND<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
     ArrayBase<D, Ix1>: PartialEq,
 {
@@ -51,10 +67,15 @@ where
 
 impl<D> InterpDataND<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Construct and validate a new [`InterpDataND`].
+    ///
+    /// Strictly decreasing grid axes are accepted, and reversed along with the corresponding
+    /// axis of the values (by negating strides, without copying),
+    /// so that the stored grid is always increasing.
+    /// Arrays supplied later, e.g. to `set_values`, must follow the stored, increasing order.
     pub fn new(
         grid: Vec<ArrayBase<D, Ix1>>,
         values: ArrayBase<D, IxDyn>,
@@ -62,7 +83,8 @@ where
     where
         D::Elem: PartialOrd,
     {
-        let data = Self { grid, values };
+        let mut data = Self { grid, values };
+        orient_ascending(&mut data.grid, &mut data.values);
         data.validate()?;
         Ok(data)
     }
@@ -100,15 +122,81 @@ where
         Ok(())
     }
 
+    /// Validate interpolator data, with additional checks for mismatched or malformed views.
+    /// See [`InterpData::validate_strict`].
+    pub fn validate_strict(&self) -> Result<(), ValidateError>
+    where
+        D::Elem: PartialOrd,
+    {
+        self.validate()?;
+        validate_views(&self.grid, &self.values)
+    }
+
+    /// Coordinate grid, one 1-D array per axis.
+    pub fn grid(&self) -> &[ArrayBase<D, Ix1>] {
+        &self.grid
+    }
+
+    /// View of the grid along axis `dim`, or `None` if out of range.
+    pub fn axis(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        self.grid.get(dim).map(|g| g.view())
+    }
+
+    /// View of the function values, of dynamic dimensionality.
+    pub fn values(&self) -> ArrayViewD<'_, D::Elem> {
+        self.values.view().into_dyn()
+    }
+
+    /// Shape of the function values, i.e. the grid length along each axis.
+    pub fn shape(&self) -> Vec<usize> {
+        self.values.shape().to_vec()
+    }
+
     /// Get data dimensionality.
+    ///
+    /// Data is 0-dimensional if it contains a single value and no grid coordinates.
+    /// Singleton axes (grid dimensions of length 1) still count toward dimensionality.
     pub fn ndim(&self) -> usize {
-        if self.values.len() == 1 {
+        if self.values.len() == 1 && self.grid.iter().all(|g| g.is_empty()) {
             0
         } else {
             self.values.ndim()
         }
     }
 
+    /// Are the grid and values stored in standard (C-order, contiguous) layout?
+    ///
+    /// Data constructed from sliced or transposed arrays may not be,
+    /// which slows interpolation until repacked (see [`InterpDataND::into_contiguous`]).
+    pub fn is_contiguous(&self) -> bool {
+        self.grid.iter().all(|g| g.is_standard_layout()) && self.values.is_standard_layout()
+    }
+
+    /// Is the grid identical to that of `other`, including its dimensionality?
+    ///
+    /// Operations combining the values of two interpolators require this.
+    pub fn same_grid<D2>(&self, other: &InterpDataND<D2>) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+    {
+        self.grid.len() == other.grid.len()
+            && self.grid.iter().zip(&other.grid).all(|(a, b)| a == b)
+    }
+
+    /// Is the grid identical to that of `other`, including its dimensionality, to within `epsilon`?
+    pub fn same_grid_approx<D2>(&self, other: &InterpDataND<D2>, epsilon: D::Elem) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        D::Elem: Num + PartialOrd + Copy,
+    {
+        self.grid.len() == other.grid.len()
+            && self
+                .grid
+                .iter()
+                .zip(&other.grid)
+                .all(|(a, b)| approx_eq(a, b, epsilon))
+    }
+
     /// View interpolator data.
     pub fn view(&self) -> InterpDataNDViewed<&D::Elem> {
         InterpDataNDViewed {
@@ -129,8 +217,22 @@ where
     }
 }
 
+impl<T> InterpDataNDOwned<T>
+where
+    T: PartialEq + Debug + Clone,
+{
+    /// Repack the grid and values into standard (C-order, contiguous) layout,
+    /// only copying arrays that are not already.
+    pub fn into_contiguous(self) -> Self {
+        Self {
+            grid: self.grid.into_iter().map(into_standard_layout).collect(),
+            values: into_standard_layout(self.values),
+        }
+    }
+}
+
 /// N-D interpolator
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(
     feature = "serde",
@@ -148,7 +250,7 @@ where
 )]
 pub struct InterpND<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
     S: StrategyND<D> + Clone,
 {
@@ -164,18 +266,27 @@ where
 pub type InterpNDViewed<T, S> = InterpND<ViewRepr<T>, S>;
 /// [`InterpND`] that owns data.
 pub type InterpNDOwned<T, S> = InterpND<OwnedRepr<T>, S>;
+/// [`InterpND`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type InterpNDCow<'a, T, S> = InterpND<CowRepr<'a, T>, S>;
 
 extrapolate_impl!(InterpND, StrategyND);
 partialeq_impl!(InterpND, InterpDataND, StrategyND);
+clone_impl!(InterpND, StrategyND);
+contiguous_impl!(InterpND, StrategyND);
+set_values_impl!(InterpND, StrategyND, IxDyn);
 
 impl<D, S> InterpND<D, S>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialOrd + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
     S: StrategyND<D> + Clone,
 {
     /// Construct and validate an N-D (any dimensionality) interpolator.
     ///
+    /// Grid dimensions of length 1 (singleton axes) are supported,
+    /// acting as a fixed slice: the point coordinate for such an axis must equal its single grid value,
+    /// otherwise it is handled per the [`Extrapolate`] setting (e.g. [`Extrapolate::Clamp`]).
+    ///
     /// Applicable interpolation strategies:
     /// - [`strategy::Linear`]
     /// - [`strategy::Nearest`]
@@ -222,6 +333,7 @@ where
     ///     ninterp::error::InterpolateError::ExtrapolateError(_)
     /// ));
     /// ```
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new(
         grid: Vec<ArrayBase<D, Ix1>>,
         values: ArrayBase<D, IxDyn>,
@@ -238,6 +350,27 @@ where
         Ok(interpolator)
     }
 
+    /// Does `other` have an identical grid, e.g. to combine their values?
+    /// See [`InterpDataND::same_grid`].
+    pub fn same_grid<D2, S2>(&self, other: &InterpND<D2, S2>) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        S2: StrategyND<D2> + Clone,
+    {
+        self.data.same_grid(&other.data)
+    }
+
+    /// Does `other` have an identical grid, to within `epsilon`?
+    /// See [`InterpDataND::same_grid_approx`].
+    pub fn same_grid_approx<D2, S2>(&self, other: &InterpND<D2, S2>, epsilon: D::Elem) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        D::Elem: Num + Copy,
+        S2: StrategyND<D2> + Clone,
+    {
+        self.data.same_grid_approx(&other.data, epsilon)
+    }
+
     /// Return an interpolator with viewed data.
     pub fn view(&self) -> InterpNDViewed<&D::Elem, S>
     where
@@ -247,7 +380,7 @@ where
         InterpNDViewed {
             data: self.data.view(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
         }
     }
 
@@ -260,14 +393,143 @@ where
         InterpNDOwned {
             data: self.data.into_owned(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
+        }
+    }
+}
+
+/// Structured diagnostics for constructing an [`InterpND`], see [`InterpND::diagnose`].
+///
+/// Per-axis fields are indexed by the supplied grid axes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructionReport {
+    /// Dimensionality inferred from the values array.
+    pub ndim: usize,
+    /// Does the number of grid axes match [`ConstructionReport::ndim`]?
+    pub grid_length_ok: bool,
+    /// Does each grid axis have elements?
+    pub nonempty: Vec<bool>,
+    /// Is each grid axis sorted?
+    pub monotonic: Vec<bool>,
+    /// Does each grid axis length match the corresponding values axis length?
+    pub shape_compatible: Vec<bool>,
+    /// Is the [`Extrapolate`] setting applicable to the strategy and data?
+    pub extrapolate_applicable: bool,
+    /// Did the strategy initialize successfully?
+    /// Only attempted if the data is valid, otherwise `false`.
+    pub strategy_initialized: bool,
+    /// Messages describing each failed check.
+    pub messages: Vec<String>,
+}
+
+impl ConstructionReport {
+    /// Did all checks pass, i.e. would [`InterpND::new`] succeed?
+    pub fn is_ok(&self) -> bool {
+        self.messages.is_empty()
+    }
+}
+
+impl<D, S> InterpND<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+    S: StrategyND<D> + Clone,
+{
+    /// Report on the validity of interpolator inputs without constructing an interpolator,
+    /// e.g. for tools validating user-provided tables.
+    ///
+    /// Unlike [`InterpND::new`], all checks are run rather than stopping at the first failure.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let report = InterpND::diagnose(
+    ///     vec![array![0., 2., 1.], array![0., 1.]],
+    ///     array![[0., 1.], [2., 3.], [4., 5.]].into_dyn(),
+    ///     strategy::Nearest,
+    ///     Extrapolate::Enable,
+    /// );
+    /// assert!(!report.is_ok());
+    /// assert_eq!(report.monotonic, vec![false, true]);
+    /// assert!(!report.extrapolate_applicable);
+    /// ```
+    pub fn diagnose(
+        grid: Vec<ArrayBase<D, Ix1>>,
+        values: ArrayBase<D, IxDyn>,
+        strategy: S,
+        extrapolate: Extrapolate<D::Elem>,
+    ) -> ConstructionReport {
+        let mut interpolator = Self {
+            data: InterpDataND { grid, values },
+            strategy,
+            extrapolate,
+        };
+        let data = &interpolator.data;
+        let mut messages = Vec::new();
+        let ndim = data.ndim();
+        let grid_length_ok =
+            data.grid.len() == ndim || (ndim == 0 && data.grid.iter().all(|g| g.is_empty()));
+        if !grid_length_ok {
+            messages.push(format!(
+                "grid length {} does not match dimensionality {ndim}",
+                data.grid.len(),
+            ));
+        }
+        let mut nonempty = Vec::with_capacity(data.grid.len());
+        let mut monotonic = Vec::with_capacity(data.grid.len());
+        let mut shape_compatible = Vec::with_capacity(data.grid.len());
+        for (i, g) in data.grid.iter().enumerate() {
+            nonempty.push(ndim == 0 || !g.is_empty());
+            if !nonempty[i] {
+                messages.push(ValidateError::EmptyGrid(i).to_string());
+            }
+            monotonic.push(g.windows(2).into_iter().all(|w| w[0] <= w[1]));
+            if !monotonic[i] {
+                messages.push(ValidateError::Monotonicity(i).to_string());
+            }
+            shape_compatible.push(ndim == 0 || data.values.shape().get(i) == Some(&g.len()));
+            if !shape_compatible[i] {
+                messages.push(ValidateError::IncompatibleShapes(i).to_string());
+            }
+        }
+        let data_ok = messages.is_empty();
+        let extrapolate_applicable = match interpolator.check_extrapolate(&interpolator.extrapolate)
+        {
+            Ok(()) => true,
+            Err(err) => {
+                messages.push(err.to_string());
+                false
+            }
+        };
+        // Strategies may assume valid data, so only initialize if all data checks passed
+        let strategy_initialized = if data_ok {
+            match interpolator.strategy.init(&interpolator.data) {
+                Ok(()) => true,
+                Err(err) => {
+                    messages.push(err.to_string());
+                    false
+                }
+            }
+        } else {
+            false
+        };
+        ConstructionReport {
+            ndim,
+            grid_length_ok,
+            nonempty,
+            monotonic,
+            shape_compatible,
+            extrapolate_applicable,
+            strategy_initialized,
+            messages,
         }
     }
 }
 
 impl<D, S> Interpolator<D::Elem> for InterpND<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
     S: StrategyND<D> + Clone,
 {
@@ -288,6 +550,9 @@ where
         if point.len() != n {
             return Err(InterpolateError::PointLength(n));
         }
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
         let mut errors = Vec::new();
         for dim in 0..n {
             if !(self.data.grid[dim].first().unwrap()..=self.data.grid[dim].last().unwrap())
@@ -295,7 +560,31 @@ where
             {
                 match &self.extrapolate {
                     Extrapolate::Enable => {}
+                    Extrapolate::EnableBounded(limit) => {
+                        let grid = &self.data.grid[dim];
+                        let (min, max) = (grid.first().unwrap(), grid.last().unwrap());
+                        if beyond_limit(point[dim], *min, *max, *limit) {
+                            errors.push(format!(
+                                "\n    point[{dim}] = {:?} is more than {limit:?} beyond grid[{dim}] = {grid:?}",
+                                point[dim],
+                            ));
+                        }
+                    }
                     Extrapolate::Fill(value) => return Ok(*value),
+                    Extrapolate::SaturatingFill { .. } => {
+                        return Err(InterpolateError::Other(format!(
+                            "{:?} is only applicable for 1-D interpolators",
+                            self.extrapolate
+                        )))
+                    }
+                    Extrapolate::NearestValue => {
+                        let index: Vec<_> = point
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &pt)| nearest_index(self.data.grid[i].view(), pt))
+                            .collect();
+                        return Ok(self.data.values[index.as_slice()]);
+                    }
                     Extrapolate::Clamp => {
                         let clamped_point: Vec<_> = point
                             .iter()
@@ -315,11 +604,15 @@ where
                             .iter()
                             .enumerate()
                             .map(|(dim, pt)| {
-                                wrap(
-                                    *pt,
-                                    *self.data.grid[dim].first().unwrap(),
-                                    *self.data.grid[dim].last().unwrap(),
-                                )
+                                let min = self.data.grid[dim].first().unwrap();
+                                let max = self.data.grid[dim].last().unwrap();
+                                // Leave in-bounds coordinates untouched,
+                                // as wrapping maps a coordinate exactly at `max` to `min`
+                                if (min..=max).contains(&pt) {
+                                    *pt
+                                } else {
+                                    wrap(*pt, *min, *max)
+                                }
                             })
                             .collect();
                         return self.strategy.interpolate(&self.data, &wrapped_point);
@@ -339,19 +632,178 @@ where
         self.strategy.interpolate(&self.data, point)
     }
 
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let n = self.ndim();
+        if point.len() != n {
+            return Err(InterpolateError::PointLength(n));
+        }
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let clamped_point: Vec<_> = point
+            .iter()
+            .enumerate()
+            .map(|(dim, pt)| {
+                *clamp(
+                    pt,
+                    self.data.grid[dim].first().unwrap(),
+                    self.data.grid[dim].last().unwrap(),
+                )
+            })
+            .collect();
+        self.strategy.interpolate(&self.data, &clamped_point)
+    }
+
     fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
         self.check_extrapolate(&extrapolate)?;
         self.extrapolate = extrapolate;
         Ok(())
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.data.is_contiguous()
+    }
+
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        if dim >= self.ndim() {
+            return None;
+        }
+        let grid = &self.data.grid[dim];
+        Some((*grid.first().unwrap(), *grid.last().unwrap()))
+    }
+
+    #[inline]
+    fn grid(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        self.data.grid.get(dim).map(|grid| grid.view())
+    }
+
+    #[inline]
+    fn values_view(&self) -> Option<ArrayViewD<'_, D::Elem>> {
+        Some(self.data.values.view().into_dyn())
+    }
+
+    /// Returns `Some` if the strategy is a [`StrategyNDEnum`](strategy::enums::StrategyNDEnum)
+    /// or one of its variants' strategies, converting it to the enum.
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<D::Elem>>>
+    where
+        D::Elem: 'static,
+    {
+        let strategy = strategy::enums::StrategyNDEnum::from_any(self.strategy.as_any()?)?;
+        Some(Box::new(InterpND {
+            data: self.data.view().into_owned(),
+            strategy,
+            extrapolate: self.extrapolate,
+        }))
+    }
+}
+
+impl<D, S> InterpND<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
+    S: StrategyND<D> + Clone,
+{
+    /// Interpolate along a path of points, e.g. a simulation trajectory through the table.
+    ///
+    /// Each row of `path` is an N-D point.
+    /// Bracket indices from the previous point are reused when consecutive points share a grid cell
+    /// (see [`StrategyND::interpolate_with_hint`]),
+    /// so smooth paths avoid most of the per-point index searching.
+    /// Results are identical to calling [`Interpolator::interpolate`] on each point.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = InterpND::new(
+    ///     vec![array![0., 1., 2.], array![0., 1.]],
+    ///     array![[0., 1.], [2., 3.], [4., 5.]].into_dyn(),
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let path = array![[0.25, 0.5], [0.5, 0.5], [1.5, 0.5]];
+    /// assert_eq!(
+    ///     interp.interpolate_path(&path.view()).unwrap(),
+    ///     array![1.0, 1.5, 3.5]
+    /// );
+    /// ```
+    pub fn interpolate_path(
+        &self,
+        path: &ArrayView2<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        let n = self.ndim();
+        if path.ncols() != n {
+            return Err(InterpolateError::PointLength(n));
+        }
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let mut hint = vec![0; n];
+        let mut out = Vec::with_capacity(path.nrows());
+        for row in path.rows() {
+            let point = row.to_vec();
+            let in_bounds = point
+                .iter()
+                .zip(&self.data.grid)
+                .all(|(pt, grid)| (grid.first().unwrap()..=grid.last().unwrap()).contains(&pt));
+            out.push(if in_bounds {
+                self.strategy
+                    .interpolate_with_hint(&self.data, &point, &mut hint)?
+            } else {
+                self.interpolate(&point)?
+            });
+        }
+        Ok(Array1::from_vec(out))
+    }
+}
+
+impl<D> InterpND<D, strategy::Linear>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Copy + Debug,
+{
+    /// Analytic gradient of the multilinear interpolant at `point`,
+    /// with one partial derivative per axis, i.e. of length [`InterpND::ndim`].
+    ///
+    /// Each partial derivative is the slope along its axis of the enclosing cell,
+    /// varying linearly with the position along the other axes.
+    /// At interior grid lines the gradient is discontinuous,
+    /// and the cell below the line is used (except at the upper grid edge).
+    /// Out-of-bounds points follow the [`Extrapolate`] setting, as for [`Interp2D::gradient`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// // f(x, y) = 2 * x - y
+    /// let interp = InterpND::new(
+    ///     vec![array![0., 1., 2.], array![0., 1.]],
+    ///     array![[0., -1.], [2., 1.], [4., 3.]].into_dyn(),
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.gradient(&[1.5, 0.2]).unwrap(), vec![2., -1.]);
+    /// ```
+    pub fn gradient(&self, point: &[D::Elem]) -> Result<Vec<D::Elem>, InterpolateError> {
+        multilinear_gradient(
+            &self.data.grid,
+            self.data.values.view(),
+            point,
+            &self.extrapolate,
+        )
+    }
 }
 
 impl<D> InterpND<D, Box<dyn StrategyND<D>>>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialEq + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(&mut self, strategy: Box<dyn StrategyND<D>>) -> Result<(), ValidateError> {
         self.strategy = strategy;
         self.check_extrapolate(&self.extrapolate)
@@ -360,10 +812,11 @@ where
 
 impl<D> InterpND<D, strategy::enums::StrategyNDEnum>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(
         &mut self,
         strategy: impl Into<strategy::enums::StrategyNDEnum>,
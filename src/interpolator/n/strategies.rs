@@ -14,16 +14,18 @@ pub fn get_index_permutations(shape: &[usize]) -> Vec<Vec<usize>> {
         .collect()
 }
 
-impl<D> StrategyND<D> for Linear
-where
-    D: Data + RawDataClone + Clone,
-    D::Elem: Num + PartialOrd + Copy + Debug,
-{
-    fn interpolate(
-        &self,
+impl Linear {
+    /// N-D linear interpolation, with `lower_index` supplying the lower bracket index
+    /// for an in-bounds coordinate, given its original dimension index.
+    fn interpolate_nd<D>(
         data: &InterpDataND<D>,
         point: &[D::Elem],
-    ) -> Result<D::Elem, InterpolateError> {
+        mut lower_index: impl FnMut(usize, ArrayView1<D::Elem>, &D::Elem) -> usize,
+    ) -> Result<D::Elem, InterpolateError>
+    where
+        D: Data + RawDataClone,
+        D::Elem: Num + PartialOrd + Copy + Debug,
+    {
         // Dimensionality
         let mut n = data.values.ndim();
 
@@ -32,6 +34,7 @@ where
         // or   if the point shares two of three values of a 3-D grid point, then the interpolation becomes 1-D
         let mut point = point.to_owned();
         let mut grid: Vec<_> = data.grid.iter().map(|arr| arr.view()).collect();
+        let mut dims: Vec<usize> = (0..n).collect();
         let mut values_view = data.values.view();
         for dim in (0..n).rev() {
             // Range is reversed so that removal doesn't affect indexing
@@ -41,6 +44,7 @@ where
             {
                 point.remove(dim);
                 grid.remove(dim);
+                dims.remove(dim);
                 values_view.index_axis_inplace(Axis(dim), pos);
             }
         }
@@ -48,6 +52,8 @@ where
             // Supplied point is coincident with a grid point, so just return the value
             return Ok(values_view.first().copied().unwrap());
         }
+        // Remaining dimensions need a bracketing pair of grid points
+        check_brackets(&grid)?;
         // Simplified dimensionality
         n = values_view.ndim();
 
@@ -63,7 +69,7 @@ where
             } else if &point[dim] > grid[dim].last().unwrap() {
                 grid[dim].len() - 2
             } else {
-                find_nearest_index(grid[dim].view(), &point[dim])
+                lower_index(dims[dim], grid[dim].view(), &point[dim])
             };
             let interp_diff = (point[dim] - grid[dim][lower_idx])
                 / (grid[dim][lower_idx + 1] - grid[dim][lower_idx]);
@@ -106,16 +112,54 @@ where
         // return the only value contained within the 0-dimensional array
         Ok(interp_vals.first().copied().unwrap())
     }
+}
+
+impl<D> StrategyND<D> for Linear
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    fn interpolate(
+        &self,
+        data: &InterpDataND<D>,
+        point: &[D::Elem],
+    ) -> Result<D::Elem, InterpolateError> {
+        Self::interpolate_nd(data, point, |_, grid, target| {
+            find_nearest_index(grid, target)
+        })
+    }
+
+    /// Checks whether the hinted cell still brackets each coordinate before searching.
+    fn interpolate_with_hint(
+        &self,
+        data: &InterpDataND<D>,
+        point: &[D::Elem],
+        hint: &mut [usize],
+    ) -> Result<D::Elem, InterpolateError> {
+        Self::interpolate_nd(data, point, |dim, grid, target| {
+            let lower = hint[dim];
+            if lower + 1 < grid.len() && &grid[lower] <= target && target <= &grid[lower + 1] {
+                return lower;
+            }
+            let lower = find_nearest_index(grid, target);
+            hint[dim] = lower;
+            lower
+        })
+    }
 
     /// Returns `true`.
     fn allow_extrapolate(&self) -> bool {
         true
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
 }
 
 impl<D> StrategyND<D> for Nearest
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -147,6 +191,8 @@ where
             // Supplied point is coincident with a grid point, so just return the value
             return Ok(values_view.first().copied().unwrap());
         }
+        // Remaining dimensions need a bracketing pair of grid points
+        check_brackets(&grid)?;
         // Simplified dimensionality
         n = values_view.ndim();
 
@@ -205,4 +251,8 @@ where
     fn allow_extrapolate(&self) -> bool {
         false
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
 }
@@ -318,6 +318,20 @@ fn test_extrapolate_clamp() {
     );
 }
 
+#[test]
+fn test_interpolate_clamped() {
+    let interp = InterpND::new(
+        vec![array![0., 1.], array![0., 1., 2.]],
+        array![[0., 1., 2.], [3., 4., 5.]].into_dyn(),
+        strategy::Nearest,
+        Extrapolate::Fill(f64::NAN),
+    )
+    .unwrap();
+    assert!(interp.interpolate(&[-1., 3.]).unwrap().is_nan());
+    assert_eq!(interp.interpolate_clamped(&[-1., 3.]).unwrap(), 2.);
+    assert_eq!(interp.interpolate_clamped(&[2., 0.9]).unwrap(), 4.);
+}
+
 #[test]
 fn test_extrapolate_wrap() {
     let interp = InterpND::new(
@@ -345,6 +359,85 @@ fn test_extrapolate_wrap() {
     );
 }
 
+#[test]
+fn test_extrapolate_wrap_mixed_bounds() {
+    let interp = InterpND::new(
+        vec![array![0., 1.], array![0., 1., 2.]],
+        array![[0., 1., 2.], [3., 4., 5.]].into_dyn(),
+        strategy::Linear,
+        Extrapolate::Wrap,
+    )
+    .unwrap();
+    // In-bounds axis exactly at its max keeps its edge value,
+    // while the out-of-bounds axis is wrapped
+    assert_eq!(interp.interpolate(&[1., 2.5]).unwrap(), 3.5);
+    assert_eq!(interp.interpolate(&[1.5, 2.]).unwrap(), 3.5);
+    assert_eq!(
+        interp.interpolate(&[1.5, 2.]).unwrap(),
+        interp.interpolate(&[0.5, 2.]).unwrap()
+    );
+}
+
+#[test]
+fn test_singleton_axis() {
+    let x = array![0., 1., 2.];
+    let y = array![0.5];
+    let z = array![0., 1., 2., 3.];
+    let f_xz = Array2::from_shape_fn((3, 4), |(i, k)| (i * 4 + k) as f64);
+    let f_xyz = f_xz.clone().into_shape_with_order((3, 1, 4)).unwrap();
+    let mut interp = InterpND::new(
+        vec![x.view(), y.view(), z.view()],
+        f_xyz.view().into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp.ndim(), 3);
+    // Equivalent interpolator without the singleton axis
+    let interp_2d = InterpND::new(
+        vec![x.view(), z.view()],
+        f_xz.view().into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    for point in [[0., 0.], [0.5, 1.5], [1.25, 2.75], [2., 3.]] {
+        assert_eq!(
+            interp.interpolate(&[point[0], 0.5, point[1]]).unwrap(),
+            interp_2d.interpolate(&point).unwrap()
+        );
+    }
+    // Coordinate on the singleton axis must match its grid value
+    assert!(matches!(
+        interp.interpolate(&[0.5, 0.6, 1.5]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    // ...or be clamped
+    interp.set_extrapolate(Extrapolate::Clamp).unwrap();
+    assert_eq!(
+        interp.interpolate(&[0.5, 0.6, 1.5]).unwrap(),
+        interp_2d.interpolate(&[0.5, 1.5]).unwrap()
+    );
+    interp.set_extrapolate(Extrapolate::Wrap).unwrap();
+    assert_eq!(
+        interp.interpolate(&[0.5, 0.6, 1.5]).unwrap(),
+        interp_2d.interpolate(&[0.5, 1.5]).unwrap()
+    );
+    // Extrapolation is not possible along a singleton axis
+    assert!(interp.set_extrapolate(Extrapolate::Enable).is_err());
+
+    // All-singleton axes are still N-dimensional
+    let interp = InterpND::new(
+        vec![array![1.], array![2.], array![3.]],
+        array![[[0.5]]].into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp.ndim(), 3);
+    assert_eq!(interp.interpolate(&[1., 2., 3.]).unwrap(), 0.5);
+}
+
 #[test]
 fn test_mismatched_grid() {
     assert!(matches!(
@@ -366,18 +459,18 @@ fn test_mismatched_grid() {
         Extrapolate::Error,
     )
     .is_ok(),);
-    assert!(matches!(
+    // Non-empty grid with a single value is a 1-D singleton axis, not 0-D
+    assert_eq!(
         InterpND::new(
-            // non-empty grid
             vec![array![1.]],
-            // 0-D values
             array![0.].into_dyn(),
             strategy::Linear,
             Extrapolate::Error,
         )
-        .unwrap_err(),
-        ValidateError::Other(_)
-    ));
+        .unwrap()
+        .ndim(),
+        1
+    );
 }
 
 #[test]
@@ -406,3 +499,240 @@ fn test_serde() {
     let de: InterpNDOwned<f64, strategy::Nearest> = serde_json::from_str(&ser).unwrap();
     assert_eq!(interp, de);
 }
+
+#[test]
+fn test_interpolate_path() {
+    let x = array![0.05, 0.10, 0.15, 0.20];
+    let y = array![0.10, 0.20, 0.30];
+    let z = array![0.20, 0.40];
+    let values = array![
+        [[0., 1.], [2., 3.], [4., 5.]],
+        [[6., 7.], [8., 9.], [10., 11.]],
+        [[12., 13.], [14., 15.], [16., 17.]],
+        [[18., 19.], [20., 21.], [22., 23.]],
+    ]
+    .into_dyn();
+    for strategy in [
+        Box::new(strategy::Linear) as Box<dyn StrategyND<_>>,
+        Box::new(strategy::Nearest),
+    ] {
+        let interp = InterpND::new(
+            vec![x.view(), y.view(), z.view()],
+            values.view(),
+            strategy,
+            Extrapolate::Clamp,
+        )
+        .unwrap();
+        // Smooth path, including grid-coincident and out-of-bounds points
+        let path = Array2::from_shape_fn((50, 3), |(i, j)| {
+            let t = i as f64 / 49.;
+            match j {
+                0 => 0.03 + 0.2 * t,
+                1 => 0.3 - 0.2 * t,
+                _ => 0.2 + 0.2 * t * t,
+            }
+        });
+        let expected: Array1<f64> = path
+            .rows()
+            .into_iter()
+            .map(|row| interp.interpolate(row.as_slice().unwrap()).unwrap())
+            .collect();
+        assert_eq!(interp.interpolate_path(&path.view()).unwrap(), expected);
+    }
+    let interp = InterpND::new(
+        vec![x.view(), y.view(), z.view()],
+        values.view(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(matches!(
+        interp
+            .interpolate_path(&array![[0.1, 0.2]].view())
+            .unwrap_err(),
+        InterpolateError::PointLength(3)
+    ));
+    assert!(matches!(
+        interp
+            .interpolate_path(&array![[0.1, 0.2, 0.3], [0.3, 0.2, 0.3]].view())
+            .unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+}
+
+#[test]
+fn test_extrapolate_nearest_value() {
+    let interp = InterpND::new(
+        vec![
+            array![0.05, 0.10, 0.15],
+            array![0.10, 0.20, 0.30],
+            array![0.20, 0.40, 0.60],
+        ],
+        array![
+            [[0., 1., 2.], [3., 4., 5.], [6., 7., 8.]],
+            [[9., 10., 11.], [12., 13., 14.], [15., 16., 17.]],
+            [[18., 19., 20.], [21., 22., 23.], [24., 25., 26.]],
+        ]
+        .into_dyn(),
+        strategy::Linear,
+        Extrapolate::NearestValue,
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[0., 0., 0.]).unwrap(), 0.);
+    assert_eq!(interp.interpolate(&[1., 1., 1.]).unwrap(), 26.);
+    assert_eq!(interp.interpolate(&[0.11, 0.24, 1.]).unwrap(), 14.);
+}
+
+#[test]
+fn test_diagnose() {
+    // Non-monotonic x-axis and mismatched y-axis length, both reported
+    let report = InterpND::diagnose(
+        vec![array![0., 2., 1.], array![0., 1., 2.]],
+        array![[0., 1.], [2., 3.], [4., 5.]].into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    );
+    assert!(!report.is_ok());
+    assert_eq!(report.ndim, 2);
+    assert!(report.grid_length_ok);
+    assert_eq!(report.nonempty, vec![true, true]);
+    assert_eq!(report.monotonic, vec![false, true]);
+    assert_eq!(report.shape_compatible, vec![true, false]);
+    assert!(report.extrapolate_applicable);
+    assert!(!report.strategy_initialized);
+    assert_eq!(
+        report.messages,
+        vec![
+            ValidateError::Monotonicity(0).to_string(),
+            ValidateError::IncompatibleShapes(1).to_string(),
+        ]
+    );
+    // Valid inputs
+    let report = InterpND::diagnose(
+        vec![array![0., 1., 2.], array![0., 1.]],
+        array![[0., 1.], [2., 3.], [4., 5.]].into_dyn(),
+        strategy::Linear,
+        Extrapolate::Enable,
+    );
+    assert!(report.is_ok());
+    assert!(report.strategy_initialized);
+}
+
+#[test]
+fn test_same_grid() {
+    let grid = vec![array![0., 1., 2.], array![0., 1.]];
+    let values = array![[0., 1.], [2., 3.], [4., 5.]].into_dyn();
+    let interp = InterpND::new(
+        grid.clone(),
+        values.clone(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let same = InterpND::new(
+        grid.clone(),
+        values.map(|v| -v),
+        strategy::Nearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(interp.same_grid(&same));
+    let nudged = InterpND::new(
+        vec![array![0., 1., 2. + 1e-12], array![0., 1.]],
+        values.clone(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(!interp.same_grid(&nudged));
+    assert!(interp.same_grid_approx(&nudged, 1e-9));
+    // Differing dimensionality
+    let lower = InterpND::new(
+        vec![array![0., 1., 2.]],
+        array![0., 1., 2.].into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(!interp.same_grid(&lower));
+    assert!(!interp.same_grid_approx(&lower, 1.));
+}
+
+#[test]
+fn test_gradient_plane() {
+    let coeffs = [1.5, -0.25, 2., 0.75];
+    let grid = vec![
+        array![0., 1., 3.],
+        array![-1., 0., 2.],
+        array![0., 0.5, 1.],
+        array![-2., 2.],
+    ];
+    let values = ArrayD::from_shape_fn(IxDyn(&[3, 3, 3, 2]), |index| {
+        (0..4)
+            .map(|dim| coeffs[dim] * grid[dim][index[dim]])
+            .sum::<f64>()
+            + 3.
+    });
+    let mut interp = InterpND::new(grid, values, strategy::Linear, Extrapolate::Error).unwrap();
+    for point in [
+        [0., -1., 0., -2.],
+        [0.5, 0.5, 0.7, 0.],
+        [1., 0., 1., 1.],
+        [3., 2., 1., 2.],
+    ] {
+        let gradient = interp.gradient(&point).unwrap();
+        assert_eq!(gradient.len(), interp.ndim());
+        for (partial, coeff) in gradient.iter().zip(coeffs) {
+            assert_approx_eq!(*partial, coeff);
+        }
+    }
+    assert!(matches!(
+        interp.gradient(&[0.5, 0.5]).unwrap_err(),
+        InterpolateError::PointLength(4)
+    ));
+    assert!(matches!(
+        interp.gradient(&[4., 0., 0., 0.]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    interp.set_extrapolate(Extrapolate::Enable).unwrap();
+    assert_approx_eq!(interp.gradient(&[4., 0., 0., 0.]).unwrap()[0], coeffs[0]);
+}
+
+#[test]
+fn test_set_values() {
+    let mut interp = InterpND::new(
+        vec![array![0., 1.], array![0., 1.]],
+        array![[0., 1.], [2., 3.]].into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    interp
+        .set_values(array![[0., 10.], [20., 30.]].into_dyn())
+        .unwrap();
+    assert_eq!(interp.interpolate(&[0.5, 0.5]).unwrap(), 15.);
+    // Mismatched dimensionality
+    assert!(matches!(
+        interp.set_values(array![0., 1.].into_dyn()).unwrap_err(),
+        ValidateError::IncompatibleShapes(1)
+    ));
+    assert_eq!(interp.interpolate(&[0.5, 0.5]).unwrap(), 15.);
+}
+
+#[test]
+fn test_data_accessors() {
+    let interp = InterpND::new(
+        vec![array![0., 1.], array![0., 1., 2.], array![5.]],
+        Array3::from_shape_fn((2, 3, 1), |(i, j, _)| (i * 3 + j) as f64).into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let data = &interp.data;
+    assert_eq!(data.grid().len(), 3);
+    assert_eq!(data.axis(1).unwrap(), array![0., 1., 2.]);
+    assert_eq!(data.axis(2).unwrap(), array![5.]);
+    assert_eq!(data.axis(3), None);
+    assert_eq!(data.values()[[1, 2, 0]], 5.);
+    assert_eq!(data.shape(), vec![2, 3, 1]);
+}
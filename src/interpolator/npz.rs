@@ -0,0 +1,615 @@
+//! Reading and writing [`InterpolatorEnum`]s as NumPy `.npz` archives.
+//!
+//! Archives contain one `.npy` array per entry, named so that `numpy.load` exposes:
+//! - `grid_0`, `grid_1`, ...: 1-D grid coordinates, one per axis
+//! - `values`: values array, with one axis per grid (0-D for [`Interp0D`])
+//! - `metadata`: 0-D unicode string of `key=value` lines:
+//!   - `interpolator`: `Interp0D`, `Interp1D`, `Interp2D`, `Interp3D`, or `InterpND`
+//!   - `strategy`: strategy variant name, e.g. `Linear` (absent for [`Interp0D`])
+//!   - `extrapolate`: [`Extrapolate`] variant name, e.g. `Clamp` (absent for [`Interp0D`])
+//! - `extrapolate`: 1-D parameters of the [`Extrapolate`] variant, in field order
+//!   (e.g. `[value, rate]` for [`Extrapolate::SaturatingFill`], empty for [`Extrapolate::Clamp`])
+//!
+//! Arrays are written uncompressed, as by `numpy.savez`.
+//! Archives written by `numpy.savez_compressed` can also be read.
+//!
+//! # Example:
+//! ```
+//! use std::io::Cursor;
+//! use ndarray::prelude::*;
+//! use ninterp::prelude::*;
+//! let interp = InterpolatorEnum::new_2d(
+//!     array![0., 1.],
+//!     array![0., 1., 2.],
+//!     array![[0., 1., 2.], [3., 4., 5.]],
+//!     strategy::Linear,
+//!     Extrapolate::Clamp,
+//! )
+//! .unwrap();
+//! let mut buffer = Cursor::new(Vec::new());
+//! interp.write_npz(&mut buffer).unwrap();
+//! let loaded = InterpolatorEnumOwned::<f64>::read_npz(buffer).unwrap();
+//! assert_eq!(loaded, interp);
+//! ```
+
+use super::*;
+
+use itertools::Itertools;
+use std::fs::File;
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+use enums::{InterpolatorEnum, InterpolatorEnumOwned};
+use strategy::enums::*;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Array element types storable in `.npy` arrays, as little-endian bytes.
+pub trait NpyElement: Copy {
+    /// NumPy dtype descriptor, e.g. `<f8`.
+    const DESCR: &'static str;
+    /// Size in bytes.
+    const SIZE: usize;
+    /// Append little-endian bytes to `out`.
+    fn write_le(self, out: &mut Vec<u8>);
+    /// Read from [`NpyElement::SIZE`] little-endian bytes.
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! npy_element_impl {
+    ($t:ty, $descr:literal) => {
+        impl NpyElement for $t {
+            const DESCR: &'static str = $descr;
+            const SIZE: usize = std::mem::size_of::<$t>();
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+            fn read_le(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().unwrap())
+            }
+        }
+    };
+}
+
+npy_element_impl!(f32, "<f4");
+npy_element_impl!(f64, "<f8");
+npy_element_impl!(i32, "<i4");
+npy_element_impl!(i64, "<i8");
+
+/// `.npy` version 1.0 header, padded to a multiple of 64 bytes.
+fn npy_header(descr: &str, fortran_order: bool, shape: &[usize]) -> Vec<u8> {
+    let shape = match shape {
+        [] => "()".to_string(),
+        [n] => format!("({n},)"),
+        _ => format!("({})", shape.iter().join(", ")),
+    };
+    let fortran_order = if fortran_order { "True" } else { "False" };
+    let mut header =
+        format!("{{'descr': '{descr}', 'fortran_order': {fortran_order}, 'shape': {shape}, }}");
+    // Magic, version, and header length precede the header, which ends with a newline
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+    header.push('\n');
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+    bytes
+}
+
+fn npy_array<T: NpyElement, D: Data<Elem = T>, Dim: Dimension>(
+    array: &ArrayBase<D, Dim>,
+) -> Vec<u8> {
+    let mut bytes = npy_header(T::DESCR, false, array.shape());
+    bytes.reserve(array.len() * T::SIZE);
+    for &x in array.iter() {
+        x.write_le(&mut bytes);
+    }
+    bytes
+}
+
+fn npy_str(s: &str) -> Vec<u8> {
+    let len = s.chars().count().max(1);
+    let mut bytes = npy_header(&format!("<U{len}"), false, &[]);
+    for c in s.chars() {
+        bytes.extend_from_slice(&(c as u32).to_le_bytes());
+    }
+    bytes.resize(bytes.len() + 4 * (len - s.chars().count()), 0);
+    bytes
+}
+
+/// Parsed `.npy` array, with unconverted data.
+struct Npy<'a> {
+    descr: String,
+    shape: Vec<usize>,
+    fortran_order: bool,
+    data: &'a [u8],
+}
+
+fn parse_npy(bytes: &[u8]) -> Result<Npy<'_>, NpzError> {
+    if bytes.len() < 10 || &bytes[..MAGIC.len()] != MAGIC {
+        return Err(NpzError::Format("missing `.npy` magic string".into()));
+    }
+    let (header_start, header_len) = match bytes[6] {
+        1 => (10, u16::from_le_bytes([bytes[8], bytes[9]]) as usize),
+        2 | 3 if bytes.len() >= 12 => (
+            12,
+            u32::from_le_bytes(bytes[8..12].try_into().unwrap()) as usize,
+        ),
+        version => {
+            return Err(NpzError::Format(format!(
+                "unsupported `.npy` version {version}"
+            )))
+        }
+    };
+    let data_start = header_start + header_len;
+    let header = bytes
+        .get(header_start..data_start)
+        .and_then(|header| std::str::from_utf8(header).ok())
+        .ok_or_else(|| NpzError::Format("invalid `.npy` header".into()))?;
+    let field = |key: &str| {
+        header
+            .find(&format!("'{key}':"))
+            .or_else(|| header.find(&format!("\"{key}\":")))
+            .map(|i| header[i + key.len() + 3..].trim_start())
+            .ok_or_else(|| NpzError::Format(format!("`.npy` header is missing `{key}`")))
+    };
+    let descr = field("descr")?;
+    let descr = descr
+        .get(1..)
+        .and_then(|rest| rest.split(['\'', '"']).next())
+        .ok_or_else(|| NpzError::Format("invalid `.npy` descr".into()))?
+        .to_string();
+    let fortran_order = field("fortran_order")?.starts_with("True");
+    let shape = field("shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|shape| shape.split(')').next())
+        .ok_or_else(|| NpzError::Format("invalid `.npy` shape".into()))?
+        .split(',')
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map(|n| {
+            n.parse()
+                .map_err(|_| NpzError::Format(format!("invalid `.npy` shape entry {n}")))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Npy {
+        descr,
+        shape,
+        fortran_order,
+        data: &bytes[data_start..],
+    })
+}
+
+fn read_npy_array<T: NpyElement>(bytes: &[u8]) -> Result<ArrayD<T>, NpzError> {
+    let Npy {
+        descr,
+        shape,
+        fortran_order,
+        data,
+    } = parse_npy(bytes)?;
+    if descr != T::DESCR {
+        return Err(NpzError::Format(format!(
+            "expected dtype {}, found {descr}",
+            T::DESCR
+        )));
+    }
+    let len: usize = shape.iter().product();
+    if data.len() < len * T::SIZE {
+        return Err(NpzError::Format(format!(
+            "`.npy` data is too short for shape {shape:?}"
+        )));
+    }
+    let elements = data[..len * T::SIZE]
+        .chunks_exact(T::SIZE)
+        .map(T::read_le)
+        .collect();
+    let array = if fortran_order {
+        ArrayD::from_shape_vec(IxDyn(&shape).f(), elements)
+    } else {
+        ArrayD::from_shape_vec(IxDyn(&shape), elements)
+    }
+    .map_err(|e| NpzError::Format(e.to_string()))?;
+    Ok(array.as_standard_layout().into_owned())
+}
+
+fn read_npy_str(bytes: &[u8]) -> Result<String, NpzError> {
+    let Npy { descr, data, .. } = parse_npy(bytes)?;
+    let len: usize = descr
+        .strip_prefix("<U")
+        .and_then(|len| len.parse().ok())
+        .ok_or_else(|| NpzError::Format(format!("expected unicode dtype, found {descr}")))?;
+    data.chunks_exact(4)
+        .take(len)
+        .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+        .take_while(|&c| c != 0)
+        .map(|c| {
+            char::from_u32(c)
+                .ok_or_else(|| NpzError::Format(format!("invalid unicode code point {c}")))
+        })
+        .collect()
+}
+
+fn strategy_1d(name: &str) -> Result<Strategy1DEnum, NpzError> {
+    Ok(match name {
+        "Linear" => strategy::Linear.into(),
+        "Nearest" => strategy::Nearest.into(),
+        "LeftNearest" => strategy::LeftNearest.into(),
+        "RightNearest" => strategy::RightNearest.into(),
+        _ => return Err(unknown_strategy(name, 1)),
+    })
+}
+
+macro_rules! strategy_parse_impl {
+    ($parse_fn:ident, $Enum:ident, $dims:literal) => {
+        fn $parse_fn(name: &str) -> Result<$Enum, NpzError> {
+            Ok(match name {
+                "Linear" => strategy::Linear.into(),
+                "Nearest" => strategy::Nearest.into(),
+                _ => return Err(unknown_strategy(name, $dims)),
+            })
+        }
+    };
+}
+
+strategy_parse_impl!(strategy_2d, Strategy2DEnum, "2");
+strategy_parse_impl!(strategy_3d, Strategy3DEnum, "3");
+strategy_parse_impl!(strategy_nd, StrategyNDEnum, "N");
+
+fn unknown_strategy(name: &str, dims: impl std::fmt::Display) -> NpzError {
+    NpzError::Format(format!("unknown {dims}-D strategy `{name}`"))
+}
+
+fn extrapolate_parts<T: Copy>(extrapolate: &Extrapolate<T>) -> (&'static str, Vec<T>) {
+    match *extrapolate {
+        Extrapolate::Enable => ("Enable", vec![]),
+        Extrapolate::EnableBounded(limit) => ("EnableBounded", vec![limit]),
+        Extrapolate::Fill(value) => ("Fill", vec![value]),
+        Extrapolate::SaturatingFill { value, rate } => ("SaturatingFill", vec![value, rate]),
+        Extrapolate::Clamp => ("Clamp", vec![]),
+        Extrapolate::NearestValue => ("NearestValue", vec![]),
+        Extrapolate::Wrap => ("Wrap", vec![]),
+        Extrapolate::Error => ("Error", vec![]),
+    }
+}
+
+fn extrapolate_from_parts<T: Copy>(name: &str, params: &[T]) -> Result<Extrapolate<T>, NpzError> {
+    let expected = match name {
+        "EnableBounded" | "Fill" => 1,
+        "SaturatingFill" => 2,
+        _ => 0,
+    };
+    if params.len() != expected {
+        return Err(NpzError::Format(format!(
+            "`Extrapolate::{name}` expects {expected} parameters, found {}",
+            params.len()
+        )));
+    }
+    Ok(match name {
+        "Enable" => Extrapolate::Enable,
+        "EnableBounded" => Extrapolate::EnableBounded(params[0]),
+        "Fill" => Extrapolate::Fill(params[0]),
+        "SaturatingFill" => Extrapolate::SaturatingFill {
+            value: params[0],
+            rate: params[1],
+        },
+        "Clamp" => Extrapolate::Clamp,
+        "NearestValue" => Extrapolate::NearestValue,
+        "Wrap" => Extrapolate::Wrap,
+        "Error" => Extrapolate::Error,
+        _ => {
+            return Err(NpzError::Format(format!(
+                "unknown `Extrapolate` variant `{name}`"
+            )))
+        }
+    })
+}
+
+impl<D> InterpolatorEnum<D>
+where
+    D: Data + RawDataClone,
+    D::Elem: NpyElement + Num + PartialOrd + Copy + Debug,
+{
+    /// Write the interpolator as a `.npz` archive, see the [module](self) documentation for its layout.
+    pub fn write_npz<W: Write + Seek>(&self, writer: W) -> Result<(), NpzError> {
+        let (kind, grid, values, strategy, extrapolate) = match self {
+            InterpolatorEnum::Interp0D(interp) => {
+                ("Interp0D", vec![], npy_array(&arr0(interp.0)), None, None)
+            }
+            InterpolatorEnum::Interp1D(interp) => (
+                "Interp1D",
+                interp.data.grid.iter().map(npy_array).collect(),
+                npy_array(&interp.data.values),
+                Some(interp.strategy.variant_name()),
+                Some(&interp.extrapolate),
+            ),
+            InterpolatorEnum::Interp2D(interp) => (
+                "Interp2D",
+                interp.data.grid.iter().map(npy_array).collect(),
+                npy_array(&interp.data.values),
+                Some(interp.strategy.variant_name()),
+                Some(&interp.extrapolate),
+            ),
+            InterpolatorEnum::Interp3D(interp) => (
+                "Interp3D",
+                interp.data.grid.iter().map(npy_array).collect(),
+                npy_array(&interp.data.values),
+                Some(interp.strategy.variant_name()),
+                Some(&interp.extrapolate),
+            ),
+            InterpolatorEnum::InterpND(interp) => (
+                "InterpND",
+                interp.data.grid.iter().map(npy_array).collect(),
+                npy_array(&interp.data.values),
+                Some(interp.strategy.variant_name()),
+                Some(&interp.extrapolate),
+            ),
+        };
+        let mut metadata = format!("interpolator={kind}");
+        let mut entries: Vec<(String, Vec<u8>)> = grid
+            .into_iter()
+            .enumerate()
+            .map(|(dim, bytes)| (format!("grid_{dim}.npy"), bytes))
+            .collect();
+        entries.push(("values.npy".into(), values));
+        if let Some(strategy) = strategy {
+            metadata.push_str(&format!("\nstrategy={strategy}"));
+        }
+        if let Some(extrapolate) = extrapolate {
+            let (name, params) = extrapolate_parts(extrapolate);
+            metadata.push_str(&format!("\nextrapolate={name}"));
+            entries.push(("extrapolate.npy".into(), npy_array(&Array1::from(params))));
+        }
+        entries.push(("metadata.npy".into(), npy_str(&metadata)));
+
+        let mut zip = ZipWriter::new(writer);
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Stored);
+        for (name, bytes) in entries {
+            zip.start_file(name, options)?;
+            zip.write_all(&bytes)?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+
+    /// Save the interpolator to a `.npz` file at `path`, see [`InterpolatorEnum::write_npz`].
+    pub fn save_npz(&self, path: impl AsRef<Path>) -> Result<(), NpzError> {
+        self.write_npz(File::create(path)?)
+    }
+}
+
+impl<T> InterpolatorEnumOwned<T>
+where
+    T: NpyElement + Num + PartialOrd + Copy + Debug,
+{
+    /// Read an interpolator from a `.npz` archive, see the [module](self) documentation for its layout.
+    ///
+    /// The interpolator is validated after reading.
+    pub fn read_npz<R: Read + Seek>(reader: R) -> Result<Self, NpzError> {
+        let mut zip = ZipArchive::new(reader)?;
+        let mut entry = |name: &str| -> Result<Vec<u8>, NpzError> {
+            let mut file = zip
+                .by_name(&format!("{name}.npy"))
+                .map_err(|_| NpzError::Format(format!("missing array `{name}`")))?;
+            let mut bytes = Vec::new();
+            file.read_to_end(&mut bytes)?;
+            Ok(bytes)
+        };
+        let metadata = read_npy_str(&entry("metadata")?)?;
+        let metadata = |key: &str| {
+            metadata
+                .lines()
+                .find_map(|line| line.strip_prefix(key)?.strip_prefix('='))
+                .ok_or_else(|| NpzError::Format(format!("metadata is missing `{key}`")))
+        };
+        let values = read_npy_array::<T>(&entry("values")?)?;
+        let kind = metadata("interpolator")?;
+        if kind == "Interp0D" {
+            return Ok(Self::new_0d(values.into_iter().next().ok_or_else(
+                || NpzError::Format("`values` of `Interp0D` is empty".into()),
+            )?));
+        }
+        let grid = (0..values.ndim())
+            .map(|dim| {
+                read_npy_array::<T>(&entry(&format!("grid_{dim}"))?)?
+                    .into_dimensionality::<Ix1>()
+                    .map_err(|_| NpzError::Format(format!("`grid_{dim}` is not 1-D")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let extrapolate = extrapolate_from_parts(
+            metadata("extrapolate")?,
+            read_npy_array::<T>(&entry("extrapolate")?)?
+                .as_slice()
+                .unwrap(),
+        )?;
+        let strategy = metadata("strategy")?;
+        let values_ndim = |ndim: usize| {
+            NpzError::Format(format!(
+                "`values` of `{kind}` should be {ndim}-D, found {}-D",
+                values.ndim()
+            ))
+        };
+        let mut grid = grid.into_iter();
+        Ok(match kind {
+            "Interp1D" => Self::new_1d(
+                grid.next().ok_or_else(|| values_ndim(1))?,
+                values
+                    .clone()
+                    .into_dimensionality()
+                    .map_err(|_| values_ndim(1))?,
+                strategy_1d(strategy)?,
+                extrapolate,
+            )?,
+            "Interp2D" => Self::new_2d(
+                grid.next().ok_or_else(|| values_ndim(2))?,
+                grid.next().ok_or_else(|| values_ndim(2))?,
+                values
+                    .clone()
+                    .into_dimensionality()
+                    .map_err(|_| values_ndim(2))?,
+                strategy_2d(strategy)?,
+                extrapolate,
+            )?,
+            "Interp3D" => Self::new_3d(
+                grid.next().ok_or_else(|| values_ndim(3))?,
+                grid.next().ok_or_else(|| values_ndim(3))?,
+                grid.next().ok_or_else(|| values_ndim(3))?,
+                values
+                    .clone()
+                    .into_dimensionality()
+                    .map_err(|_| values_ndim(3))?,
+                strategy_3d(strategy)?,
+                extrapolate,
+            )?,
+            "InterpND" => {
+                Self::new_nd(grid.collect(), values, strategy_nd(strategy)?, extrapolate)?
+            }
+            _ => {
+                return Err(NpzError::Format(format!(
+                    "unknown interpolator type `{kind}`"
+                )))
+            }
+        })
+    }
+
+    /// Load an interpolator from a `.npz` file at `path`, see [`InterpolatorEnumOwned::read_npz`].
+    pub fn load_npz(path: impl AsRef<Path>) -> Result<Self, NpzError> {
+        Self::read_npz(File::open(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    fn round_trip(interp: &InterpolatorEnumOwned<f64>) -> InterpolatorEnumOwned<f64> {
+        let mut buffer = Cursor::new(Vec::new());
+        interp.write_npz(&mut buffer).unwrap();
+        InterpolatorEnumOwned::read_npz(buffer).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let interps = [
+            InterpolatorEnum::new_0d(0.5),
+            InterpolatorEnum::new_1d(
+                array![0., 1., 2.],
+                array![1., 3., 2.],
+                strategy::RightNearest,
+                Extrapolate::SaturatingFill {
+                    value: 0.,
+                    rate: 0.5,
+                },
+            )
+            .unwrap(),
+            InterpolatorEnum::new_2d(
+                array![0., 1.],
+                array![-1., 0., 1.],
+                array![[0., 1., 2.], [3., 4., 5.]],
+                strategy::Linear,
+                Extrapolate::EnableBounded(0.25),
+            )
+            .unwrap(),
+            InterpolatorEnum::new_3d(
+                array![0., 1.],
+                array![0., 1.],
+                array![0., 1., 2.],
+                Array3::from_shape_fn((2, 2, 3), |(i, j, k)| (i * 6 + j * 3 + k) as f64),
+                strategy::Nearest,
+                Extrapolate::Clamp,
+            )
+            .unwrap(),
+            InterpolatorEnum::new_nd(
+                vec![
+                    array![0., 1.],
+                    array![0., 1.],
+                    array![0., 1.],
+                    array![0., 1.],
+                ],
+                Array4::from_shape_fn((2, 2, 2, 2), |(i, j, k, l)| (i + j + k + l) as f64)
+                    .into_dyn(),
+                strategy::Linear,
+                Extrapolate::Fill(-1.),
+            )
+            .unwrap(),
+        ];
+        for interp in &interps {
+            let loaded = round_trip(interp);
+            assert_eq!(&loaded, interp);
+            let point = vec![0.25; interp.ndim()];
+            assert_eq!(
+                loaded.interpolate(&point).unwrap(),
+                interp.interpolate(&point).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_npy() {
+        // Column-major data, as written by NumPy for Fortran-ordered arrays
+        let mut bytes = npy_header("<f8", true, &[2, 3]);
+        for x in [0., 3., 1., 4., 2., 5.] {
+            f64::write_le(x, &mut bytes);
+        }
+        assert_eq!(bytes.iter().position(|&b| b == b'\n').unwrap() % 64, 63);
+        assert_eq!(
+            read_npy_array::<f64>(&bytes).unwrap(),
+            array![[0., 1., 2.], [3., 4., 5.]].into_dyn()
+        );
+        assert!(matches!(
+            read_npy_array::<f32>(&bytes).unwrap_err(),
+            NpzError::Format(_)
+        ));
+        assert_eq!(
+            read_npy_str(&npy_str("interpolator=Interp1D")).unwrap(),
+            "interpolator=Interp1D"
+        );
+        assert_eq!(read_npy_str(&npy_str("")).unwrap(), "");
+    }
+
+    #[test]
+    fn test_invalid() {
+        let mut buffer = Cursor::new(Vec::new());
+        let mut zip = ZipWriter::new(&mut buffer);
+        zip.start_file("metadata.npy", SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(&npy_str("interpolator=Interp1D\nstrategy=Linear"))
+            .unwrap();
+        zip.finish().unwrap();
+        buffer.set_position(0);
+        assert!(matches!(
+            InterpolatorEnumOwned::<f64>::read_npz(buffer).unwrap_err(),
+            NpzError::Format(_)
+        ));
+        // Settings are validated as by the constructors
+        let mut interp = Interp1D::new(
+            array![0., 1.],
+            array![0., 1.],
+            strategy::Linear.into(),
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        interp.extrapolate = Extrapolate::EnableBounded(-1.);
+        let mut buffer = Cursor::new(Vec::new());
+        InterpolatorEnum::Interp1D(interp)
+            .write_npz(&mut buffer)
+            .unwrap();
+        buffer.set_position(0);
+        assert!(matches!(
+            InterpolatorEnumOwned::<f64>::read_npz(buffer).unwrap_err(),
+            NpzError::Validate(ValidateError::ExtrapolateSelection(_))
+        ));
+        assert!(matches!(
+            extrapolate_from_parts::<f64>("Fill", &[]).unwrap_err(),
+            NpzError::Format(_)
+        ));
+        assert!(matches!(
+            strategy_2d("LeftNearest").unwrap_err(),
+            NpzError::Format(_)
+        ));
+    }
+}
@@ -2,7 +2,11 @@
 
 use super::*;
 
+use itertools::Itertools;
+use num_traits::ToPrimitive;
+
 mod strategies;
+pub(crate) use strategies::solve_linear_system;
 #[cfg(test)]
 mod tests;
 
@@ -14,25 +18,183 @@ pub type InterpData1D<D> = InterpData<D, N>;
 pub type InterpData1DViewed<T> = InterpData1D<ViewRepr<T>>;
 /// [`InterpData1D`] that owns data.
 pub type InterpData1DOwned<T> = InterpData1D<OwnedRepr<T>>;
+/// [`InterpData1D`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type InterpData1DCow<'a, T> = InterpData1D<CowRepr<'a, T>>;
 
 impl<D> InterpData1D<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialOrd + Debug,
 {
     /// Construct and validate a new [`InterpData1D`].
+    ///
+    /// Strictly decreasing grid axes are accepted, and reversed along with the corresponding
+    /// axis of the values (by negating strides, without copying),
+    /// so that the stored grid is always increasing.
+    /// Arrays supplied later, e.g. to `set_values`, must follow the stored, increasing order.
     pub fn new(x: ArrayBase<D, Ix1>, f_x: ArrayBase<D, Ix1>) -> Result<Self, ValidateError> {
-        let data = Self {
+        let mut data = Self {
             grid: [x],
             values: f_x,
         };
+        orient_ascending(&mut data.grid, &mut data.values);
         data.validate()?;
         Ok(data)
     }
+
+    /// Find the lower bracketing grid index for each of the supplied points,
+    /// equivalent to calling [`find_nearest_index`] on each point.
+    ///
+    /// If `points` are sorted, a single linear sweep over the grid is used
+    /// instead of a binary search per point.
+    pub fn index_brackets(&self, points: &ArrayView1<D::Elem>) -> Array1<usize> {
+        let grid = self.grid[0].view();
+        if grid.len() < 2 {
+            return Array1::zeros(points.len());
+        }
+        if !points.windows(2).into_iter().all(|w| w[0] <= w[1]) {
+            return points.map(|p| find_nearest_index(grid, p));
+        }
+        let last = grid.len() - 1;
+        // Number of grid points strictly less than the current point
+        let mut below = 0;
+        points.map(|p| {
+            while below <= last && &grid[below] < p {
+                below += 1;
+            }
+            if p == &grid[last] {
+                last - 1
+            } else {
+                below.saturating_sub(1)
+            }
+        })
+    }
+}
+
+/// Resolution of repeated grid points (plateaus), e.g. a jump in stepwise measured data,
+/// see [`InterpData1D::with_plateau_policy`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum PlateauPolicy {
+    /// Return [`ValidateError::Monotonicity`].
+    #[default]
+    Error,
+    /// Keep the first value at a repeated grid point, i.e. the value approached from the left.
+    TakeLeft,
+    /// Keep the last value at a repeated grid point, i.e. the value approached from the right.
+    TakeRight,
+    /// Keep the mean of all values at a repeated grid point.
+    Average,
+}
+
+/// Rounding of a floating-point interpolation result to an integer,
+/// see [`Interp1D::interpolate_rounded`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Rounding {
+    /// Round to the nearest integer, with halfway cases rounded away from zero.
+    #[default]
+    Nearest,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+}
+
+impl<T> InterpData1DOwned<T>
+where
+    T: PartialOrd + Debug + Clone,
+{
+    /// Construct and validate a new [`InterpData1D`],
+    /// first sorting the `(x, f_x)` pairs by `x` (stable).
+    ///
+    /// Useful for data imported from unordered sources, which would otherwise fail validation
+    /// with [`ValidateError::Monotonicity`].
+    /// Repeated or unordered (e.g. NaN) `x` values are still an error.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::data::InterpData1D;
+    /// let data = InterpData1D::sorted_from(array![2., 0., 1.], array![0.8, 0.0, 0.4]).unwrap();
+    /// assert_eq!(data.grid[0], array![0., 1., 2.]);
+    /// assert_eq!(data.values, array![0.0, 0.4, 0.8]);
+    /// ```
+    pub fn sorted_from(x: Array1<T>, f_x: Array1<T>) -> Result<Self, ValidateError> {
+        if x.len() != f_x.len() {
+            return Err(ValidateError::IncompatibleShapes(0));
+        }
+        // Unordered values (e.g. NaN) would make the comparison below inconsistent
+        if x.iter().any(|x_i| x_i.partial_cmp(x_i).is_none()) {
+            return Err(ValidateError::Monotonicity(0));
+        }
+        let mut pairs: Vec<(T, T)> = x.into_iter().zip(f_x).collect();
+        // `sort_by` is stable
+        pairs.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+        if pairs.windows(2).any(|w| w[0].0 == w[1].0) {
+            return Err(ValidateError::Monotonicity(0));
+        }
+        let (x, f_x): (Vec<T>, Vec<T>) = pairs.into_iter().unzip();
+        Self::new(Array1::from_vec(x), Array1::from_vec(f_x))
+    }
+
+    /// Construct and validate a new [`InterpData1D`],
+    /// first collapsing runs of repeated `x` values into a single grid point per the [`PlateauPolicy`].
+    ///
+    /// Strategies such as [`strategy::Linear`] are otherwise ill-defined across
+    /// a zero-width grid segment.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::data::{InterpData1D, PlateauPolicy};
+    /// let data = InterpData1D::with_plateau_policy(
+    ///     array![0., 1., 1., 2.],
+    ///     array![0., 1., 3., 4.],
+    ///     PlateauPolicy::TakeRight,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(data.grid[0], array![0., 1., 2.]);
+    /// assert_eq!(data.values, array![0., 3., 4.]);
+    /// ```
+    pub fn with_plateau_policy(
+        x: Array1<T>,
+        f_x: Array1<T>,
+        policy: PlateauPolicy,
+    ) -> Result<Self, ValidateError>
+    where
+        T: Num + Copy,
+    {
+        if x.len() != f_x.len() {
+            return Err(ValidateError::IncompatibleShapes(0));
+        }
+        let mut grid: Vec<T> = Vec::with_capacity(x.len());
+        // Collapsed values, as (sum, count) to support averaging
+        let mut values: Vec<(T, T)> = Vec::with_capacity(x.len());
+        for (&x_i, &f_i) in x.iter().zip(f_x.iter()) {
+            if grid.last() != Some(&x_i) {
+                grid.push(x_i);
+                values.push((f_i, T::one()));
+                continue;
+            }
+            let (sum, count) = values.last_mut().unwrap();
+            match policy {
+                PlateauPolicy::Error => return Err(ValidateError::Monotonicity(0)),
+                PlateauPolicy::TakeLeft => {}
+                PlateauPolicy::TakeRight => *sum = f_i,
+                PlateauPolicy::Average => {
+                    *sum = *sum + f_i;
+                    *count = *count + T::one();
+                }
+            }
+        }
+        let values = values.into_iter().map(|(sum, count)| sum / count).collect();
+        Self::new(Array1::from_vec(grid), Array1::from_vec(values))
+    }
 }
 
 /// 1-D interpolator
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(
     feature = "serde",
@@ -50,7 +212,7 @@ where
 )]
 pub struct Interp1D<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
     S: Strategy1D<D> + Clone,
 {
@@ -66,14 +228,20 @@ where
 pub type Interp1DViewed<T, S> = Interp1D<ViewRepr<T>, S>;
 /// [`Interp1D`] that owns data.
 pub type Interp1DOwned<T, S> = Interp1D<OwnedRepr<T>, S>;
+/// [`Interp1D`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type Interp1DCow<'a, T, S> = Interp1D<CowRepr<'a, T>, S>;
 
 extrapolate_impl!(Interp1D, Strategy1D);
 partialeq_impl!(Interp1D, InterpData1D, Strategy1D);
+clone_impl!(Interp1D, Strategy1D);
+contiguous_impl!(Interp1D, Strategy1D);
+set_values_impl!(Interp1D, Strategy1D, Ix1);
+index_impl!(Interp1D, Strategy1D);
 
 impl<D, S> Interp1D<D, S>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialOrd + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
     S: Strategy1D<D> + Clone,
 {
     /// Instantiate one-dimensional interpolator.
@@ -83,8 +251,13 @@ where
     /// - [`strategy::Nearest`]
     /// - [`strategy::LeftNearest`]
     /// - [`strategy::RightNearest`]
+    /// - [`strategy::PolyFit`]
+    /// - [`strategy::Newton`]
+    /// - [`strategy::Geometric`]
+    /// - [`strategy::Pchip`]
     ///
-    /// [`Extrapolate::Enable`] is valid for [`strategy::Linear`]
+    /// [`Extrapolate::Enable`] is valid for [`strategy::Linear`], [`strategy::PolyFit`], [`strategy::Newton`],
+    /// [`strategy::Geometric`], and [`strategy::Pchip`]
     ///
     /// # Example:
     /// ```
@@ -107,12 +280,17 @@ where
     ///     1.44
     /// );
     /// ```
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new(
         x: ArrayBase<D, Ix1>,
         f_x: ArrayBase<D, Ix1>,
         strategy: S,
         extrapolate: Extrapolate<D::Elem>,
     ) -> Result<Self, ValidateError> {
+        let mut strategy = strategy;
+        if is_descending(&x) {
+            strategy.reverse_indices(x.len());
+        }
         let mut interpolator = Self {
             data: InterpData1D::new(x, f_x)?,
             strategy,
@@ -123,6 +301,169 @@ where
         Ok(interpolator)
     }
 
+    /// Instantiate one-dimensional interpolator without validating data or initializing the strategy,
+    /// e.g. for loading many trusted, pre-validated tables at scale.
+    ///
+    /// This is not `unsafe`, but the caller is responsible for validity:
+    /// invalid data (e.g. unsorted or mismatched `x` and `f_x`) can produce wrong results or panics,
+    /// and strategies that require initialization (e.g. [`strategy::PolyFit`])
+    /// return [`InterpolateError::Uninitialized`] until initialized.
+    /// Call [`Interpolator::validate`] to opt in to checking and initialization afterward.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let mut interp = Interp1D::new_unchecked(
+    ///     array![0., 1., 2.],
+    ///     array![0.0, 0.4, 0.8],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// );
+    /// assert_eq!(interp.interpolate(&[1.4]).unwrap(), 0.56);
+    /// assert!(interp.validate().is_ok());
+    /// ```
+    #[must_use]
+    pub fn new_unchecked(
+        x: ArrayBase<D, Ix1>,
+        f_x: ArrayBase<D, Ix1>,
+        strategy: S,
+        extrapolate: Extrapolate<D::Elem>,
+    ) -> Self {
+        Self {
+            data: InterpData1D {
+                grid: [x],
+                values: f_x,
+            },
+            strategy,
+            extrapolate,
+        }
+    }
+
+    /// Return an interpolator with a monotonically increasing transform `f` applied to the grid,
+    /// e.g. a unit conversion or offset. Function values are unchanged.
+    ///
+    /// The strategy is re-initialized with the transformed data.
+    /// Errors with [`ValidateError::Monotonicity`] if the transform does not preserve
+    /// the (strict) ordering of the grid.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// // x in m
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2.],
+    ///     array![0., 0.4, 0.8],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// // x in cm
+    /// let interp_cm = interp.map_grid(|x| x * 100.).unwrap();
+    /// assert_eq!(interp_cm.interpolate(&[150.]).unwrap(), interp.interpolate(&[1.5]).unwrap());
+    /// ```
+    pub fn map_grid(
+        &self,
+        f: impl Fn(D::Elem) -> D::Elem,
+    ) -> Result<Interp1DOwned<D::Elem, S>, ValidateError>
+    where
+        S: Strategy1D<OwnedRepr<D::Elem>>,
+        D::Elem: Copy,
+    {
+        let x = self.data.grid[0].mapv(f);
+        let preserves_order = self.data.grid[0]
+            .windows(2)
+            .into_iter()
+            .zip(x.windows(2))
+            .all(|(w, w_mapped)| w[0] == w[1] || w_mapped[0] < w_mapped[1]);
+        if !preserves_order {
+            return Err(ValidateError::Monotonicity(0));
+        }
+        Interp1D::new(
+            x,
+            self.data.values.to_owned(),
+            self.strategy.clone(),
+            self.extrapolate,
+        )
+    }
+
+    /// Smoothness class of the interpolant, see [`Strategy1D::continuity`].
+    pub fn continuity_class(&self) -> u8 {
+        self.strategy.continuity()
+    }
+
+    /// Are the values monotonic (non-decreasing or non-increasing) across the whole grid?
+    ///
+    /// Equivalent to [`Interp1D::monotone_segments`] returning a single segment.
+    pub fn is_monotonic_values(&self) -> bool {
+        self.monotone_segments().len() == 1
+    }
+
+    /// Partition the grid into maximal index ranges `(start, end)` (inclusive)
+    /// over which the values are monotonic, e.g. to find regions where the interpolant is invertible.
+    ///
+    /// Consecutive segments share their turning point,
+    /// and flat runs are merged into the surrounding segment.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2., 3., 4., 5.],
+    ///     array![3., 1., 1., 2., 4., 0.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.monotone_segments(), vec![(0, 2), (2, 4), (4, 5)]);
+    /// assert!(!interp.is_monotonic_values());
+    /// ```
+    pub fn monotone_segments(&self) -> Vec<(usize, usize)> {
+        let values = &self.data.values;
+        let mut segments = Vec::new();
+        let mut start = 0;
+        // Direction of the current segment, `None` while it is flat
+        let mut direction = None;
+        for i in 1..values.len() {
+            let step = values[i].partial_cmp(&values[i - 1]);
+            match (direction, step) {
+                (_, Some(std::cmp::Ordering::Equal)) => {}
+                (None, _) => direction = step,
+                (Some(d), _) if Some(d) == step => {}
+                _ => {
+                    segments.push((start, i - 1));
+                    start = i - 1;
+                    direction = step;
+                }
+            }
+        }
+        segments.push((start, values.len().saturating_sub(1)));
+        segments
+    }
+
+    /// Does `other` have an identical grid, e.g. to combine their values?
+    /// See [`InterpData::same_grid`].
+    pub fn same_grid<D2, S2>(&self, other: &Interp1D<D2, S2>) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        S2: Strategy1D<D2> + Clone,
+    {
+        self.data.same_grid(&other.data)
+    }
+
+    /// Does `other` have an identical grid, to within `epsilon`?
+    /// See [`InterpData::same_grid_approx`].
+    pub fn same_grid_approx<D2, S2>(&self, other: &Interp1D<D2, S2>, epsilon: D::Elem) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        D::Elem: Num + Copy,
+        S2: Strategy1D<D2> + Clone,
+    {
+        self.data.same_grid_approx(&other.data, epsilon)
+    }
+
     /// Return an interpolator with viewed data.
     pub fn view(&self) -> Interp1DViewed<&D::Elem, S>
     where
@@ -132,7 +473,7 @@ where
         Interp1DViewed {
             data: self.data.view(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
         }
     }
 
@@ -145,14 +486,50 @@ where
         Interp1DOwned {
             data: self.data.into_owned(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
         }
     }
 }
 
+impl<T, S> Interp1DOwned<T, S>
+where
+    T: Num + PartialOrd + Copy + Debug,
+    S: Strategy1D<OwnedRepr<T>> + Clone,
+{
+    /// Instantiate one-dimensional interpolator by evaluating `f` at each point of the grid `x`,
+    /// e.g. for a fast lookup approximation of an analytic function.
+    ///
+    /// Errors for the same reasons as [`Interp1D::new`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::from_fn(
+    ///     array![0., 1., 2., 3.],
+    ///     |x| x * x,
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.data.values, array![0., 1., 4., 9.]);
+    /// assert_eq!(interp.interpolate(&[2.5]).unwrap(), 6.5);
+    /// ```
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn from_fn(
+        x: Array1<T>,
+        f: impl Fn(T) -> T,
+        strategy: S,
+        extrapolate: Extrapolate<T>,
+    ) -> Result<Self, ValidateError> {
+        let f_x = x.mapv(f);
+        Self::new(x, f_x, strategy, extrapolate)
+    }
+}
+
 impl<D, S> Interpolator<D::Elem> for Interp1D<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
     S: Strategy1D<D> + Clone,
 {
@@ -173,12 +550,61 @@ where
         let point: &[D::Elem; N] = point
             .try_into()
             .map_err(|_| InterpolateError::PointLength(N))?;
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
         if !(self.data.grid[0].first().unwrap()..=self.data.grid[0].last().unwrap())
             .contains(&&point[0])
         {
             match &self.extrapolate {
                 Extrapolate::Enable => {}
+                Extrapolate::EnableBounded(limit) => {
+                    let (min, max) = (
+                        self.data.grid[0].first().unwrap(),
+                        self.data.grid[0].last().unwrap(),
+                    );
+                    if beyond_limit(point[0], *min, *max, *limit) {
+                        return Err(InterpolateError::ExtrapolateError(format!(
+                            "\n    point[0] = {:?} is more than {limit:?} beyond grid[0] = {:?}",
+                            point[0], self.data.grid[0]
+                        )));
+                    }
+                }
                 Extrapolate::Fill(value) => return Ok(*value),
+                Extrapolate::SaturatingFill { value, rate } => {
+                    let (edge_x, edge_value) = if &point[0] < self.data.grid[0].first().unwrap() {
+                        (
+                            *self.data.grid[0].first().unwrap(),
+                            *self.data.values.first().unwrap(),
+                        )
+                    } else {
+                        (
+                            *self.data.grid[0].last().unwrap(),
+                            *self.data.values.last().unwrap(),
+                        )
+                    };
+                    let distance = if point[0] > edge_x {
+                        point[0] - edge_x
+                    } else {
+                        edge_x - point[0]
+                    };
+                    let change = *rate * distance;
+                    // Move toward `value`, saturating once it is reached
+                    return Ok(if edge_value < *value {
+                        if edge_value + change < *value {
+                            edge_value + change
+                        } else {
+                            *value
+                        }
+                    } else if edge_value - change > *value {
+                        edge_value - change
+                    } else {
+                        *value
+                    });
+                }
+                Extrapolate::NearestValue => {
+                    return Ok(self.data.values[nearest_index(self.data.grid[0].view(), point[0])])
+                }
                 Extrapolate::Clamp => {
                     let clamped_point = [*clamp(
                         &point[0],
@@ -206,19 +632,1039 @@ where
         self.strategy.interpolate(&self.data, point)
     }
 
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let point: &[D::Elem; N] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(N))?;
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let clamped_point = [*clamp(
+            &point[0],
+            self.data.grid[0].first().unwrap(),
+            self.data.grid[0].last().unwrap(),
+        )];
+        self.strategy.interpolate(&self.data, &clamped_point)
+    }
+
     fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
         self.check_extrapolate(&extrapolate)?;
         self.extrapolate = extrapolate;
         Ok(())
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.data.is_contiguous()
+    }
+
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        if dim >= self.ndim() {
+            return None;
+        }
+        let grid = &self.data.grid[dim];
+        Some((*grid.first().unwrap(), *grid.last().unwrap()))
+    }
+
+    #[inline]
+    fn grid(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        self.data.grid.get(dim).map(|grid| grid.view())
+    }
+
+    #[inline]
+    fn values_view(&self) -> Option<ArrayViewD<'_, D::Elem>> {
+        Some(self.data.values.view().into_dyn())
+    }
+
+    /// Returns `Some` if the strategy is a [`Strategy1DEnum`](strategy::enums::Strategy1DEnum)
+    /// or one of its variants' strategies, converting it to the enum.
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<D::Elem>>>
+    where
+        D::Elem: 'static,
+    {
+        let strategy = strategy::enums::Strategy1DEnum::from_any(self.strategy.as_any()?)?;
+        Some(Box::new(Interp1D {
+            data: self.data.view().into_owned(),
+            strategy,
+            extrapolate: self.extrapolate,
+        }))
+    }
+}
+
+impl<D, S> Interp1D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
+    S: Strategy1D<D> + Clone,
+{
+    /// Interpolate at each of `points`, equivalent to calling [`Interpolator::interpolate`] on each.
+    ///
+    /// If all points lie within the grid bounds, this delegates to [`Strategy1D::interpolate_batch`],
+    /// letting the strategy hoist per-point work, e.g. finding bracketing indices
+    /// in a single sweep over sorted points.
+    /// Otherwise, each point is interpolated individually, per the [`Extrapolate`] setting.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2.],
+    ///     array![0., 10., 0.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     interp.interpolate_batch(&array![0.25, 1., 1.5].view()).unwrap(),
+    ///     array![2.5, 10., 5.]
+    /// );
+    /// ```
+    pub fn interpolate_batch(
+        &self,
+        points: &ArrayView1<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let x = &self.data.grid[0];
+        let bounds = x.first().unwrap()..=x.last().unwrap();
+        if points.iter().all(|p| bounds.contains(&p)) {
+            return self.strategy.interpolate_batch(&self.data, points);
+        }
+        points
+            .iter()
+            .map(|&p| self.interpolate(&[p]))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Array1::from_vec)
+    }
+
+    /// Interpolate at `point`, adding the trapezoidal area between `(prev_x, prev_val)`
+    /// and `(point, value)` to the running integral `acc`, then returning `value`.
+    ///
+    /// Fuses table lookup with integration for time-stepping simulations:
+    /// feed each returned value back as `prev_val` on the next step.
+    /// Steps landing on every grid point integrate a [`strategy::Linear`] interpolant exactly.
+    /// `acc` is left unchanged on error.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// // power in W, over time in s
+    /// let interp = Interp1D::new(
+    ///     array![0., 10., 20.],
+    ///     array![0., 100., 100.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let mut energy = 0.;
+    /// let (mut t, mut power) = (0., interp.interpolate(&[0.]).unwrap());
+    /// for t_next in [5., 10., 15., 20.] {
+    ///     power = interp
+    ///         .interpolate_and_accumulate(&[t_next], t, power, &mut energy)
+    ///         .unwrap();
+    ///     t = t_next;
+    /// }
+    /// assert_eq!(energy, 1500.);
+    /// ```
+    pub fn interpolate_and_accumulate(
+        &self,
+        point: &[D::Elem; 1],
+        prev_x: D::Elem,
+        prev_val: D::Elem,
+        acc: &mut D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        let value = self.interpolate(point)?;
+        let two = D::Elem::one() + D::Elem::one();
+        *acc = *acc + (point[0] - prev_x) * (prev_val + value) / two;
+        Ok(value)
+    }
+
+    /// Analytic derivative `[df/dx]` of the interpolant at `point`, see [`Strategy1D::derivative`].
+    ///
+    /// Out-of-bounds points follow the [`Extrapolate`] setting, differentiating what
+    /// [`Interpolator::interpolate`] would return:
+    /// - [`Extrapolate::Enable`], [`Extrapolate::EnableBounded`]: derivative of the strategy's extrapolation
+    /// - [`Extrapolate::Fill`], [`Extrapolate::Clamp`], [`Extrapolate::NearestValue`]: zero, as the value is constant
+    /// - [`Extrapolate::SaturatingFill`]: `rate` toward the fill value, or zero once saturated
+    /// - [`Extrapolate::Wrap`]: derivative at the wrapped point
+    /// - [`Extrapolate::Error`]: an error
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 3.],
+    ///     array![0., 2., 3.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Clamp,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.gradient(&[0.5]).unwrap(), vec![2.]);
+    /// assert_eq!(interp.gradient(&[2.]).unwrap(), vec![0.5]);
+    /// assert_eq!(interp.gradient(&[4.]).unwrap(), vec![0.]);
+    /// ```
+    pub fn gradient(&self, point: &[D::Elem]) -> Result<Vec<D::Elem>, InterpolateError> {
+        let point: &[D::Elem; 1] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(1))?;
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let x = &self.data.grid[0];
+        let (min, max) = (*x.first().unwrap(), *x.last().unwrap());
+        if (min..=max).contains(&point[0]) {
+            return Ok(vec![self.strategy.derivative(&self.data, point)?]);
+        }
+        let derivative = match &self.extrapolate {
+            Extrapolate::Enable | Extrapolate::EnableBounded(_) => {
+                // Checks the bound, if any
+                self.interpolate(point)?;
+                self.strategy.derivative(&self.data, point)?
+            }
+            Extrapolate::Fill(_) | Extrapolate::Clamp | Extrapolate::NearestValue => {
+                D::Elem::zero()
+            }
+            Extrapolate::SaturatingFill { value, rate } => {
+                if self.interpolate(point)? == *value {
+                    D::Elem::zero()
+                } else {
+                    let edge_value = if point[0] < min {
+                        *self.data.values.first().unwrap()
+                    } else {
+                        *self.data.values.last().unwrap()
+                    };
+                    // Moving away from the edge toward `value`
+                    if (point[0] < min) == (edge_value < *value) {
+                        D::Elem::zero() - *rate
+                    } else {
+                        *rate
+                    }
+                }
+            }
+            Extrapolate::Wrap => self
+                .strategy
+                .derivative(&self.data, &[wrap(point[0], min, max)])?,
+            Extrapolate::Error => {
+                return Err(InterpolateError::ExtrapolateError(format!(
+                    "\n    point[0] = {:?} is out of bounds for grid[0] = {:?}",
+                    point[0], x
+                )))
+            }
+        };
+        Ok(vec![derivative])
+    }
+
+    /// Definite integral of the interpolant from `a` to `b`, see [`Strategy1D::integral`],
+    /// e.g. energy from a tabulated power curve.
+    ///
+    /// Integrals with `a > b` are negated, as usual.
+    /// Bounds beyond the grid follow the [`Extrapolate`] setting, integrating what
+    /// [`Interpolator::interpolate`] would return:
+    /// - [`Extrapolate::Enable`], [`Extrapolate::EnableBounded`]: integral of the strategy's extrapolation
+    /// - [`Extrapolate::Fill`], [`Extrapolate::SaturatingFill`], [`Extrapolate::Clamp`],
+    ///   [`Extrapolate::NearestValue`]: integral of the fill or edge values beyond the grid
+    /// - [`Extrapolate::Wrap`]: whole periods of the grid, plus the remainder
+    /// - [`Extrapolate::Error`]: an error
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// // power in W, over time in s
+    /// let interp = Interp1D::new(
+    ///     array![0., 10., 20.],
+    ///     array![0., 100., 100.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Fill(0.),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.integrate(0., 20.).unwrap(), 1500.);
+    /// assert_eq!(interp.integrate(5., 15.).unwrap(), 875.);
+    /// assert_eq!(interp.integrate(15., -5.).unwrap(), -1000.);
+    /// ```
+    pub fn integrate(&self, a: D::Elem, b: D::Elem) -> Result<D::Elem, InterpolateError> {
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        if b < a {
+            return self
+                .integrate(b, a)
+                .map(|integral| D::Elem::zero() - integral);
+        }
+        let x = &self.data.grid[0];
+        let f_x = &self.data.values;
+        let (min, max) = (*x.first().unwrap(), *x.last().unwrap());
+        if min <= a && b <= max {
+            return self.strategy.integral(&self.data, a, b);
+        }
+        match &self.extrapolate {
+            Extrapolate::Enable => self.strategy.integral(&self.data, a, b),
+            Extrapolate::EnableBounded(limit) => {
+                for bound in [a, b] {
+                    if !(min..=max).contains(&bound) && beyond_limit(bound, min, max, *limit) {
+                        return Err(InterpolateError::ExtrapolateError(format!(
+                            "\n    bound = {bound:?} is more than {limit:?} beyond grid[0] = {x:?}"
+                        )));
+                    }
+                }
+                self.strategy.integral(&self.data, a, b)
+            }
+            // A single point wraps to itself, so the interpolant is constant
+            Extrapolate::Wrap if min == max => Ok(f_x[0] * (b - a)),
+            Extrapolate::Wrap => {
+                // Whole periods, plus the integral from `min` to the wrapped bound
+                let period = self.strategy.integral(&self.data, min, max)?;
+                let antiderivative = |bound: D::Elem| {
+                    let periods = (bound - min).div_euclid(&(max - min));
+                    self.strategy
+                        .integral(&self.data, min, wrap(bound, min, max))
+                        .map(|remainder| periods * period + remainder)
+                };
+                Ok(antiderivative(b)? - antiderivative(a)?)
+            }
+            Extrapolate::Error => Err(InterpolateError::ExtrapolateError(format!(
+                "\n    bounds ({a:?}, {b:?}) are out of bounds for grid[0] = {x:?}"
+            ))),
+            Extrapolate::Fill(_)
+            | Extrapolate::SaturatingFill { .. }
+            | Extrapolate::Clamp
+            | Extrapolate::NearestValue => {
+                let two = D::Elem::one() + D::Elem::one();
+                // Integral over distances `s_1..s_2` beyond the edge at index `edge`
+                let beyond = |edge: usize, s_1: D::Elem, s_2: D::Elem| match &self.extrapolate {
+                    Extrapolate::Fill(value) => Ok(*value * (s_2 - s_1)),
+                    Extrapolate::SaturatingFill { value, rate } => {
+                        let edge_value = f_x[edge];
+                        let (gap, sign) = if edge_value < *value {
+                            (*value - edge_value, D::Elem::one())
+                        } else {
+                            (edge_value - *value, D::Elem::zero() - D::Elem::one())
+                        };
+                        // Distance at which `value` is reached
+                        let s_sat = if rate.is_zero() {
+                            s_2
+                        } else {
+                            clamp(gap / *rate, s_1, s_2)
+                        };
+                        let f = |s| edge_value + sign * *rate * s;
+                        Ok((s_sat - s_1) * (f(s_1) + f(s_sat)) / two + *value * (s_2 - s_sat))
+                    }
+                    Extrapolate::NearestValue => Ok(f_x[edge] * (s_2 - s_1)),
+                    _ => self
+                        .strategy
+                        .interpolate(&self.data, &[x[edge]])
+                        .map(|value| value * (s_2 - s_1)),
+                };
+                let mut integral = if b <= min || a >= max {
+                    D::Elem::zero()
+                } else {
+                    self.strategy
+                        .integral(&self.data, clamp(a, min, max), clamp(b, min, max))?
+                };
+                if a < min {
+                    let upper = if b < min { b } else { min };
+                    integral = integral + beyond(0, min - upper, min - a)?;
+                }
+                if b > max {
+                    let lower = if a > max { a } else { max };
+                    integral = integral + beyond(x.len() - 1, lower - max, b - max)?;
+                }
+                Ok(integral)
+            }
+        }
+    }
+
+    /// Sorted, deduplicated union of this and another interpolator's grids.
+    pub fn union_grid(&self, other: &Self) -> Array1<D::Elem> {
+        self.data.grid[0]
+            .iter()
+            .merge_by(other.data.grid[0].iter(), |a, b| a <= b)
+            .dedup()
+            .copied()
+            .collect()
+    }
+
+    /// Resample this and another interpolator onto their [`Interp1D::union_grid`],
+    /// e.g. to compare or combine tables defined on different grids elementwise.
+    ///
+    /// Values at each table's own grid points are preserved.
+    /// Union points outside a table's grid are handled per that table's [`Extrapolate`] setting,
+    /// so this errors for tables with [`Extrapolate::Error`] unless their grids span the same range.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let a = Interp1D::new(
+    ///     array![0., 2.],
+    ///     array![0., 2.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let b = Interp1D::new(
+    ///     array![0., 1., 2.],
+    ///     array![1., 0., 1.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let [a, b] = a.resample_to_union(&b).unwrap();
+    /// assert_eq!(a.data.grid[0], array![0., 1., 2.]);
+    /// assert_eq!(a.data.values, array![0., 1., 2.]);
+    /// assert_eq!(b.data.values, array![1., 0., 1.]);
+    /// ```
+    pub fn resample_to_union(
+        &self,
+        other: &Self,
+    ) -> Result<[Interp1DOwned<D::Elem, S>; 2], InterpolateError>
+    where
+        S: Strategy1D<OwnedRepr<D::Elem>>,
+    {
+        let grid = self.union_grid(other);
+        let resample = |interp: &Self| {
+            let values = grid
+                .iter()
+                .map(|&x| interp.interpolate(&[x]))
+                .collect::<Result<Array1<_>, _>>()?;
+            Interp1D::new(
+                grid.clone(),
+                values,
+                interp.strategy.clone(),
+                interp.extrapolate,
+            )
+            .map_err(|err| InterpolateError::Other(err.to_string()))
+        };
+        Ok([resample(self)?, resample(other)?])
+    }
+}
+
+impl<D, S> Interp1D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Euclid + Debug,
+    S: Strategy1D<D> + Clone,
+{
+    /// Root-mean-square error between the interpolant and a reference function `f`
+    /// at the supplied sample points, e.g. to compare the accuracy of strategies on a test function.
+    ///
+    /// Errors if interpolating at any sample point fails, or if there are no sample points.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2.],
+    ///     array![0., 1., 4.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let samples = array![0., 0.5, 1., 1.5, 2.];
+    /// // errors of 0.25 at x = 0.5 and x = 1.5
+    /// let rmse = interp.rmse_against(|x| x * x, samples.view()).unwrap();
+    /// assert_eq!(rmse, (2. * 0.25f64.powi(2) / 5.).sqrt());
+    /// ```
+    pub fn rmse_against(
+        &self,
+        f: impl Fn(D::Elem) -> D::Elem,
+        sample_points: ArrayView1<D::Elem>,
+    ) -> Result<D::Elem, InterpolateError> {
+        if sample_points.is_empty() {
+            return Err(InterpolateError::Other(
+                "at least one sample point is required".into(),
+            ));
+        }
+        let (sum, count) = sample_points.iter().try_fold(
+            (D::Elem::zero(), D::Elem::zero()),
+            |(sum, count), &x| {
+                let error = self.interpolate(&[x])? - f(x);
+                Ok::<_, InterpolateError>((sum + error * error, count + D::Elem::one()))
+            },
+        )?;
+        Ok((sum / count).sqrt())
+    }
+}
+
+impl<T, S> Interp1DOwned<T, S>
+where
+    T: Num + PartialOrd + Copy + Debug,
+    S: Strategy1D<OwnedRepr<T>> + Clone,
+{
+    /// Keep only the `(x, f(x))` pairs for which `pred` returns `true`,
+    /// then revalidate the data and re-initialize the strategy.
+    /// Grid indices held by the strategy, e.g. [`strategy::StaircaseLinear::breakpoints`],
+    /// are remapped to the remaining points (see [`Strategy1D::retain_indices`]).
+    ///
+    /// Useful for cleaning measured data in place, e.g. dropping points with `NaN` values.
+    /// If the remaining data is invalid for the interpolator
+    /// (e.g. empty, or too few points for the strategy),
+    /// an error is returned and the interpolator is left unchanged.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let mut interp = Interp1D::new(
+    ///     array![0., 1., 2., 3.],
+    ///     array![0., f64::NAN, 2., 3.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// interp.retain(|_, f_x| !f_x.is_nan()).unwrap();
+    /// assert_eq!(interp.interpolate(&[1.]).unwrap(), 1.);
+    /// ```
+    pub fn retain(&mut self, pred: impl Fn(T, T) -> bool) -> Result<(), ValidateError> {
+        let (kept, (x, f_x)): (Vec<usize>, (Vec<T>, Vec<T>)) = self.data.grid[0]
+            .iter()
+            .zip(self.data.values.iter())
+            .map(|(&x, &f_x)| (x, f_x))
+            .enumerate()
+            .filter(|&(_, (x, f_x))| pred(x, f_x))
+            .unzip();
+        let mut strategy = self.strategy.clone();
+        strategy.retain_indices(&kept);
+        *self = Self::new(
+            Array1::from_vec(x),
+            Array1::from_vec(f_x),
+            strategy,
+            self.extrapolate,
+        )?;
+        Ok(())
+    }
+}
+
+impl<T, S> Interp1DOwned<T, S>
+where
+    T: Float + Euclid + Debug,
+    S: Strategy1D<OwnedRepr<T>> + Clone,
+{
+    /// Number of uniformly spaced points [`Interp1DOwned::adaptive_from_fn`] starts from.
+    const ADAPTIVE_SEED_POINTS: usize = 9;
+    /// Maximum number of refinement passes in [`Interp1DOwned::adaptive_from_fn`],
+    /// i.e. the maximum number of times any seed interval is bisected.
+    const ADAPTIVE_MAX_PASSES: usize = 32;
+
+    /// Tabulate `f` over `domain`, refining the grid where the interpolant is inaccurate.
+    ///
+    /// Starting from a uniform grid of a few points, each pass evaluates the interpolant at every interval midpoint,
+    /// and bisects intervals where it deviates from `f` by more than `tol`,
+    /// until no interval needs bisecting (or after a fixed maximum number of passes).
+    /// Points therefore concentrate where `f` curves sharply,
+    /// giving accurate tables with far fewer points than a uniform grid.
+    /// Features narrower than the starting grid spacing may be missed.
+    ///
+    /// The returned interpolator uses [`Extrapolate::Error`].
+    ///
+    /// Errors if `domain` is not increasing, or if the strategy fails to initialize.
+    ///
+    /// # Example:
+    /// ```
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1DOwned::adaptive_from_fn(
+    ///     |x: f64| x.sqrt(),
+    ///     (0., 4.),
+    ///     1e-3,
+    ///     strategy::Linear,
+    /// )
+    /// .unwrap();
+    /// let x = &interp.data.grid[0];
+    /// // Grid is densest near x = 0, where sqrt is steepest
+    /// assert!(x[1] - x[0] < x[x.len() - 1] - x[x.len() - 2]);
+    /// ```
+    pub fn adaptive_from_fn(
+        f: impl Fn(T) -> T,
+        domain: (T, T),
+        tol: T,
+        strategy: S,
+    ) -> Result<Self, ValidateError> {
+        let (min, max) = domain;
+        if min.partial_cmp(&max) != Some(std::cmp::Ordering::Less) {
+            return Err(ValidateError::Other(format!(
+                "adaptive domain must be increasing, found {domain:?}"
+            )));
+        }
+        let n = Self::ADAPTIVE_SEED_POINTS;
+        let mut x: Vec<T> = (0..n)
+            .map(|i| min + (max - min) * T::from(i).unwrap() / T::from(n - 1).unwrap())
+            .collect();
+        let mut f_x: Vec<T> = x.iter().map(|&x| f(x)).collect();
+        let two = T::one() + T::one();
+        for _ in 0..Self::ADAPTIVE_MAX_PASSES {
+            let interp = Self::new(
+                Array1::from_vec(x.clone()),
+                Array1::from_vec(f_x.clone()),
+                strategy.clone(),
+                Extrapolate::Error,
+            )?;
+            let (mut refined_x, mut refined_f_x) = (vec![x[0]], vec![f_x[0]]);
+            for i in 1..x.len() {
+                let mid = (x[i - 1] + x[i]) / two;
+                // Stop bisecting once the interval cannot be split further
+                if x[i - 1] < mid && mid < x[i] {
+                    let f_mid = f(mid);
+                    let error = interp
+                        .interpolate(&[mid])
+                        .map_err(|e| ValidateError::Other(e.to_string()))?
+                        - f_mid;
+                    if error.abs() > tol {
+                        refined_x.push(mid);
+                        refined_f_x.push(f_mid);
+                    }
+                }
+                refined_x.push(x[i]);
+                refined_f_x.push(f_x[i]);
+            }
+            if refined_x.len() == x.len() {
+                return Ok(interp);
+            }
+            (x, f_x) = (refined_x, refined_f_x);
+        }
+        Self::new(
+            Array1::from_vec(x),
+            Array1::from_vec(f_x),
+            strategy,
+            Extrapolate::Error,
+        )
+    }
+}
+
+macro_rules! arithmetic_impl {
+    ($Trait:ident, $method:ident, $op:tt) => {
+        /// Elementwise operation on the values of two interpolators sharing a grid,
+        /// producing a [`strategy::Linear`] interpolator with the left operand's [`Extrapolate`] setting.
+        ///
+        /// Errors if the grids differ, see [`Interp1D::resample_to_union`].
+        impl<D, S> std::ops::$Trait for &Interp1D<D, S>
+        where
+            D: Data + RawDataClone,
+            D::Elem: Num + PartialOrd + Debug + Copy,
+            S: Strategy1D<D> + Clone,
+        {
+            type Output = Result<Interp1DOwned<D::Elem, strategy::Linear>, ValidateError>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                if !self.same_grid(rhs) {
+                    return Err(ValidateError::Other(
+                        "interpolator grids differ, see `Interp1D::resample_to_union`".into(),
+                    ));
+                }
+                Interp1D::new(
+                    self.data.grid[0].to_owned(),
+                    &self.data.values $op &rhs.data.values,
+                    strategy::Linear,
+                    self.extrapolate,
+                )
+            }
+        }
+    };
+}
+
+arithmetic_impl!(Add, add, +);
+arithmetic_impl!(Sub, sub, -);
+arithmetic_impl!(Mul, mul, *);
+arithmetic_impl!(Div, div, /);
+
+impl<D> Interp1D<D, strategy::Linear>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    /// Find all `x` within the grid bounds where the piecewise-linear interpolant equals `target`.
+    ///
+    /// Data need not be monotonic: each segment is scanned for a crossing,
+    /// so e.g. resonance curves can return several solutions.
+    /// Grid points whose value equals `target` are included,
+    /// meaning a segment lying flat at `target` contributes both of its endpoints.
+    /// Solutions are returned in increasing order, and an empty [`Vec`] if there are none.
+    ///
+    /// Errors if `target` is unordered (e.g. `NaN`).
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2., 3., 4.],
+    ///     array![2., 0., 2., 0., 2.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.solve_all(1.).unwrap(), vec![0.5, 1.5, 2.5, 3.5]);
+    /// assert_eq!(interp.solve_all(0.).unwrap(), vec![1., 3.]);
+    /// assert!(interp.solve_all(3.).unwrap().is_empty());
+    /// ```
+    pub fn solve_all(&self, target: D::Elem) -> Result<Vec<D::Elem>, InterpolateError> {
+        if target.partial_cmp(&target).is_none() {
+            return Err(InterpolateError::Other(format!(
+                "cannot solve for unordered target {target:?}"
+            )));
+        }
+        let x = &self.data.grid[0];
+        let f_x = &self.data.values;
+        let mut solutions = Vec::new();
+        for i in 0..x.len() {
+            if f_x[i] == target {
+                solutions.push(x[i]);
+            }
+            if i + 1 < x.len()
+                && ((f_x[i] < target && target < f_x[i + 1])
+                    || (f_x[i] > target && target > f_x[i + 1]))
+            {
+                solutions
+                    .push(x[i] + (target - f_x[i]) * (x[i + 1] - x[i]) / (f_x[i + 1] - f_x[i]));
+            }
+        }
+        Ok(solutions)
+    }
+
+    /// Find the `x` where the piecewise-linear interpolant equals `target`,
+    /// e.g. for root-finding against a monotonic lookup table.
+    ///
+    /// Values must be strictly monotonic (increasing or decreasing), so that the solution is unique.
+    /// For non-monotonic data, see [`Interp1D::solve_all`].
+    ///
+    /// Targets outside the range of the values follow the [`Extrapolate`] setting:
+    /// - [`Extrapolate::Enable`]: solve along the extended edge segment
+    /// - [`Extrapolate::EnableBounded`]: as above, erroring if the solution is further than
+    ///   the limit beyond the grid
+    /// - [`Extrapolate::Clamp`], [`Extrapolate::NearestValue`]: the grid edge nearest in value
+    /// - [`Extrapolate::Error`]: an error
+    ///
+    /// Other variants have no inverse, and error for out-of-range targets.
+    ///
+    /// Errors if the values are not strictly monotonic, or if `target` is unordered (e.g. `NaN`).
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2.],
+    ///     array![10., 6., 4.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Enable,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.inverse(8.).unwrap(), 0.5);
+    /// assert_eq!(interp.inverse(3.).unwrap(), 2.5);
+    /// ```
+    pub fn inverse(&self, target: D::Elem) -> Result<D::Elem, InterpolateError> {
+        if target.partial_cmp(&target).is_none() {
+            return Err(InterpolateError::Other(format!(
+                "cannot solve for unordered target {target:?}"
+            )));
+        }
+        let x = &self.data.grid[0];
+        let f_x = &self.data.values;
+        let n = x.len();
+        let increasing = f_x[n - 1] > f_x[0];
+        // Is `a` strictly before `b` in the direction of the values?
+        let before = |a: D::Elem, b: D::Elem| if increasing { a < b } else { a > b };
+        if let Some(i) = (0..n - 1).find(|&i| !before(f_x[i], f_x[i + 1])) {
+            return Err(InterpolateError::Other(format!(
+                "values must be strictly monotonic to invert, but f_x[{i}] = {:?} and f_x[{}] = {:?}",
+                f_x[i],
+                i + 1,
+                f_x[i + 1]
+            )));
+        }
+        let below_first = before(target, f_x[0]);
+        let beyond_last = before(f_x[n - 1], target);
+        let i = if below_first || beyond_last {
+            match self.extrapolate {
+                Extrapolate::Enable | Extrapolate::EnableBounded(_) if n > 1 => {
+                    if below_first {
+                        0
+                    } else {
+                        n - 2
+                    }
+                }
+                Extrapolate::Clamp | Extrapolate::NearestValue => {
+                    return Ok(if below_first { x[0] } else { x[n - 1] })
+                }
+                Extrapolate::Error => {
+                    return Err(InterpolateError::ExtrapolateError(format!(
+                        "\n    target = {target:?} is out of range of values = {f_x:?}"
+                    )))
+                }
+                _ => {
+                    return Err(InterpolateError::Other(format!(
+                        "cannot invert for out-of-range target {target:?} with {:?}",
+                        self.extrapolate
+                    )))
+                }
+            }
+        } else if target == f_x[n - 1] {
+            return Ok(x[n - 1]);
+        } else {
+            // Binary search for the bracketing segment
+            let (mut lower, mut upper) = (0, n - 1);
+            while upper - lower > 1 {
+                let mid = (lower + upper) / 2;
+                if !before(target, f_x[mid]) {
+                    lower = mid;
+                } else {
+                    upper = mid;
+                }
+            }
+            lower
+        };
+        let solution = x[i] + (target - f_x[i]) * (x[i + 1] - x[i]) / (f_x[i + 1] - f_x[i]);
+        if let Extrapolate::EnableBounded(limit) = self.extrapolate {
+            if (below_first || beyond_last) && beyond_limit(solution, x[0], x[n - 1], limit) {
+                return Err(InterpolateError::ExtrapolateError(format!(
+                    "\n    solution x = {solution:?} for target = {target:?} is more than {limit:?} beyond grid[0] = {x:?}"
+                )));
+            }
+        }
+        Ok(solution)
+    }
+
+    /// Return the `(x, value)` of the maximum of the interpolant within the grid bounds.
+    ///
+    /// A piecewise-linear interpolant attains its maximum at a grid point,
+    /// so this is the top node. Ties resolve to the lowest `x`.
+    ///
+    /// Errors if any value is unordered (e.g. `NaN`).
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![1000., 2000., 3000., 4000.],
+    ///     array![40., 75., 90., 80.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.peak().unwrap(), (3000., 90.));
+    /// ```
+    pub fn peak(&self) -> Result<(D::Elem, D::Elem), InterpolateError> {
+        let f_x = &self.data.values;
+        let mut i_max = 0;
+        for (i, value) in f_x.iter().enumerate() {
+            match value.partial_cmp(&f_x[i_max]) {
+                Some(std::cmp::Ordering::Greater) => i_max = i,
+                Some(_) => {}
+                None => {
+                    return Err(InterpolateError::Other(format!(
+                        "cannot find peak of unordered value {value:?} at index {i}"
+                    )))
+                }
+            }
+        }
+        Ok((self.data.grid[0][i_max], f_x[i_max]))
+    }
+}
+
+impl<D> Interp1D<D, strategy::Linear>
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Euclid + Debug,
+{
+    /// Interpolate at `point`, then round the result to an integer per `rounding`,
+    /// e.g. for integer-valued lookup tables such as discrete gear selection.
+    ///
+    /// Errors if the rounded result is not finite or is out of range for [`i64`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::interpolator::Rounding;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp1D::new(
+    ///     array![0., 10., 20.],
+    ///     array![1., 2., 4.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.interpolate_rounded(&[12.], Rounding::Nearest).unwrap(), 2);
+    /// assert_eq!(interp.interpolate_rounded(&[12.], Rounding::Ceil).unwrap(), 3);
+    /// ```
+    pub fn interpolate_rounded(
+        &self,
+        point: &[D::Elem; 1],
+        rounding: Rounding,
+    ) -> Result<i64, InterpolateError> {
+        let value = self.interpolate(point)?;
+        let rounded = match rounding {
+            Rounding::Nearest => value.round(),
+            Rounding::Floor => value.floor(),
+            Rounding::Ceil => value.ceil(),
+        };
+        rounded.to_i64().ok_or_else(|| {
+            InterpolateError::Other(format!(
+                "interpolated value {value:?} cannot be rounded to an i64"
+            ))
+        })
+    }
+}
+
+impl<T> Interp1DOwned<T, strategy::Newton<T>>
+where
+    T: Num + PartialOrd + Copy + Debug,
+{
+    /// Append a data point beyond the end of the grid,
+    /// updating the [`strategy::Newton`] polynomial in `O(n)` rather than re-initializing it.
+    ///
+    /// Errors if `x` is not greater than the last grid point.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// // f(x) = x^2
+    /// let mut interp = Interp1D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1.],
+    ///     strategy::Newton::new(),
+    ///     Extrapolate::Enable,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.interpolate(&[3.]).unwrap(), 3.);
+    /// interp.extend(2., 4.).unwrap();
+    /// assert_eq!(interp.interpolate(&[3.]).unwrap(), 9.);
+    /// ```
+    pub fn extend(&mut self, x: T, f_x: T) -> Result<(), ValidateError> {
+        // Also rejects `x` incomparable to the grid, e.g. NaN
+        if x.partial_cmp(self.data.grid[0].last().unwrap()) != Some(std::cmp::Ordering::Greater) {
+            return Err(ValidateError::Monotonicity(0));
+        }
+        self.strategy.extend(x, f_x)?;
+        self.data.grid[0]
+            .push(Axis(0), ndarray::aview0(&x))
+            .unwrap();
+        self.data
+            .values
+            .push(Axis(0), ndarray::aview0(&f_x))
+            .unwrap();
+        Ok(())
+    }
+}
+
+impl<T, S> Interp1DOwned<T, S>
+where
+    T: Num + PartialOrd + Copy + Debug,
+    S: Strategy1D<OwnedRepr<T>> + Clone,
+{
+    /// Append the data points of `other` lying strictly beyond the end of the grid,
+    /// re-validating and re-initializing the strategy afterward.
+    ///
+    /// Unlike an exact concatenation, the tables need not be adjacent:
+    /// any gap is bridged by the strategy between the last existing point and the first appended point.
+    /// Points of `other` within the current domain are ignored.
+    /// On error, `self` is left unchanged.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let mut interp = Interp1D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let other = Interp1D::new(
+    ///     array![0.5, 3., 4.],
+    ///     array![9., 5., 0.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// interp.extend_from_interp(&other).unwrap();
+    /// assert_eq!(interp.data.grid[0], array![0., 1., 3., 4.]);
+    /// assert_eq!(interp.interpolate(&[2.]).unwrap(), 3.);
+    /// ```
+    pub fn extend_from_interp(&mut self, other: &Self) -> Result<(), ValidateError> {
+        let last = self.data.grid[0].last().unwrap();
+        let other_x = &other.data.grid[0];
+        let start = other_x
+            .iter()
+            .position(|x| x > last)
+            .unwrap_or(other_x.len());
+        let mut data = self.data.clone();
+        data.grid[0]
+            .append(Axis(0), other_x.slice(s![start..]))
+            .map_err(|e| ValidateError::Other(e.to_string()))?;
+        data.values
+            .append(Axis(0), other.data.values.slice(s![start..]))
+            .map_err(|e| ValidateError::Other(e.to_string()))?;
+        data.validate()?;
+        let mut strategy = self.strategy.clone();
+        strategy.init(&data)?;
+        self.data = data;
+        self.strategy = strategy;
+        Ok(())
+    }
+
+    /// Append a data point `(x, f_x)` beyond the end of the grid, e.g. as samples stream in,
+    /// re-initializing the strategy afterward.
+    ///
+    /// This costs `O(n)` per push, as the strategy is re-initialized over all data
+    /// (and the arrays may need reallocating),
+    /// but avoids reconstructing and re-validating the interpolator.
+    /// See [`Interp1DOwned::extend`] for an `O(n)` update specific to [`strategy::Newton`].
+    ///
+    /// Errors if `x` is not greater than the last grid point, leaving `self` unchanged.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let mut interp = Interp1D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert!(interp.interpolate(&[1.5]).is_err());
+    /// interp.push(2., 5.).unwrap();
+    /// assert_eq!(interp.interpolate(&[1.5]).unwrap(), 3.);
+    /// assert!(interp.push(2., 6.).is_err());
+    /// ```
+    pub fn push(&mut self, x: T, f_x: T) -> Result<(), ValidateError> {
+        // Also rejects `x` incomparable to the grid, e.g. NaN
+        if x.partial_cmp(self.data.grid[0].last().unwrap()) != Some(std::cmp::Ordering::Greater) {
+            return Err(ValidateError::Monotonicity(0));
+        }
+        self.data.grid[0]
+            .push(Axis(0), ndarray::aview0(&x))
+            .map_err(|e| ValidateError::Other(e.to_string()))?;
+        self.data
+            .values
+            .push(Axis(0), ndarray::aview0(&f_x))
+            .map_err(|e| ValidateError::Other(e.to_string()))?;
+        let mut strategy = self.strategy.clone();
+        if let Err(err) = strategy.init(&self.data) {
+            self.data.grid[0].slice_collapse(s![..-1]);
+            self.data.values.slice_collapse(s![..-1]);
+            return Err(err);
+        }
+        self.strategy = strategy;
+        Ok(())
+    }
 }
 
 impl<D> Interp1D<D, Box<dyn Strategy1D<D>>>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialEq + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(&mut self, strategy: Box<dyn Strategy1D<D>>) -> Result<(), ValidateError> {
         self.strategy = strategy;
         self.check_extrapolate(&self.extrapolate)
@@ -227,10 +1673,11 @@ where
 
 impl<D> Interp1D<D, strategy::enums::Strategy1DEnum>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(
         &mut self,
         strategy: impl Into<strategy::enums::Strategy1DEnum>,
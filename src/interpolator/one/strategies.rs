@@ -3,7 +3,7 @@ use strategy::*;
 
 impl<D> Strategy1D<D> for Linear
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -16,6 +16,129 @@ where
         }
         // Extrapolation is checked previously in Interpolator::interpolate,
         // meaning by now, point is within grid bounds or extrapolation is enabled
+        let (x_l, x_diff) = find_bracket(data, &point[0])?;
+        let x_u = x_l + 1;
+        Ok(data.values[x_l] * (D::Elem::one() - x_diff) + data.values[x_u] * x_diff)
+    }
+
+    fn interpolate_bracketed(
+        &self,
+        data: &InterpData1D<D>,
+        _point: &[D::Elem; 1],
+        (x_l, x_diff): (usize, D::Elem),
+    ) -> Result<D::Elem, InterpolateError> {
+        let x_u = x_l + 1;
+        // Points on the grid give exact values, as in `interpolate`
+        Ok(if x_diff.is_zero() {
+            data.values[x_l]
+        } else if x_diff.is_one() {
+            data.values[x_u]
+        } else {
+            data.values[x_l] * (D::Elem::one() - x_diff) + data.values[x_u] * x_diff
+        })
+    }
+
+    /// Finds all bracketing indices up front, with a single sweep for sorted points.
+    fn interpolate_batch(
+        &self,
+        data: &InterpData1D<D>,
+        points: &ArrayView1<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        let x = &data.grid[0];
+        if x.len() < 2 {
+            return points
+                .iter()
+                .map(|&p| Strategy1D::interpolate(self, data, &[p]))
+                .collect();
+        }
+        let last_lower = x.len() - 2;
+        let brackets = data.index_brackets(points);
+        Ok(ndarray::Zip::from(points)
+            .and(&brackets)
+            .map_collect(|&p, &x_l| {
+                // Points beyond the grid extrapolate from the edge segments
+                let x_l = x_l.min(last_lower);
+                let x_u = x_l + 1;
+                if p == x[x_l] {
+                    data.values[x_l]
+                } else if p == x[x_u] {
+                    data.values[x_u]
+                } else {
+                    let x_diff = (p - x[x_l]) / (x[x_u] - x[x_l]);
+                    data.values[x_l] * (D::Elem::one() - x_diff) + data.values[x_u] * x_diff
+                }
+            }))
+    }
+
+    /// Slope of the segment containing `point`.
+    fn derivative(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let (x_l, _) = find_bracket(data, &point[0])?;
+        let x_u = x_l + 1;
+        Ok((data.values[x_u] - data.values[x_l]) / (data.grid[0][x_u] - data.grid[0][x_l]))
+    }
+
+    /// Sum of trapezoids over the covered segments, including partial segments at the ends.
+    fn integral(
+        &self,
+        data: &InterpData1D<D>,
+        lower: D::Elem,
+        upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        let x = &data.grid[0];
+        let f_x = &data.values;
+        let last = x.len() - 1;
+        let slope = |i: usize| (f_x[i + 1] - f_x[i]) / (x[i + 1] - x[i]);
+        let tail_slopes = if last == 0 {
+            (D::Elem::zero(), D::Elem::zero())
+        } else {
+            (slope(0), slope(last - 1))
+        };
+        Ok(piecewise_integral(
+            data,
+            lower,
+            upper,
+            tail_slopes,
+            |i, p, q| {
+                let f = |x_val| f_x[i] + slope(i) * (x_val - x[i]);
+                trapezoid(p, q, f(p), f(q))
+            },
+        ))
+    }
+
+    /// Returns `true`.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+
+    /// Returns `1` (C0).
+    fn continuity(&self) -> u8 {
+        1
+    }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "fixed-point")]
+impl<D> Strategy1D<D> for FixedLinear
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Copy + Debug,
+{
+    fn interpolate(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        if let Some(i) = data.grid[0].iter().position(|&x_val| x_val == point[0]) {
+            return Ok(data.values[i]);
+        }
+        check_brackets(&data.grid)?;
         let x_l = if &point[0] < data.grid[0].first().unwrap() {
             0
         } else if &point[0] > data.grid[0].last().unwrap() {
@@ -24,19 +147,25 @@ where
             find_nearest_index(data.grid[0].view(), &point[0])
         };
         let x_u = x_l + 1;
-        let x_diff = (point[0] - data.grid[0][x_l]) / (data.grid[0][x_u] - data.grid[0][x_l]);
-        Ok(data.values[x_l] * (D::Elem::one() - x_diff) + data.values[x_u] * x_diff)
+        let rise = (data.values[x_u] - data.values[x_l]) * (point[0] - data.grid[0][x_l]);
+        let run = data.grid[0][x_u] - data.grid[0][x_l];
+        Ok(data.values[x_l] + rise.div_euclid(&run))
     }
 
     /// Returns `true`.
     fn allow_extrapolate(&self) -> bool {
         true
     }
+
+    /// Returns `1` (C0), up to rounding.
+    fn continuity(&self) -> u8 {
+        1
+    }
 }
 
 impl<D> Strategy1D<D> for Nearest
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -47,6 +176,7 @@ where
         if let Some(i) = data.grid[0].iter().position(|&x_val| x_val == point[0]) {
             return Ok(data.values[i]);
         }
+        check_brackets(&data.grid)?;
         let x_l = find_nearest_index(data.grid[0].view(), &point[0]);
         let x_u = x_l + 1;
         let i = if point[0] - data.grid[0][x_l] < data.grid[0][x_u] - point[0] {
@@ -57,15 +187,76 @@ where
         Ok(data.values[i])
     }
 
+    /// Finds all bracketing indices up front, with a single sweep for sorted points.
+    fn interpolate_batch(
+        &self,
+        data: &InterpData1D<D>,
+        points: &ArrayView1<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        let x = &data.grid[0];
+        if x.len() < 2 {
+            return points
+                .iter()
+                .map(|&p| Strategy1D::interpolate(self, data, &[p]))
+                .collect();
+        }
+        let brackets = data.index_brackets(points);
+        Ok(ndarray::Zip::from(points)
+            .and(&brackets)
+            .map_collect(|&p, &x_l| {
+                let x_u = x_l + 1;
+                if p - x[x_l] < x[x_u] - p {
+                    data.values[x_l]
+                } else {
+                    data.values[x_u]
+                }
+            }))
+    }
+
+    /// Returns zero, as the interpolant is piecewise constant.
+    fn derivative(
+        &self,
+        _data: &InterpData1D<D>,
+        _point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        Ok(D::Elem::zero())
+    }
+
+    fn integral(
+        &self,
+        data: &InterpData1D<D>,
+        lower: D::Elem,
+        upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        let x = &data.grid[0];
+        let f_x = &data.values;
+        let two = D::Elem::one() + D::Elem::one();
+        Ok(piecewise_integral(
+            data,
+            lower,
+            upper,
+            zero_slopes(),
+            |i, p, q| {
+                // Switches value at the segment midpoint
+                let mid = clamp((x[i] + x[i + 1]) / two, p, q);
+                f_x[i] * (mid - p) + f_x[i + 1] * (q - mid)
+            },
+        ))
+    }
+
     /// Returns `false`.
     fn allow_extrapolate(&self) -> bool {
         false
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
 }
 
 impl<D> Strategy1D<D> for LeftNearest
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -76,19 +267,49 @@ where
         if let Some(i) = data.grid[0].iter().position(|&x_val| x_val == point[0]) {
             return Ok(data.values[i]);
         }
+        check_brackets(&data.grid)?;
         let x_l = find_nearest_index(data.grid[0].view(), &point[0]);
         Ok(data.values[x_l])
     }
 
+    /// Returns zero, as the interpolant is piecewise constant.
+    fn derivative(
+        &self,
+        _data: &InterpData1D<D>,
+        _point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        Ok(D::Elem::zero())
+    }
+
+    fn integral(
+        &self,
+        data: &InterpData1D<D>,
+        lower: D::Elem,
+        upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        let f_x = &data.values;
+        Ok(piecewise_integral(
+            data,
+            lower,
+            upper,
+            zero_slopes(),
+            |i, p, q| f_x[i] * (q - p),
+        ))
+    }
+
     /// Returns `false`.
     fn allow_extrapolate(&self) -> bool {
         false
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
 }
 
 impl<D> Strategy1D<D> for RightNearest
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -99,12 +320,733 @@ where
         if let Some(i) = data.grid[0].iter().position(|&x_val| x_val == point[0]) {
             return Ok(data.values[i]);
         }
+        check_brackets(&data.grid)?;
         let x_u = find_nearest_index(data.grid[0].view(), &point[0]) + 1;
         Ok(data.values[x_u])
     }
 
+    /// Returns zero, as the interpolant is piecewise constant.
+    fn derivative(
+        &self,
+        _data: &InterpData1D<D>,
+        _point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        Ok(D::Elem::zero())
+    }
+
+    fn integral(
+        &self,
+        data: &InterpData1D<D>,
+        lower: D::Elem,
+        upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        let f_x = &data.values;
+        Ok(piecewise_integral(
+            data,
+            lower,
+            upper,
+            zero_slopes(),
+            |i, p, q| f_x[i + 1] * (q - p),
+        ))
+    }
+
     /// Returns `false`.
     fn allow_extrapolate(&self) -> bool {
         false
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+impl<D, S> Strategy1D<D> for LinearTail<S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+    S: Strategy1D<D> + Clone,
+{
+    fn init(&mut self, data: &InterpData1D<D>) -> Result<(), ValidateError> {
+        self.0.init(data)
+    }
+
+    fn interpolate(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let x = &data.grid[0];
+        if (x.first().unwrap()..=x.last().unwrap()).contains(&&point[0]) {
+            return self.0.interpolate(data, point);
+        }
+        let (x_l, x_diff) = find_bracket(data, &point[0])?;
+        Ok(data.values[x_l] * (D::Elem::one() - x_diff) + data.values[x_l + 1] * x_diff)
+    }
+
+    /// Delegates to the wrapped strategy if all points lie within the grid.
+    fn interpolate_batch(
+        &self,
+        data: &InterpData1D<D>,
+        points: &ArrayView1<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        let x = &data.grid[0];
+        let bounds = x.first().unwrap()..=x.last().unwrap();
+        if points.iter().all(|p| bounds.contains(&p)) {
+            return self.0.interpolate_batch(data, points);
+        }
+        points
+            .iter()
+            .map(|&p| Strategy1D::interpolate(self, data, &[p]))
+            .collect()
+    }
+
+    /// Slope of the edge segment beyond the grid, otherwise that of the wrapped strategy.
+    fn derivative(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let x = &data.grid[0];
+        if (x.first().unwrap()..=x.last().unwrap()).contains(&&point[0]) {
+            return self.0.derivative(data, point);
+        }
+        let (x_l, _) = find_bracket(data, &point[0])?;
+        Ok((data.values[x_l + 1] - data.values[x_l]) / (x[x_l + 1] - x[x_l]))
+    }
+
+    fn reverse_indices(&mut self, len: usize) {
+        self.0.reverse_indices(len)
+    }
+
+    fn retain_indices(&mut self, kept: &[usize]) {
+        self.0.retain_indices(kept)
+    }
+
+    /// Returns `true`.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.0.is_initialized()
+    }
+
+    /// Returns the continuity of the wrapped strategy, at most `1` (C0),
+    /// as the tails meet the edge values but their slopes need not match.
+    fn continuity(&self) -> u8 {
+        self.0.continuity().min(1)
+    }
+}
+
+impl<D> Strategy1D<D> for Geometric
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Debug,
+{
+    fn init(&mut self, data: &InterpData1D<D>) -> Result<(), ValidateError> {
+        if let Some(i) = data
+            .values
+            .iter()
+            .position(|&v| v.partial_cmp(&D::Elem::zero()) != Some(std::cmp::Ordering::Greater))
+        {
+            return Err(ValidateError::Other(format!(
+                "geometric interpolation requires strictly positive values, got {:?} at index {i}",
+                data.values[i]
+            )));
+        }
+        Ok(())
+    }
+
+    fn interpolate(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        if let Some(i) = data.grid[0].iter().position(|&x_val| x_val == point[0]) {
+            return Ok(data.values[i]);
+        }
+        let (x_l, t) = find_bracket(data, &point[0])?;
+        let x_u = x_l + 1;
+        Ok(data.values[x_l].powf(D::Elem::one() - t) * data.values[x_u].powf(t))
+    }
+
+    fn derivative(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let (x_l, t) = find_bracket(data, &point[0])?;
+        let x_u = x_l + 1;
+        let f = data.values[x_l].powf(D::Elem::one() - t) * data.values[x_u].powf(t);
+        Ok(
+            f * (data.values[x_u] / data.values[x_l]).ln()
+                / (data.grid[0][x_u] - data.grid[0][x_l]),
+        )
+    }
+
+    /// Returns `true`, extending the geometric blend of the edge segment.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+
+    /// Returns `1` (C0).
+    fn continuity(&self) -> u8 {
+        1
+    }
+}
+
+impl<D, B> Strategy1D<D> for BaseStrategy1D<B>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+    B: Blend1D<D::Elem>,
+{
+    fn interpolate(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        if let Some(i) = data.grid[0].iter().position(|&x_val| x_val == point[0]) {
+            return Ok(data.values[i]);
+        }
+        let (x_l, t) = find_bracket(data, &point[0])?;
+        Ok(self.0.blend(data.values[x_l], data.values[x_l + 1], t))
+    }
+
+    fn interpolate_bracketed(
+        &self,
+        data: &InterpData1D<D>,
+        _point: &[D::Elem; 1],
+        (x_l, t): (usize, D::Elem),
+    ) -> Result<D::Elem, InterpolateError> {
+        // Points on the grid give exact values, as in `interpolate`
+        Ok(if t.is_zero() {
+            data.values[x_l]
+        } else if t.is_one() {
+            data.values[x_l + 1]
+        } else {
+            self.0.blend(data.values[x_l], data.values[x_l + 1], t)
+        })
+    }
+
+    /// Returns [`Blend1D::EXTRAPOLATE`].
+    fn allow_extrapolate(&self) -> bool {
+        B::EXTRAPOLATE
+    }
+}
+
+impl<D> Strategy1D<D> for StaircaseLinear
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    fn init(&mut self, data: &InterpData1D<D>) -> Result<(), ValidateError> {
+        self.breakpoints.sort_unstable();
+        self.breakpoints.dedup();
+        let n = data.grid[0].len();
+        if let Some(i) = self.breakpoints.iter().find(|&&i| i == 0 || i >= n) {
+            return Err(ValidateError::Other(format!(
+                "breakpoint index {i} is out of range 1..{n}"
+            )));
+        }
+        Ok(())
+    }
+
+    fn interpolate(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        if let Some(i) = data.grid[0].iter().position(|&x_val| x_val == point[0]) {
+            return Ok(data.values[i]);
+        }
+        let (x_l, x_diff) = find_bracket(data, &point[0])?;
+        let x_u = x_l + 1;
+        if self.breakpoints.binary_search(&x_u).is_ok() {
+            // Hold the left value across the jump, or the right value beyond the grid
+            return Ok(if x_diff > D::Elem::one() {
+                data.values[x_u]
+            } else {
+                data.values[x_l]
+            });
+        }
+        Ok(data.values[x_l] * (D::Elem::one() - x_diff) + data.values[x_u] * x_diff)
+    }
+
+    /// The jump between supplied indices `i - 1` and `i` lies between reversed indices
+    /// `len - i` and `len - i - 1`. Out-of-range breakpoints are kept, to fail initialization.
+    fn reverse_indices(&mut self, len: usize) {
+        for i in self
+            .breakpoints
+            .iter_mut()
+            .filter(|i| (1..len).contains(*i))
+        {
+            *i = len - *i;
+        }
+    }
+
+    /// The jump between supplied indices `i - 1` and `i` lies before the first kept index from `i`.
+    /// Breakpoints left before the first or after the last kept point no longer separate any points,
+    /// so are dropped.
+    fn retain_indices(&mut self, kept: &[usize]) {
+        self.breakpoints = self
+            .breakpoints
+            .iter()
+            .map(|&i| kept.partition_point(|&k| k < i))
+            .filter(|i| (1..kept.len()).contains(i))
+            .collect();
+    }
+
+    /// Returns `true`.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+}
+
+impl<D> Strategy1D<D> for PolyFit<D::Elem>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    fn init(&mut self, data: &InterpData1D<D>) -> Result<(), ValidateError> {
+        let x = &data.grid[0];
+        let f_x = &data.values;
+        let m = self.degree + 1;
+        if x.len() < m {
+            return Err(ValidateError::Other(format!(
+                "at least {m} data points are required for degree {} polynomial fit",
+                self.degree
+            )));
+        }
+        if let Some(weights) = &self.weights {
+            if weights.len() != x.len() {
+                return Err(ValidateError::Other(format!(
+                    "number of weights {} does not match number of data points {}",
+                    weights.len(),
+                    x.len()
+                )));
+            }
+        }
+        // Normalize coordinates onto [-1, 1] for better conditioning
+        let two = D::Elem::one() + D::Elem::one();
+        let (first, last) = (*x.first().unwrap(), *x.last().unwrap());
+        let shift = (first + last) / two;
+        let scale = if last > first {
+            (last - first) / two
+        } else {
+            D::Elem::one()
+        };
+        // Assemble normal equations: (Vᵀ W V) c = Vᵀ W f
+        let mut a = Array2::zeros((m, m));
+        let mut b = Array1::zeros(m);
+        for (i, (&x_i, &f_i)) in x.iter().zip(f_x.iter()).enumerate() {
+            let w = self
+                .weights
+                .as_ref()
+                .map_or(D::Elem::one(), |weights| weights[i]);
+            let t = (x_i - shift) / scale;
+            let mut powers = Vec::with_capacity(2 * m - 1);
+            let mut t_k = D::Elem::one();
+            for _ in 0..2 * m - 1 {
+                powers.push(t_k);
+                t_k = t_k * t;
+            }
+            for r in 0..m {
+                b[r] = b[r] + w * powers[r] * f_i;
+                for c in 0..m {
+                    a[[r, c]] = a[[r, c]] + w * powers[r + c];
+                }
+            }
+        }
+        self.coeffs = solve_linear_system(a, b).ok_or_else(|| {
+            ValidateError::Other("polynomial fit normal equations are singular".into())
+        })?;
+        self.normalization = Some((shift, scale));
+        Ok(())
+    }
+
+    fn interpolate(
+        &self,
+        _data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let (shift, scale) = self.normalization.ok_or(InterpolateError::Uninitialized)?;
+        let t = (point[0] - shift) / scale;
+        // Horner's method
+        Ok(self
+            .coeffs
+            .iter()
+            .rev()
+            .fold(D::Elem::zero(), |acc, &c| acc * t + c))
+    }
+
+    fn derivative(
+        &self,
+        _data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let (shift, scale) = self.normalization.ok_or(InterpolateError::Uninitialized)?;
+        let t = (point[0] - shift) / scale;
+        // Horner's method, carrying the derivative along
+        let (_, df_dt) = self
+            .coeffs
+            .iter()
+            .rev()
+            .fold((D::Elem::zero(), D::Elem::zero()), |(f, df), &c| {
+                (f * t + c, df * t + f)
+            });
+        Ok(df_dt / scale)
+    }
+
+    /// Returns `true`.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+
+    /// Returns `2` (C∞).
+    fn continuity(&self) -> u8 {
+        2
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.normalization.is_some()
+    }
+}
+
+impl<D> Strategy1D<D> for Newton<D::Elem>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    fn init(&mut self, data: &InterpData1D<D>) -> Result<(), ValidateError> {
+        *self = Self::new();
+        for (&x, &f_x) in data.grid[0].iter().zip(data.values.iter()) {
+            self.extend(x, f_x)?;
+        }
+        Ok(())
+    }
+
+    fn interpolate(
+        &self,
+        _data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let Some((&last, coeffs)) = self.coeffs.split_last() else {
+            return Err(InterpolateError::Uninitialized);
+        };
+        // Nested evaluation of the Newton form
+        Ok(coeffs
+            .iter()
+            .zip(&self.nodes)
+            .rev()
+            .fold(last, |acc, (&c, &x)| acc * (point[0] - x) + c))
+    }
+
+    fn derivative(
+        &self,
+        _data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        let Some((&last, coeffs)) = self.coeffs.split_last() else {
+            return Err(InterpolateError::Uninitialized);
+        };
+        // Nested evaluation of the Newton form, carrying the derivative along
+        let (_, df) = coeffs
+            .iter()
+            .zip(&self.nodes)
+            .rev()
+            .fold((last, D::Elem::zero()), |(f, df), (&c, &x)| {
+                (f * (point[0] - x) + c, df * (point[0] - x) + f)
+            });
+        Ok(df)
+    }
+
+    /// Returns `true`.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+
+    /// Returns `2` (C∞).
+    fn continuity(&self) -> u8 {
+        2
+    }
+
+    fn is_initialized(&self) -> bool {
+        !self.coeffs.is_empty()
+    }
+}
+
+impl<D> Strategy1D<D> for Pchip<D::Elem>
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Debug,
+{
+    fn init(&mut self, data: &InterpData1D<D>) -> Result<(), ValidateError> {
+        let x = &data.grid[0];
+        let f_x = &data.values;
+        let n = x.len();
+        if n < 2 {
+            return Err(ValidateError::Other(
+                "at least 2 data points are required for PCHIP interpolation".into(),
+            ));
+        }
+        let h: Vec<D::Elem> = (0..n - 1).map(|i| x[i + 1] - x[i]).collect();
+        let delta: Vec<D::Elem> = (0..n - 1).map(|i| (f_x[i + 1] - f_x[i]) / h[i]).collect();
+        let (two, three) = (
+            D::Elem::one() + D::Elem::one(),
+            D::Elem::one() + D::Elem::one() + D::Elem::one(),
+        );
+        let mut slopes = vec![D::Elem::zero(); n];
+        if n == 2 {
+            slopes.fill(delta[0]);
+            self.slopes = slopes;
+            return Ok(());
+        }
+        for k in 1..n - 1 {
+            // Zero derivative at local extrema and plateaus, otherwise weighted harmonic mean
+            if delta[k - 1] * delta[k] > D::Elem::zero() {
+                let w_1 = two * h[k] + h[k - 1];
+                let w_2 = h[k] + two * h[k - 1];
+                slopes[k] = (w_1 + w_2) / (w_1 / delta[k - 1] + w_2 / delta[k]);
+            }
+        }
+        // Shape-preserving one-sided three-point estimates at the ends
+        let end_slope = |h_0: D::Elem, h_1: D::Elem, delta_0: D::Elem, delta_1: D::Elem| {
+            let d = ((two * h_0 + h_1) * delta_0 - h_0 * delta_1) / (h_0 + h_1);
+            if d.signum() != delta_0.signum() || delta_0.is_zero() {
+                D::Elem::zero()
+            } else if delta_0.signum() != delta_1.signum() && d.abs() > three * delta_0.abs() {
+                three * delta_0
+            } else {
+                d
+            }
+        };
+        slopes[0] = end_slope(h[0], h[1], delta[0], delta[1]);
+        slopes[n - 1] = end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+        self.slopes = slopes;
+        Ok(())
+    }
+
+    fn interpolate(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        if self.slopes.len() != data.values.len() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let x = &data.grid[0];
+        let f_x = &data.values;
+        let last = x.len() - 1;
+        // Linear tails beyond the grid
+        if point[0] < x[0] {
+            return Ok(f_x[0] + self.slopes[0] * (point[0] - x[0]));
+        }
+        if point[0] > x[last] {
+            return Ok(f_x[last] + self.slopes[last] * (point[0] - x[last]));
+        }
+        if let Some(i) = x.iter().position(|&x_val| x_val == point[0]) {
+            return Ok(f_x[i]);
+        }
+        let (x_l, t) = find_bracket(data, &point[0])?;
+        let x_u = x_l + 1;
+        let h = x[x_u] - x[x_l];
+        let one = D::Elem::one();
+        let (two, three) = (one + one, one + one + one);
+        // Cubic Hermite basis, with `h_00 = 1 - h_01` factored out
+        // so that flat segments are exactly constant
+        let s = one - t;
+        let h_10 = t * s * s;
+        let h_01 = t * t * (three - two * t);
+        let h_11 = -t * t * s;
+        Ok(f_x[x_l]
+            + h_01 * (f_x[x_u] - f_x[x_l])
+            + h * (h_10 * self.slopes[x_l] + h_11 * self.slopes[x_u]))
+    }
+
+    fn derivative(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        if self.slopes.len() != data.values.len() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let x = &data.grid[0];
+        let f_x = &data.values;
+        let last = x.len() - 1;
+        if point[0] < x[0] {
+            return Ok(self.slopes[0]);
+        }
+        if point[0] > x[last] {
+            return Ok(self.slopes[last]);
+        }
+        if let Some(i) = x.iter().position(|&x_val| x_val == point[0]) {
+            return Ok(self.slopes[i]);
+        }
+        let (x_l, t) = find_bracket(data, &point[0])?;
+        let x_u = x_l + 1;
+        let h = x[x_u] - x[x_l];
+        let one = D::Elem::one();
+        let (two, three) = (one + one, one + one + one);
+        // Derivatives of the cubic Hermite basis with respect to `t`
+        let six_t_s = two * three * t * (one - t);
+        let dh_10 = (one - t) * (one - three * t);
+        let dh_11 = t * (three * t - two);
+        Ok(six_t_s * (f_x[x_u] - f_x[x_l]) / h
+            + dh_10 * self.slopes[x_l]
+            + dh_11 * self.slopes[x_u])
+    }
+
+    /// Integrates each cubic segment analytically, and the linear tails beyond the grid.
+    fn integral(
+        &self,
+        data: &InterpData1D<D>,
+        lower: D::Elem,
+        upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        if self.slopes.len() != data.values.len() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let x = &data.grid[0];
+        let f_x = &data.values;
+        let last = x.len() - 1;
+        let one = D::Elem::one();
+        let (two, three, four) = (one + one, one + one + one, one + one + one + one);
+        Ok(piecewise_integral(
+            data,
+            lower,
+            upper,
+            (self.slopes[0], self.slopes[last]),
+            |i, p, q| {
+                let h = x[i + 1] - x[i];
+                // Antiderivative with respect to `t` of the cubic Hermite form used in `interpolate`
+                let antiderivative = |t: D::Elem| {
+                    let (t2, t3, t4) = (t * t, t * t * t, t * t * t * t);
+                    let h_01 = t3 - t4 / two;
+                    let h_10 = t2 / two - two * t3 / three + t4 / four;
+                    let h_11 = t4 / four - t3 / three;
+                    f_x[i] * t
+                        + h_01 * (f_x[i + 1] - f_x[i])
+                        + h * (h_10 * self.slopes[i] + h_11 * self.slopes[i + 1])
+                };
+                h * (antiderivative((q - x[i]) / h) - antiderivative((p - x[i]) / h))
+            },
+        ))
+    }
+
+    /// Returns `true`, extending linearly from the edges.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+
+    /// Returns `2` (C1).
+    fn continuity(&self) -> u8 {
+        2
+    }
+
+    fn is_initialized(&self) -> bool {
+        !self.slopes.is_empty()
+    }
+}
+
+/// Area under the straight line from `(p, f_p)` to `(q, f_q)`.
+fn trapezoid<T: Num + Copy>(p: T, q: T, f_p: T, f_q: T) -> T {
+    (q - p) * (f_p + f_q) / (T::one() + T::one())
+}
+
+/// Tail slopes for piecewise-constant interpolants.
+fn zero_slopes<T: Zero>() -> (T, T) {
+    (T::zero(), T::zero())
+}
+
+/// Integral from `lower` to `upper` of a piecewise interpolant, where `lower <= upper`,
+/// summing `segment(i, p, q)` (the integral over `[p, q]` within the segment starting at grid point `i`)
+/// over the covered segments.
+///
+/// Beyond the grid, the interpolant extends linearly from the edge values with `tail_slopes`.
+fn piecewise_integral<D>(
+    data: &InterpData1D<D>,
+    lower: D::Elem,
+    upper: D::Elem,
+    tail_slopes: (D::Elem, D::Elem),
+    segment: impl Fn(usize, D::Elem, D::Elem) -> D::Elem,
+) -> D::Elem
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    let x = &data.grid[0];
+    let f_x = &data.values;
+    let last = x.len() - 1;
+    let tail = |edge: usize, slope: D::Elem, p: D::Elem, q: D::Elem| {
+        let f = |x_val| f_x[edge] + slope * (x_val - x[edge]);
+        trapezoid(p, q, f(p), f(q))
+    };
+    let mut sum = D::Elem::zero();
+    if lower < x[0] {
+        sum = sum + tail(0, tail_slopes.0, lower, clamp(x[0], lower, upper));
+    }
+    for i in 0..last {
+        if x[i + 1] <= lower {
+            continue;
+        }
+        if x[i] >= upper {
+            break;
+        }
+        sum = sum
+            + segment(
+                i,
+                clamp(lower, x[i], x[i + 1]),
+                clamp(upper, x[i], x[i + 1]),
+            );
+    }
+    if upper > x[last] {
+        sum = sum + tail(last, tail_slopes.1, clamp(x[last], lower, upper), upper);
+    }
+    sum
+}
+
+/// Solve the square linear system `a x = b` using Gaussian elimination with partial pivoting.
+///
+/// Returns `None` if the system is singular.
+pub(crate) fn solve_linear_system<T>(mut a: Array2<T>, mut b: Array1<T>) -> Option<Array1<T>>
+where
+    T: Num + PartialOrd + Copy,
+{
+    let n = b.len();
+    let abs = |v: T| if v < T::zero() { T::zero() - v } else { v };
+    for col in 0..n {
+        // Select pivot row with the largest magnitude in this column
+        let pivot = (col..n).fold(col, |best, row| {
+            if abs(a[[row, col]]) > abs(a[[best, col]]) {
+                row
+            } else {
+                best
+            }
+        });
+        if a[[pivot, col]] == T::zero() {
+            return None;
+        }
+        if pivot != col {
+            for c in 0..n {
+                a.swap([col, c], [pivot, c]);
+            }
+            b.swap(col, pivot);
+        }
+        for row in col + 1..n {
+            let factor = a[[row, col]] / a[[col, col]];
+            for c in col..n {
+                a[[row, c]] = a[[row, c]] - factor * a[[col, c]];
+            }
+            b[row] = b[row] - factor * b[col];
+        }
+    }
+    // Back substitution
+    let mut x = Array1::zeros(n);
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(b[row], |acc, c| acc - a[[row, c]] * x[c]);
+        x[row] = sum / a[[row, row]];
+    }
+    Some(x)
 }
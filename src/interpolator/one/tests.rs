@@ -16,6 +16,22 @@ fn test_invalid_args() {
     assert_eq!(interp.interpolate(&[1.0]).unwrap(), 0.4);
 }
 
+#[test]
+fn test_index_brackets() {
+    let data = InterpData1D::new(
+        array![0., 1., 2.5, 3., 4., 7.],
+        array![0.2, 0.4, 0.6, 0.8, 1.0, 1.2],
+    )
+    .unwrap();
+    let grid = data.grid[0].view();
+    let sorted = array![-1., 0., 0.5, 1., 1., 2.6, 3., 3.9, 6.5, 7., 8.];
+    let unsorted = array![3.9, -1., 7., 0.5, 2.6, 1., 8., 0., 3., 6.5, 1.];
+    for points in [sorted, unsorted] {
+        let expected = points.map(|p| find_nearest_index(grid, p));
+        assert_eq!(data.index_brackets(&points.view()), expected);
+    }
+}
+
 #[test]
 fn test_linear() {
     let interp = Interp1D::new(
@@ -97,6 +113,104 @@ fn test_nearest() {
     assert_eq!(interp.interpolate(&[4.00]).unwrap(), 1.0);
 }
 
+#[test]
+fn test_continuity_class() {
+    let x = array![0., 1., 2.];
+    let f_x = array![0.2, 0.4, 0.6];
+    let class = |strategy: strategy::enums::Strategy1DEnum| {
+        Interp1D::new(x.view(), f_x.view(), strategy, Extrapolate::Error)
+            .unwrap()
+            .continuity_class()
+    };
+    assert_eq!(class(strategy::Linear.into()), 1);
+    assert_eq!(class(strategy::Nearest.into()), 0);
+    assert_eq!(class(strategy::LeftNearest.into()), 0);
+    assert_eq!(class(strategy::RightNearest.into()), 0);
+    // Through dynamic dispatch
+    let interp = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        Box::new(strategy::Linear) as Box<dyn Strategy1D<_>>,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp.continuity_class(), 1);
+}
+
+#[test]
+fn test_polyfit() {
+    // f(x) = 0.5 * x - 2, with alternating noise
+    let x = Array1::linspace(0., 10., 21);
+    let noise = Array1::from_shape_fn(21, |i| if i % 2 == 0 { 0.05 } else { -0.05 });
+    let f_x = x.map(|x| 0.5 * x - 2.) + &noise;
+    let interp = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        strategy::PolyFit::new(1),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let intercept = interp.interpolate(&[0.]).unwrap();
+    let slope = interp.interpolate(&[1.]).unwrap() - intercept;
+    assert_approx_eq!(intercept, -2., 0.01);
+    assert_approx_eq!(slope, 0.5, 0.01);
+    // Does not pass through the data exactly
+    assert!(interp.interpolate(&[x[1]]).unwrap() != f_x[1]);
+
+    // Exact for data of lower or equal degree
+    let interp = Interp1D::new(
+        array![-1., 0., 1., 2., 3.],
+        array![1., 0., 1., 4., 9.],
+        strategy::PolyFit::new(2),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert_approx_eq!(interp.interpolate(&[2.5]).unwrap(), 6.25);
+    assert_approx_eq!(interp.interpolate(&[-3.]).unwrap(), 9.);
+
+    // Weights pull the fit toward heavily weighted points
+    let interp = Interp1D::new(
+        array![0., 1., 2.],
+        array![0., 0., 3.],
+        strategy::PolyFit::weighted(0, array![1., 1., 1000.]),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(interp.interpolate(&[0.]).unwrap() > 2.9);
+
+    // Too few points for requested degree
+    assert!(matches!(
+        Interp1D::new(
+            array![0., 1.],
+            array![0., 1.],
+            strategy::PolyFit::new(2),
+            Extrapolate::Error,
+        )
+        .unwrap_err(),
+        ValidateError::Other(_)
+    ));
+}
+
+#[test]
+fn test_uninitialized() {
+    // Bypassing `Interp1D::new` skips strategy initialization
+    let mut interp = Interp1D {
+        data: InterpData1D::new(array![0., 1., 2.], array![0., 1., 2.]).unwrap(),
+        strategy: strategy::PolyFit::new(1),
+        extrapolate: Extrapolate::Error,
+    };
+    assert!(matches!(
+        interp.interpolate(&[0.5]).unwrap_err(),
+        InterpolateError::Uninitialized
+    ));
+    assert!(matches!(
+        interp.interpolate_clamped(&[0.5]).unwrap_err(),
+        InterpolateError::Uninitialized
+    ));
+    interp.validate().unwrap();
+    assert_approx_eq!(interp.interpolate(&[0.5]).unwrap(), 0.5);
+}
+
 #[test]
 fn test_extrapolate_inputs() {
     // Incorrect extrapolation selection
@@ -146,6 +260,80 @@ fn test_extrapolate_fill() {
     assert!(interp.interpolate(&[5.]).unwrap().is_nan());
 }
 
+#[test]
+fn test_extrapolate_saturating_fill() {
+    let interp = Interp1D::new(
+        array![0., 1., 2., 3., 4.],
+        array![0.2, 0.4, 0.6, 0.8, 1.0],
+        strategy::Linear,
+        Extrapolate::SaturatingFill {
+            value: 0.,
+            rate: 0.5,
+        },
+    )
+    .unwrap();
+    // In-bounds points are unaffected
+    assert_eq!(interp.interpolate(&[3.75]).unwrap(), 0.95);
+    // Just past the boundary continues smoothly from the edge value
+    assert_approx_eq!(interp.interpolate(&[4.01]).unwrap(), 0.995);
+    assert_approx_eq!(interp.interpolate(&[-0.01]).unwrap(), 0.195);
+    assert_approx_eq!(interp.interpolate(&[5.]).unwrap(), 0.5);
+    // Far out it equals `value`
+    assert_eq!(interp.interpolate(&[100.]).unwrap(), 0.);
+    assert_eq!(interp.interpolate(&[-100.]).unwrap(), 0.);
+
+    // Saturating toward a ceiling
+    let interp = Interp1D::new(
+        array![0., 1., 2., 3., 4.],
+        array![0.2, 0.4, 0.6, 0.8, 1.0],
+        strategy::Nearest,
+        Extrapolate::SaturatingFill {
+            value: 2.,
+            rate: 0.5,
+        },
+    )
+    .unwrap();
+    assert_approx_eq!(interp.interpolate(&[5.]).unwrap(), 1.5);
+    assert_eq!(interp.interpolate(&[10.]).unwrap(), 2.);
+    assert_eq!(interp.interpolate(&[-10.]).unwrap(), 2.);
+
+    // Not applicable for higher dimensionalities
+    assert!(matches!(
+        Interp2D::new(
+            array![0., 1.],
+            array![0., 1.],
+            array![[0., 1.], [2., 3.]],
+            strategy::Linear,
+            Extrapolate::SaturatingFill {
+                value: 0.,
+                rate: 0.5,
+            },
+        )
+        .unwrap_err(),
+        ValidateError::ExtrapolateSelection(_)
+    ));
+
+    // Rate must be finite and non-negative
+    let mut interp = interp;
+    for rate in [-1., f64::NAN, f64::INFINITY] {
+        let extrapolate = Extrapolate::SaturatingFill { value: 2., rate };
+        assert!(matches!(
+            Interp1D::new(
+                array![0., 1.],
+                array![0., 1.],
+                strategy::Linear,
+                extrapolate,
+            )
+            .unwrap_err(),
+            ValidateError::ExtrapolateSelection(_)
+        ));
+        assert!(matches!(
+            interp.set_extrapolate(extrapolate).unwrap_err(),
+            ValidateError::ExtrapolateSelection(_)
+        ));
+    }
+}
+
 #[test]
 fn test_extrapolate_clamp() {
     let interp = Interp1D::new(
@@ -159,6 +347,27 @@ fn test_extrapolate_clamp() {
     assert_eq!(interp.interpolate(&[5.]).unwrap(), 1.0);
 }
 
+#[test]
+fn test_interpolate_clamped() {
+    let interp = Interp1D::new(
+        array![0., 1., 2., 3., 4.],
+        array![0.2, 0.4, 0.6, 0.8, 1.0],
+        strategy::Nearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(interp.interpolate(&[-1.]).is_err());
+    assert_eq!(interp.interpolate_clamped(&[-1.]).unwrap(), 0.2);
+    assert_eq!(interp.interpolate_clamped(&[5.]).unwrap(), 1.0);
+    assert_eq!(interp.interpolate_clamped(&[3.25]).unwrap(), 0.8);
+    assert!(matches!(
+        interp.interpolate_clamped(&[]).unwrap_err(),
+        InterpolateError::PointLength(_)
+    ));
+    // Stored setting is unchanged
+    assert_eq!(interp.extrapolate, Extrapolate::Error);
+}
+
 #[test]
 fn test_extrapolate() {
     let interp = Interp1D::new(
@@ -174,28 +383,1591 @@ fn test_extrapolate() {
 }
 
 #[test]
-fn test_partialeq() {
-    #[derive(PartialEq)]
-    #[allow(unused)]
-    struct MyStruct(InterpData1DOwned<f64>);
+fn test_index() {
+    let interp = Interp1D::new(
+        array![0., 1., 2.],
+        array![0.2, 0.4, 0.6],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp[[0]], 0.2);
+    assert_eq!(interp[[2]], 0.6);
+}
 
-    #[derive(PartialEq)]
-    #[allow(unused)]
-    struct MyStruct2(Interp1DOwned<f64, strategy::Linear>);
+#[test]
+fn test_retain() {
+    let mut interp = Interp1D::new(
+        array![0., 1., 2., 3., 4.],
+        array![0., f64::NAN, 2., f64::NAN, 4.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    interp.retain(|_, f_x| !f_x.is_nan()).unwrap();
+    assert_eq!(interp.data.grid[0], array![0., 2., 4.]);
+    assert_eq!(interp.data.values, array![0., 2., 4.]);
+    assert_eq!(interp.interpolate(&[1.]).unwrap(), 1.);
+    assert_eq!(interp.interpolate(&[3.]).unwrap(), 3.);
+
+    // Too few points remaining for the strategy
+    let mut interp = Interp1D::new(
+        array![0., 1., 2., 3.],
+        array![0., 1., 4., 9.],
+        strategy::PolyFit::new(2),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let original = interp.clone();
+    assert!(interp.retain(|x, _| x < 2.).is_err());
+    assert_eq!(interp, original);
+    // No points remaining
+    assert!(matches!(
+        interp.retain(|_, _| false).unwrap_err(),
+        ValidateError::EmptyGrid(0)
+    ));
+
+    // Breakpoints follow the points they index
+    let mut interp = Interp1D::new(
+        array![0., 1., 2., 3., 4., 5.],
+        array![0., 1., 2., 10., 11., 12.],
+        strategy::StaircaseLinear::new(vec![3]),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[2.5]).unwrap(), 2.);
+    interp.retain(|x, _| x >= 2.).unwrap();
+    assert_eq!(interp.strategy.breakpoints, vec![1]);
+    assert_eq!(interp.interpolate(&[2.5]).unwrap(), 2.);
+    assert_eq!(interp.interpolate(&[3.5]).unwrap(), 10.5);
+    // Dropped once no points remain on one side
+    interp.retain(|x, _| x >= 3.).unwrap();
+    assert!(interp.strategy.breakpoints.is_empty());
+    assert_eq!(interp.interpolate(&[4.5]).unwrap(), 11.5);
 }
 
 #[test]
-#[cfg(feature = "serde")]
-fn test_serde() {
+fn test_monotone_segments() {
+    let w = Interp1D::new(
+        array![0., 1., 2., 3., 4.],
+        array![4., 0., 2., 0., 4.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(w.monotone_segments(), vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+    assert!(!w.is_monotonic_values());
+    let increasing = Interp1D::new(
+        array![0., 1., 2., 3.],
+        array![0., 0., 1., 2.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(increasing.monotone_segments(), vec![(0, 3)]);
+    assert!(increasing.is_monotonic_values());
+}
+
+#[test]
+fn test_pchip_monotone() {
+    // Monotone increasing, with irregular spacing, plateaus, and steep steps
+    let x = array![0., 0.5, 1., 3., 3.2, 4., 7., 7.1, 9.];
+    let f_x = array![0., 0.01, 0.02, 0.5, 0.98, 0.99, 0.99, 1., 5.];
+    let interp = Interp1D::new(
+        x.clone(),
+        f_x.clone(),
+        strategy::Pchip::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    for i in 0..x.len() - 1 {
+        let samples = Array1::linspace(x[i], x[i + 1], 51);
+        let sampled = interp.interpolate_batch(&samples.view()).unwrap();
+        assert_eq!(sampled[0], f_x[i]);
+        assert_eq!(sampled[50], f_x[i + 1]);
+        assert!(sampled.windows(2).into_iter().all(|w| w[0] <= w[1]));
+    }
+    // Linear tails are monotone too
+    assert!(interp.interpolate(&[-1.]).unwrap() <= 0.);
+    assert!(interp.interpolate(&[10.]).unwrap() >= 5.);
+    // Newton overshoots on the same data
+    let newton = Interp1D::new(x, f_x, strategy::Newton::new(), Extrapolate::Error).unwrap();
+    let samples = Array1::linspace(0., 9., 901);
+    let sampled = newton.interpolate_batch(&samples.view()).unwrap();
+    assert!(!sampled.windows(2).into_iter().all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn test_pchip_extrema() {
+    // Local extrema at grid points are preserved, without overshoot
+    let interp = Interp1D::new(
+        array![0., 1., 2., 4.],
+        array![0., 1., 0., 0.],
+        strategy::Pchip::new(),
+        Extrapolate::Clamp,
+    )
+    .unwrap();
+    let samples = Array1::linspace(0., 4., 401);
+    let sampled = interp.interpolate_batch(&samples.view()).unwrap();
+    assert!(sampled.iter().all(|&f| (0. ..=1.).contains(&f)));
+    assert_eq!(interp.interpolate(&[2.5]).unwrap(), 0.);
+    assert_eq!(interp.interpolate(&[-1.]).unwrap(), 0.);
+    assert_eq!(interp.interpolate(&[5.]).unwrap(), 0.);
+    // Two points reduce to linear interpolation
+    let interp = Interp1D::new(
+        array![0., 2.],
+        array![1., 3.],
+        strategy::Pchip::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[0.5]).unwrap(), 1.5);
+    assert_eq!(interp.interpolate(&[3.]).unwrap(), 4.);
+    assert!(Interp1D::new(
+        array![0.],
+        array![1.],
+        strategy::Pchip::new(),
+        Extrapolate::Error
+    )
+    .is_err());
+}
+
+#[test]
+fn test_rmse_against() {
+    let x = array![0., 0.75, 1.5, 2.25, 3.];
+    let f_x = x.mapv(f64::sin);
+    let samples = Array1::linspace(0., 3., 101);
+    let linear =
+        Interp1D::new(x.clone(), f_x.clone(), strategy::Linear, Extrapolate::Error).unwrap();
+    let newton = Interp1D::new(x, f_x, strategy::Newton::new(), Extrapolate::Error).unwrap();
+    let linear_rmse = linear.rmse_against(f64::sin, samples.view()).unwrap();
+    let newton_rmse = newton.rmse_against(f64::sin, samples.view()).unwrap();
+    assert!(newton_rmse < linear_rmse);
+    assert!(linear.rmse_against(f64::sin, array![4.].view()).is_err());
+    assert!(linear.rmse_against(f64::sin, array![].view()).is_err());
+}
+
+#[test]
+fn test_plateau_policy() {
+    let x = array![0., 1., 1., 1., 2.];
+    let f_x = array![0., 1., 2., 6., 4.];
+    for (policy, expected) in [
+        (PlateauPolicy::TakeLeft, 1.),
+        (PlateauPolicy::TakeRight, 6.),
+        (PlateauPolicy::Average, 3.),
+    ] {
+        let data = InterpData1D::with_plateau_policy(x.clone(), f_x.clone(), policy).unwrap();
+        assert_eq!(data.grid[0], array![0., 1., 2.]);
+        assert_eq!(data.values, array![0., expected, 4.]);
+        let interp = Interp1D {
+            data,
+            strategy: strategy::Linear,
+            extrapolate: Extrapolate::Error,
+        };
+        assert_eq!(interp.interpolate(&[1.]).unwrap(), expected);
+        assert_eq!(interp.interpolate(&[1.5]).unwrap(), (expected + 4.) / 2.);
+    }
+    assert!(matches!(
+        InterpData1D::with_plateau_policy(x, f_x, PlateauPolicy::Error).unwrap_err(),
+        ValidateError::Monotonicity(0)
+    ));
+}
+
+#[test]
+#[cfg(feature = "fixed-point")]
+fn test_fixed_linear() {
+    let run = || {
+        let interp = Interp1D::new(
+            array![0i64, 7, 20, 33],
+            array![-1_000i64, 2_500, 2_400, 9_999],
+            strategy::FixedLinear,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        (-5..40)
+            .map(|x| interp.interpolate(&[x]).unwrap())
+            .collect::<Vec<_>>()
+    };
+    let (first, second) = (run(), run());
+    assert_eq!(first, second);
+    // Quotients round toward negative infinity
+    assert_eq!(first[5 + 1], -1_000 + 3_500 / 7);
+    assert_eq!(first[5 + 8], 2_500 + (-100_i64).div_euclid(13));
+    assert_eq!(first[5 + 8], 2_492);
+}
+
+#[test]
+fn test_union_grid() {
+    let a = Interp1D::new(
+        array![0., 0.5, 2., 3.],
+        array![1., 2., 0., 4.],
+        strategy::Linear,
+        Extrapolate::Clamp,
+    )
+    .unwrap();
+    let b = Interp1D::new(
+        array![-1., 0.5, 1., 2.5],
+        array![3., -1., 0., 5.],
+        strategy::Linear,
+        Extrapolate::Clamp,
+    )
+    .unwrap();
+    let union = a.union_grid(&b);
+    assert_eq!(union, array![-1., 0., 0.5, 1., 2., 2.5, 3.]);
+    let [a_resampled, b_resampled] = a.resample_to_union(&b).unwrap();
+    assert_eq!(a_resampled.data.grid[0], union);
+    assert_eq!(b_resampled.data.grid[0], union);
+    for (original, resampled) in [(&a.data, &a_resampled), (&b.data, &b_resampled)] {
+        for (x, f_x) in original.grid[0].iter().zip(&original.values) {
+            assert_eq!(resampled.interpolate(&[*x]).unwrap(), *f_x);
+        }
+    }
+    // Union points beyond the grid are handled per the Extrapolate setting
+    let a = Interp1D::new(
+        array![0., 1.],
+        array![0., 1.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(a.resample_to_union(&b).is_err());
+}
+
+#[test]
+fn test_arithmetic() {
+    let x = array![0., 1., 2., 4.];
+    let a = Interp1D::new(
+        x.clone(),
+        array![1., 2., 4., 8.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let b = Interp1D::new(
+        x.clone(),
+        array![0.5, -1., 2., 4.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let sum = (&a + &b).unwrap();
+    let difference = (&a - &b).unwrap();
+    let product = (&a * &b).unwrap();
+    let quotient = (&a / &b).unwrap();
+    for x_i in x {
+        let (f_a, f_b) = (
+            a.interpolate(&[x_i]).unwrap(),
+            b.interpolate(&[x_i]).unwrap(),
+        );
+        assert_eq!(sum.interpolate(&[x_i]).unwrap(), f_a + f_b);
+        assert_eq!(difference.interpolate(&[x_i]).unwrap(), f_a - f_b);
+        assert_eq!(product.interpolate(&[x_i]).unwrap(), f_a * f_b);
+        assert_eq!(quotient.interpolate(&[x_i]).unwrap(), f_a / f_b);
+    }
+    let shifted = Interp1D::new(
+        array![0., 1., 2., 3.],
+        array![1., 2., 4., 8.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!((&a + &shifted).is_err());
+}
+
+#[test]
+fn test_geometric() {
+    let interp = Interp1D::new(
+        array![0., 1., 3.],
+        array![1., 100., 0.01],
+        strategy::Geometric,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // Midpoint is the geometric mean, not the arithmetic mean
+    assert_approx_eq!(interp.interpolate(&[0.5]).unwrap(), 10.);
+    assert_approx_eq!(interp.interpolate(&[2.]).unwrap(), 1.);
+    assert_eq!(interp.interpolate(&[1.]).unwrap(), 100.);
+    assert!(Interp1D::new(
+        array![0., 1.],
+        array![1., 0.],
+        strategy::Geometric,
+        Extrapolate::Error,
+    )
+    .is_err());
+}
+
+#[test]
+fn test_solve_all() {
+    // W-shaped data
     let interp = Interp1D::new(
         array![0., 1., 2., 3., 4.],
-        array![0.2, 0.4, 0.6, 0.8, 1.0],
-        strategy::LeftNearest,
+        array![4., 0., 3., 0., 4.],
+        strategy::Linear,
         Extrapolate::Error,
     )
     .unwrap();
+    let solutions = interp.solve_all(2.).unwrap();
+    assert_eq!(solutions.len(), 4);
+    for (x, expected) in solutions.iter().zip([0.5, 1. + 2. / 3., 2. + 1. / 3., 3.5]) {
+        assert_approx_eq!(*x, expected);
+        assert_approx_eq!(interp.interpolate(&[*x]).unwrap(), 2.);
+    }
+    // Crossings at grid points are not duplicated
+    assert_eq!(interp.solve_all(3.).unwrap(), vec![0.25, 2., 3.75]);
+    assert_eq!(interp.solve_all(0.).unwrap(), vec![1., 3.]);
+    assert!(interp.solve_all(5.).unwrap().is_empty());
+    assert!(interp.solve_all(f64::NAN).is_err());
 
-    let ser = serde_json::to_string(&interp).unwrap();
-    let de: Interp1DOwned<f64, strategy::LeftNearest> = serde_json::from_str(&ser).unwrap();
-    assert_eq!(interp, de);
+    // Flat segments at the target return their endpoints
+    let interp = Interp1D::new(
+        array![0., 1., 2., 3.],
+        array![0., 1., 1., 2.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp.solve_all(1.).unwrap(), vec![1., 2.]);
+}
+
+#[test]
+fn test_inverse() {
+    let x = array![0., 1., 3., 4.];
+    // Increasing
+    let interp = Interp1D::new(
+        x.clone(),
+        array![1., 3., 4., 8.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    for x in [0., 0.3, 1., 2.5, 3.9, 4.] {
+        let f_x = interp.interpolate(&[x]).unwrap();
+        assert_approx_eq!(interp.inverse(f_x).unwrap(), x);
+    }
+    assert_eq!(interp.inverse(3.).unwrap(), 1.);
+    assert!(matches!(
+        interp.inverse(9.).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    assert!(interp.inverse(f64::NAN).is_err());
+    // Decreasing
+    let mut interp = Interp1D::new(
+        x.clone(),
+        array![8., 4., 3., 1.],
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    for x in [0., 0.5, 2., 3., 3.5, 4.] {
+        let f_x = interp.interpolate(&[x]).unwrap();
+        assert_approx_eq!(interp.inverse(f_x).unwrap(), x);
+    }
+    // Extrapolation
+    assert_eq!(interp.inverse(10.).unwrap(), -0.5);
+    assert_eq!(interp.inverse(0.).unwrap(), 4.5);
+    interp.set_extrapolate(Extrapolate::Clamp).unwrap();
+    assert_eq!(interp.inverse(10.).unwrap(), 0.);
+    assert_eq!(interp.inverse(0.).unwrap(), 4.);
+    interp
+        .set_extrapolate(Extrapolate::EnableBounded(1.))
+        .unwrap();
+    assert_eq!(interp.inverse(0.).unwrap(), 4.5);
+    assert!(interp.inverse(-2.).is_err());
+    interp.set_extrapolate(Extrapolate::Fill(0.)).unwrap();
+    assert!(interp.inverse(0.).is_err());
+    // Not monotonic
+    for f_x in [array![1., 3., 2., 4.], array![1., 2., 2., 3.]] {
+        let interp = Interp1D::new(x.clone(), f_x, strategy::Linear, Extrapolate::Error).unwrap();
+        assert!(matches!(
+            interp.inverse(1.5).unwrap_err(),
+            InterpolateError::Other(_)
+        ));
+    }
+}
+
+#[test]
+fn test_peak() {
+    let interp = Interp1D::new(
+        array![0., 1., 2., 3., 4.],
+        array![1., 3., 2., 3., 0.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // Ties resolve to the lowest x
+    assert_eq!(interp.peak().unwrap(), (1., 3.));
+    // No point in between exceeds the top node
+    for x in Array1::linspace(0., 4., 81) {
+        assert!(interp.interpolate(&[x]).unwrap() <= 3.);
+    }
+    let interp = Interp1D::new(
+        array![0., 1., 2.],
+        array![1., f64::NAN, 2.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(interp.peak().is_err());
+}
+
+#[test]
+fn test_new_unchecked() {
+    let x = array![0., 1., 2., 3., 4.];
+    let f_x = array![0.2, 0.4, 0.6, 0.8, 1.0];
+    for strategy in [
+        Box::new(strategy::Linear) as Box<dyn Strategy1D<_>>,
+        Box::new(strategy::Nearest),
+        Box::new(strategy::LeftNearest),
+        Box::new(strategy::RightNearest),
+    ] {
+        let checked =
+            Interp1D::new(x.view(), f_x.view(), strategy.clone(), Extrapolate::Clamp).unwrap();
+        let unchecked = Interp1D::new_unchecked(x.view(), f_x.view(), strategy, Extrapolate::Clamp);
+        for point in [-1., 0., 0.3, 1.5, 2.5, 4., 5.] {
+            assert_eq!(
+                unchecked.interpolate(&[point]).unwrap(),
+                checked.interpolate(&[point]).unwrap()
+            );
+        }
+    }
+    // Validation is opt-in
+    let mut unchecked = Interp1D::new_unchecked(
+        array![1., 0.],
+        array![0., 1.],
+        strategy::Linear,
+        Extrapolate::Error,
+    );
+    assert!(matches!(
+        unchecked.validate().unwrap_err(),
+        ValidateError::Monotonicity(0)
+    ));
+}
+
+#[test]
+fn test_newton() {
+    // f(x) = x^3 - 2x + 1
+    let f = |x: f64| x.powi(3) - 2. * x + 1.;
+    let x = array![-1., 0., 0.5, 2.];
+    let f_x = x.mapv(f);
+    let newton = Interp1D::new(
+        x.clone(),
+        f_x.clone(),
+        strategy::Newton::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    // Exact polynomial interpolation, equivalent to a least-squares fit of degree n - 1
+    let polyfit = Interp1D::new(x, f_x, strategy::PolyFit::new(3), Extrapolate::Enable).unwrap();
+    for point in [-2., -1., -0.3, 0., 0.25, 1., 2., 3.] {
+        assert_approx_eq!(newton.interpolate(&[point]).unwrap(), f(point));
+        assert_approx_eq!(
+            newton.interpolate(&[point]).unwrap(),
+            polyfit.interpolate(&[point]).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_newton_extend() {
+    let x = array![0., 0.5, 1.2, 2., 3.1];
+    let f_x = array![1., -0.4, 0.3, 2.2, -1.];
+    let mut extended = Interp1D::new(
+        x.slice(s![..2]).to_owned(),
+        f_x.slice(s![..2]).to_owned(),
+        strategy::Newton::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    for i in 2..x.len() {
+        extended.extend(x[i], f_x[i]).unwrap();
+    }
+    let rebuilt = Interp1D::new(
+        x.clone(),
+        f_x.clone(),
+        strategy::Newton::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert_eq!(extended.data, rebuilt.data);
+    for (a, b) in extended
+        .strategy
+        .coeffs
+        .iter()
+        .zip(&rebuilt.strategy.coeffs)
+    {
+        assert_approx_eq!(a, b);
+    }
+    for point in [-1., 0., 0.7, 1.5, 3.1, 4.] {
+        assert_approx_eq!(
+            extended.interpolate(&[point]).unwrap(),
+            rebuilt.interpolate(&[point]).unwrap()
+        );
+    }
+    // Appended points must extend the grid
+    let before = extended.clone();
+    for x in [3., f64::NAN] {
+        assert!(matches!(
+            extended.extend(x, 0.).unwrap_err(),
+            ValidateError::Monotonicity(0)
+        ));
+    }
+    assert_eq!(extended, before);
+    // Repeated or non-finite nodes are rejected by the strategy
+    let mut strategy = strategy::Newton::new();
+    strategy.extend(0., 1.).unwrap();
+    assert!(strategy.extend(0., 2.).is_err());
+    assert!(matches!(
+        strategy.extend(f64::NAN, 2.).unwrap_err(),
+        ValidateError::InvalidValues(_)
+    ));
+    assert_eq!(strategy.nodes, vec![0.]);
+}
+
+#[test]
+fn test_extrapolate_enable_bounded() {
+    let interp = Interp1D::new(
+        array![0., 1., 2.],
+        array![0., 2., 4.],
+        strategy::Linear,
+        Extrapolate::EnableBounded(1.),
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[1.5]).unwrap(), 3.);
+    assert_eq!(interp.interpolate(&[2.5]).unwrap(), 5.);
+    assert_eq!(interp.interpolate(&[-1.]).unwrap(), -2.);
+    assert!(matches!(
+        interp.interpolate(&[10.]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    assert!(matches!(
+        interp.interpolate(&[-1.5]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    assert_eq!(
+        interp.extrapolation_used(&[10.]).unwrap(),
+        ExtrapolationUsed::Rejected(0)
+    );
+    // Not applicable for strategies that do not extrapolate
+    assert!(Interp1D::new(
+        array![0., 1., 2.],
+        array![0., 2., 4.],
+        strategy::Nearest,
+        Extrapolate::EnableBounded(1.),
+    )
+    .is_err());
+    // Limit must be finite and non-negative
+    let mut interp = interp;
+    for limit in [-1., f64::NAN, f64::INFINITY] {
+        assert!(matches!(
+            Interp1D::new(
+                array![0., 1.],
+                array![0., 1.],
+                strategy::Linear,
+                Extrapolate::EnableBounded(limit),
+            )
+            .unwrap_err(),
+            ValidateError::ExtrapolateSelection(_)
+        ));
+        assert!(matches!(
+            interp
+                .set_extrapolate(Extrapolate::EnableBounded(limit))
+                .unwrap_err(),
+            ValidateError::ExtrapolateSelection(_)
+        ));
+    }
+    assert!(interp
+        .set_extrapolate(Extrapolate::EnableBounded(0.))
+        .is_ok());
+    interp.extrapolate = Extrapolate::EnableBounded(-1.);
+    assert!(interp.validate().is_err());
+    // An invalid limit bypassing validation rejects rather than admits points
+    interp.extrapolate = Extrapolate::EnableBounded(f64::NAN);
+    assert!(interp.interpolate(&[2.5]).is_err());
+    assert_eq!(
+        interp.extrapolation_used(&[2.5]).unwrap(),
+        ExtrapolationUsed::Rejected(0)
+    );
+}
+
+#[test]
+fn test_extrapolate_nearest_value() {
+    let x = array![0., 1., 2., 3.];
+    let f_x = array![1., 0.2, 0.5, 3.];
+    for strategy in [
+        Box::new(strategy::Linear) as Box<dyn Strategy1D<_>>,
+        Box::new(strategy::Nearest),
+        Box::new(strategy::LeftNearest),
+        Box::new(strategy::RightNearest),
+        Box::new(strategy::PolyFit::new(1)),
+        Box::new(strategy::Newton::new()),
+    ] {
+        let interp =
+            Interp1D::new(x.view(), f_x.view(), strategy, Extrapolate::NearestValue).unwrap();
+        assert_eq!(interp.interpolate(&[-1.]).unwrap(), 1.);
+        assert_eq!(interp.interpolate(&[4.]).unwrap(), 3.);
+    }
+    // Differs from `Extrapolate::Clamp` for strategies that don't pass through the data
+    let interp = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        strategy::PolyFit::new(1),
+        Extrapolate::Clamp,
+    )
+    .unwrap();
+    assert_ne!(interp.interpolate(&[4.]).unwrap(), 3.);
+}
+
+#[test]
+fn test_sorted_from() {
+    let x = array![0., 1., 2., 3., 4.];
+    let f_x = array![0.2, 0.4, 0.6, 0.8, 1.0];
+    let shuffled_x = array![3., 0., 4., 2., 1.];
+    let shuffled_f_x = array![0.8, 0.2, 1.0, 0.6, 0.4];
+    assert_eq!(
+        InterpData1D::sorted_from(shuffled_x.clone(), shuffled_f_x.clone()).unwrap(),
+        InterpData1D::new(x.clone(), f_x.clone()).unwrap()
+    );
+    // Shuffled input produces the same interpolator as pre-sorted input
+    let interp = Interp1D {
+        data: InterpData1D::sorted_from(shuffled_x, shuffled_f_x).unwrap(),
+        strategy: strategy::Linear,
+        extrapolate: Extrapolate::Error,
+    };
+    assert_eq!(
+        interp,
+        Interp1D::new(x, f_x, strategy::Linear, Extrapolate::Error).unwrap()
+    );
+    // Repeated x-values
+    assert!(matches!(
+        InterpData1D::sorted_from(array![1., 0., 1.], array![0., 1., 2.]).unwrap_err(),
+        ValidateError::Monotonicity(0)
+    ));
+    // Unordered x-values, which would otherwise make the sort panic
+    let x_nan = array![
+        15.,
+        18.,
+        9.,
+        11.,
+        6.,
+        13.,
+        10.,
+        f64::NAN,
+        17.,
+        16.,
+        3.,
+        12.,
+        5.,
+        8.,
+        7.,
+        2.,
+        14.,
+        1.,
+        0.,
+        4.,
+        f64::NAN
+    ];
+    assert!(matches!(
+        InterpData1D::sorted_from(x_nan, Array1::zeros(21)).unwrap_err(),
+        ValidateError::Monotonicity(0)
+    ));
+    // Mismatched lengths
+    assert!(matches!(
+        InterpData1D::sorted_from(array![1., 0.], array![0., 1., 2.]).unwrap_err(),
+        ValidateError::IncompatibleShapes(0)
+    ));
+}
+
+#[test]
+fn test_cow() {
+    let x = array![0., 1., 2., 3., 4.];
+    let f_x = array![0.2, 0.4, 0.6, 0.8, 1.0];
+    let borrowed: Interp1DCow<f64, _> = Interp1D::new(
+        CowArray::from(x.view()),
+        CowArray::from(f_x.view()),
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let owned: Interp1DCow<f64, _> = Interp1D::new(
+        CowArray::from(x.clone()),
+        CowArray::from(f_x.clone()),
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert!(borrowed.data.values.is_view());
+    assert!(owned.data.values.is_owned());
+    for point in [-1., 0., 0.3, 1.5, 2.5, 4., 5.] {
+        assert_eq!(
+            borrowed.interpolate(&[point]).unwrap(),
+            owned.interpolate(&[point]).unwrap()
+        );
+    }
+    // Mutation clones borrowed data, leaving the source untouched
+    let mut mutated = borrowed.clone();
+    mutated.data.values[4] = 2.;
+    assert!(mutated.data.values.is_owned());
+    assert!(borrowed.data.values.is_view());
+    assert_eq!(f_x[4], 1.0);
+    assert_eq!(mutated.interpolate(&[3.5]).unwrap(), 1.4);
+}
+
+#[test]
+fn test_map_grid() {
+    let interp = Interp1D::new(
+        array![0., 0.5, 1.2, 2.],
+        array![1., -0.4, 0.3, 2.2],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let scaled = interp.map_grid(|x| x * 100.).unwrap();
+    assert_eq!(scaled.data.grid[0], array![0., 50., 120., 200.]);
+    assert_eq!(scaled.data.values, interp.data.values);
+    for x in [0., 0.3, 0.5, 1., 1.7, 2.] {
+        assert_approx_eq!(
+            scaled.interpolate(&[x * 100.]).unwrap(),
+            interp.interpolate(&[x]).unwrap()
+        );
+    }
+    let shifted = interp.map_grid(|x| x - 10.).unwrap();
+    assert_eq!(shifted.interpolate(&[-9.5]).unwrap(), -0.4);
+    // Decreasing and order-collapsing transforms are rejected
+    assert!(matches!(
+        interp.map_grid(|x| -x).unwrap_err(),
+        ValidateError::Monotonicity(0)
+    ));
+    assert!(matches!(
+        interp.map_grid(|x: f64| x.min(1.)).unwrap_err(),
+        ValidateError::Monotonicity(0)
+    ));
+}
+
+#[test]
+fn test_partialeq() {
+    #[derive(PartialEq)]
+    #[allow(unused)]
+    struct MyStruct(InterpData1DOwned<f64>);
+
+    #[derive(PartialEq)]
+    #[allow(unused)]
+    struct MyStruct2(Interp1DOwned<f64, strategy::Linear>);
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serde() {
+    let interp = Interp1D::new(
+        array![0., 1., 2., 3., 4.],
+        array![0.2, 0.4, 0.6, 0.8, 1.0],
+        strategy::LeftNearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+
+    let ser = serde_json::to_string(&interp).unwrap();
+    let de: Interp1DOwned<f64, strategy::LeftNearest> = serde_json::from_str(&ser).unwrap();
+    assert_eq!(interp, de);
+}
+
+#[test]
+fn test_interpolate_batch() {
+    let x = array![0., 1., 2.5, 3., 5.];
+    let f_x = array![1., -2., 4., 0.5, 3.];
+    let sorted = array![0., 0.3, 1., 1., 2.2, 2.5, 2.9, 3.7, 5.];
+    let unsorted = array![4.1, 0., 2.5, 1.7, 5., 0.6];
+    let beyond = array![-1., 0.5, 6.];
+    let strategies = [
+        // Overridden
+        Box::new(strategy::Linear) as Box<dyn Strategy1D<_>>,
+        Box::new(strategy::Nearest),
+        // Default
+        Box::new(strategy::LeftNearest),
+        Box::new(strategy::RightNearest),
+    ];
+    for strategy in strategies {
+        let interp = Interp1D::new(x.view(), f_x.view(), strategy, Extrapolate::Clamp).unwrap();
+        for points in [&sorted, &unsorted, &beyond] {
+            let expected = points.map(|&p| interp.interpolate(&[p]).unwrap());
+            assert_eq!(interp.interpolate_batch(&points.view()).unwrap(), expected);
+        }
+    }
+    // Strategy-level batches extrapolate from the edge segments
+    let interp = Interp1D::new(x, f_x, strategy::Linear, Extrapolate::Enable).unwrap();
+    let expected = beyond.map(|&p| interp.interpolate(&[p]).unwrap());
+    assert_eq!(
+        Strategy1D::interpolate_batch(&interp.strategy, &interp.data, &beyond.view()).unwrap(),
+        expected
+    );
+    assert!(interp
+        .interpolate_batch(&array![].view())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_adaptive_from_fn() {
+    // Sharp bump
+    let f = |x: f64| (-(x / 0.05).powi(2)).exp();
+    let tol = 1e-4;
+    let interp = Interp1DOwned::adaptive_from_fn(f, (-1., 1.), tol, strategy::Linear).unwrap();
+    let n = interp.data.grid[0].len();
+    let samples = Array1::linspace(-1., 1., 10_001);
+    let max_error = |interp: &Interp1DOwned<f64, strategy::Linear>| {
+        samples
+            .iter()
+            .map(|&x| (interp.interpolate(&[x]).unwrap() - f(x)).abs())
+            .fold(0., f64::max)
+    };
+    assert!(max_error(&interp) < 2. * tol);
+    // A uniform grid needs spacing ~sqrt(8 * tol / max|f''|), i.e. ~2000 points
+    assert!(n < 300, "{n} points");
+    let uniform_x = Array1::linspace(-1., 1., n);
+    let uniform = Interp1D::new(
+        uniform_x.clone(),
+        uniform_x.map(|&x| f(x)),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(max_error(&uniform) > 10. * tol);
+
+    // Smooth functions with a non-linear strategy
+    let interp =
+        Interp1DOwned::adaptive_from_fn(f64::sin, (0., 6.), 1e-6, strategy::Newton::new()).unwrap();
+    assert_approx_eq!(interp.interpolate(&[2.5]).unwrap(), 2.5f64.sin(), 1e-5);
+
+    assert!(Interp1DOwned::adaptive_from_fn(f, (1., -1.), tol, strategy::Linear).is_err());
+}
+
+#[test]
+fn test_single_point_grid() {
+    let x = array![1.];
+    let f_x = array![2.];
+    assert_eq!(find_nearest_index(x.view(), &1.), 0);
+    for strategy in [
+        Box::new(strategy::Linear) as Box<dyn Strategy1D<_>>,
+        Box::new(strategy::Nearest),
+        Box::new(strategy::LeftNearest),
+        Box::new(strategy::RightNearest),
+    ] {
+        let interp = Interp1D::new(x.view(), f_x.view(), strategy, Extrapolate::Clamp).unwrap();
+        // Grid point itself and clamped points
+        assert_eq!(interp.interpolate(&[1.]).unwrap(), 2.);
+        assert_eq!(interp.interpolate(&[0.5]).unwrap(), 2.);
+        assert_eq!(
+            interp
+                .interpolate_batch(&array![1., 3., 1.].view())
+                .unwrap(),
+            array![2., 2., 2.]
+        );
+        // Points needing a bracketing pair error rather than panic
+        assert!(matches!(
+            interp.strategy.interpolate(&interp.data, &[1.5]),
+            Err(InterpolateError::Other(_))
+        ));
+        assert!(
+            Strategy1D::interpolate_batch(&interp.strategy, &interp.data, &array![1.5].view())
+                .is_err()
+        );
+    }
+    let interp = Interp1D::new(x.view(), f_x.view(), strategy::Linear, Extrapolate::Error).unwrap();
+    assert!(matches!(
+        interp.interpolate(&[1.5]),
+        Err(InterpolateError::ExtrapolateError(_))
+    ));
+    for extrapolate in [Extrapolate::Enable, Extrapolate::EnableBounded(1.)] {
+        assert!(matches!(
+            Interp1D::new(x.view(), f_x.view(), strategy::Linear, extrapolate),
+            Err(ValidateError::Other(_))
+        ));
+    }
+    // Wrapping around a single point returns it, rather than dividing by a zero period
+    let interp = Interp1D::new(x.view(), f_x.view(), strategy::Linear, Extrapolate::Wrap).unwrap();
+    assert_eq!(interp.interpolate(&[-3.5]).unwrap(), 2.);
+    assert_eq!(interp.integrate(0., 3.).unwrap(), 6.);
+    let interp = Interp1D::new(
+        array![0i64],
+        array![5i64],
+        strategy::Nearest,
+        Extrapolate::Wrap,
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[0]).unwrap(), 5);
+    assert_eq!(interp.interpolate(&[3]).unwrap(), 5);
+    assert_eq!(interp.interpolate(&[-7]).unwrap(), 5);
+}
+
+#[test]
+fn test_interpolate_rounded() {
+    let interp = Interp1D::new(
+        array![0., 1., 2.],
+        array![1., 4., 0.],
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    // Fractional result of 2.5
+    assert_eq!(interp.interpolate(&[0.5]).unwrap(), 2.5);
+    assert_eq!(
+        interp
+            .interpolate_rounded(&[0.5], Rounding::Nearest)
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        interp.interpolate_rounded(&[0.5], Rounding::Floor).unwrap(),
+        2
+    );
+    assert_eq!(
+        interp.interpolate_rounded(&[0.5], Rounding::Ceil).unwrap(),
+        3
+    );
+    // Negative results
+    assert_eq!(interp.interpolate(&[2.5]).unwrap(), -2.);
+    assert_eq!(
+        interp
+            .interpolate_rounded(&[2.125], Rounding::Nearest)
+            .unwrap(),
+        -1
+    );
+    assert_eq!(
+        interp
+            .interpolate_rounded(&[2.125], Rounding::Floor)
+            .unwrap(),
+        -1
+    );
+    assert_eq!(
+        interp
+            .interpolate_rounded(&[2.125], Rounding::Ceil)
+            .unwrap(),
+        0
+    );
+    // Unrepresentable
+    assert!(interp
+        .interpolate_rounded(&[1e20], Rounding::Nearest)
+        .is_err());
+}
+
+#[test]
+fn test_base_strategy() {
+    // Linear blend, reproducing `strategy::Linear`
+    #[derive(Debug, Clone)]
+    struct Lerp;
+    impl Blend1D<f64> for Lerp {
+        const EXTRAPOLATE: bool = true;
+        fn blend(&self, f_l: f64, f_u: f64, t: f64) -> f64 {
+            f_l * (1. - t) + f_u * t
+        }
+    }
+    let x = array![0., 1., 2.5, 3., 5.];
+    let f_x = array![1., -2., 4., 0.5, 3.];
+    let base = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        strategy::BaseStrategy1D(Lerp),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let linear =
+        Interp1D::new(x.view(), f_x.view(), strategy::Linear, Extrapolate::Enable).unwrap();
+    for point in [-1., 0., 0.3, 1., 2.2, 3.7, 5., 6.] {
+        assert_eq!(
+            base.interpolate(&[point]).unwrap(),
+            linear.interpolate(&[point]).unwrap()
+        );
+    }
+    // Usable as a dynamic strategy
+    let boxed = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        Box::new(strategy::BaseStrategy1D(Lerp)) as Box<dyn Strategy1D<_>>,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert_eq!(
+        boxed.interpolate(&[0.3]).unwrap(),
+        linear.interpolate(&[0.3]).unwrap()
+    );
+}
+
+#[test]
+fn test_same_grid() {
+    let x = array![0., 1., 2.5, 3.];
+    let interp = Interp1D::new(
+        x.clone(),
+        array![0., 1., 2., 3.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // Identical grid, differing values and strategy
+    let same = Interp1D::new(
+        x.clone(),
+        array![3., 2., 1., 0.],
+        strategy::Nearest,
+        Extrapolate::Clamp,
+    )
+    .unwrap();
+    assert!(interp.same_grid(&same));
+    assert!(interp.same_grid_approx(&same, 0.));
+    // Tiny differences
+    let nudged = Interp1D::new(
+        &x + 1e-12,
+        array![0., 1., 2., 3.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(!interp.same_grid(&nudged));
+    assert!(interp.same_grid_approx(&nudged, 1e-9));
+    assert!(!interp.same_grid_approx(&nudged, 1e-15));
+    // Different grids
+    let different = Interp1D::new(
+        array![0., 1., 2., 3.],
+        array![0., 1., 2., 3.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(!interp.same_grid(&different));
+    assert!(!interp.same_grid_approx(&different, 0.1));
+    let shorter = Interp1D::new(
+        array![0., 1.],
+        array![0., 1.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(!interp.same_grid(&shorter));
+    assert!(!interp.same_grid_approx(&shorter, 10.));
+}
+
+#[test]
+fn test_interpolate_and_accumulate() {
+    let x = array![0., 1., 2.5, 3., 5.];
+    let f_x = array![1., -2., 4., 0.5, 3.];
+    let interp = Interp1D::new(x.view(), f_x.view(), strategy::Linear, Extrapolate::Error).unwrap();
+    // Exact integral of the piecewise-linear interpolant
+    let exact: f64 = x
+        .windows(2)
+        .into_iter()
+        .zip(f_x.windows(2))
+        .map(|(x, f)| (x[1] - x[0]) * (f[0] + f[1]) / 2.)
+        .sum();
+    // Sweep including every grid point
+    let sweep = Array1::linspace(0., 5., 21);
+    let mut acc = 0.;
+    let mut prev_val = interp.interpolate(&[sweep[0]]).unwrap();
+    for w in sweep.windows(2) {
+        prev_val = interp
+            .interpolate_and_accumulate(&[w[1]], w[0], prev_val, &mut acc)
+            .unwrap();
+        assert_eq!(prev_val, interp.interpolate(&[w[1]]).unwrap());
+    }
+    assert_approx_eq!(acc, exact, 1e-12);
+    // Errors leave the accumulator unchanged
+    assert!(interp
+        .interpolate_and_accumulate(&[6.], 5., prev_val, &mut acc)
+        .is_err());
+    assert_approx_eq!(acc, exact, 1e-12);
+}
+
+#[test]
+fn test_staircase_linear() {
+    // Jump from 2 to 10 at x = 2
+    let x = array![0., 1., 2., 3., 4.];
+    let f_x = array![0., 2., 10., 11., 12.];
+    let interp = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        strategy::StaircaseLinear::new(vec![2]),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let eps = 1e-9;
+    // Distinct left and right limits at the breakpoint
+    assert_eq!(interp.interpolate(&[2. - eps]).unwrap(), 2.);
+    assert_eq!(interp.interpolate(&[2.]).unwrap(), 10.);
+    assert_approx_eq!(interp.interpolate(&[2. + eps]).unwrap(), 10.);
+    // Linear within bins
+    assert_eq!(interp.interpolate(&[0.5]).unwrap(), 1.);
+    assert_eq!(interp.interpolate(&[3.5]).unwrap(), 11.5);
+    // Held across the jump segment
+    assert_eq!(interp.interpolate(&[1.5]).unwrap(), 2.);
+    // Extrapolation
+    assert_eq!(interp.interpolate(&[-1.]).unwrap(), -2.);
+    assert_eq!(interp.interpolate(&[5.]).unwrap(), 13.);
+    // Jump in the last segment holds the right value beyond the grid
+    let interp = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        strategy::StaircaseLinear::new(vec![4, 1, 4]),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert_eq!(interp.strategy.breakpoints, vec![1, 4]);
+    assert_eq!(interp.interpolate(&[3.5]).unwrap(), 11.);
+    assert_eq!(interp.interpolate(&[5.]).unwrap(), 12.);
+    assert_eq!(interp.interpolate(&[-1.]).unwrap(), 0.);
+    // Breakpoints index a descending grid as supplied
+    let ascending = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        strategy::StaircaseLinear::new(vec![2]),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let descending = Interp1D::new(
+        x.slice(s![..;-1]),
+        f_x.slice(s![..;-1]),
+        strategy::StaircaseLinear::new(vec![3]),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert_eq!(descending.strategy.breakpoints, vec![2]);
+    for point in [-1., 0.5, 1.5, 2. - eps, 2., 3.5, 5.] {
+        assert_eq!(
+            descending.interpolate(&[point]).unwrap(),
+            ascending.interpolate(&[point]).unwrap()
+        );
+    }
+    // Without breakpoints, equivalent to `Linear`
+    let linear =
+        Interp1D::new(x.view(), f_x.view(), strategy::Linear, Extrapolate::Enable).unwrap();
+    let staircase = Interp1D::new(
+        x.view(),
+        f_x.view(),
+        strategy::StaircaseLinear::default(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    for point in [-1., 0.5, 1.5, 2., 3.7, 5.] {
+        assert_eq!(
+            staircase.interpolate(&[point]).unwrap(),
+            linear.interpolate(&[point]).unwrap()
+        );
+    }
+    // Out of range breakpoints
+    for breakpoints in [vec![0], vec![5]] {
+        assert!(Interp1D::new(
+            x.view(),
+            f_x.view(),
+            strategy::StaircaseLinear::new(breakpoints),
+            Extrapolate::Error,
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn test_extend_from_interp() {
+    let mut interp = Interp1D::new(
+        array![0., 1., 2.],
+        array![0., 2., 4.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let other = Interp1D::new(
+        array![1., 2., 4., 5.],
+        array![-1., -1., 10., 0.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(interp.interpolate(&[3.]).is_err());
+    interp.extend_from_interp(&other).unwrap();
+    assert_eq!(interp.domain_bounds(0), Some((0., 5.)));
+    assert_eq!(interp.data.grid[0], array![0., 1., 2., 4., 5.]);
+    assert_eq!(interp.data.values, array![0., 2., 4., 10., 0.]);
+    // Existing points are unchanged, and the gap is bridged linearly
+    assert_eq!(interp.interpolate(&[1.5]).unwrap(), 3.);
+    assert_eq!(interp.interpolate(&[3.]).unwrap(), 7.);
+    assert_eq!(interp.interpolate(&[4.5]).unwrap(), 5.);
+    // Nothing beyond the domain
+    let before = interp.clone();
+    interp.extend_from_interp(&other).unwrap();
+    assert_eq!(interp, before);
+    // Strategy is re-initialized
+    let mut interp = Interp1D::new(
+        array![0., 1.],
+        array![0., 1.],
+        strategy::Newton::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let other = Interp1D::new(
+        array![2., 3.],
+        array![4., 9.],
+        strategy::Newton::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[4.]).unwrap(), 4.);
+    interp.extend_from_interp(&other).unwrap();
+    assert_approx_eq!(interp.interpolate(&[4.]).unwrap(), 16.);
+}
+
+#[test]
+fn test_gradient() {
+    let x = array![0., 1., 3., 4.];
+    let f_x = array![0., 2., 3., 1.];
+    let mut interp =
+        Interp1D::new(x.clone(), f_x.clone(), strategy::Linear, Extrapolate::Error).unwrap();
+    // Segment slopes, using the segment below at interior grid points
+    for (point, slope) in [
+        (0., 2.),
+        (0.5, 2.),
+        (1., 2.),
+        (2., 0.5),
+        (3.5, -2.),
+        (4., -2.),
+    ] {
+        assert_eq!(interp.gradient(&[point]).unwrap(), [slope]);
+    }
+    assert!(matches!(
+        interp.gradient(&[5.]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    assert!(matches!(
+        interp.gradient(&[1., 2.]).unwrap_err(),
+        InterpolateError::PointLength(1)
+    ));
+    for (extrapolate, expected) in [
+        (Extrapolate::Enable, -2.),
+        (Extrapolate::Fill(0.), 0.),
+        (Extrapolate::Clamp, 0.),
+        (Extrapolate::NearestValue, 0.),
+        // Wraps to 1.5
+        (Extrapolate::Wrap, 0.5),
+        (
+            Extrapolate::SaturatingFill {
+                value: 0.,
+                rate: 0.5,
+            },
+            -0.5,
+        ),
+        (
+            Extrapolate::SaturatingFill {
+                value: 5.,
+                rate: 0.5,
+            },
+            0.5,
+        ),
+        (
+            Extrapolate::SaturatingFill {
+                value: 1.2,
+                rate: 0.5,
+            },
+            0.,
+        ),
+    ] {
+        interp.set_extrapolate(extrapolate).unwrap();
+        assert_eq!(interp.gradient(&[5.5]).unwrap(), [expected]);
+    }
+    interp
+        .set_extrapolate(Extrapolate::SaturatingFill {
+            value: 5.,
+            rate: 0.5,
+        })
+        .unwrap();
+    assert_eq!(interp.gradient(&[-1.]).unwrap(), [-0.5]);
+
+    // Smooth strategies, against central finite differences
+    let h = 1e-6;
+    let pchip = Interp1D::new(
+        x.clone(),
+        f_x.clone(),
+        strategy::Pchip::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let newton = Interp1D::new(x, f_x, strategy::Newton::new(), Extrapolate::Enable).unwrap();
+    let fd = |interp: &dyn Interpolator<f64>, point: f64| {
+        (interp.interpolate(&[point + h]).unwrap() - interp.interpolate(&[point - h]).unwrap())
+            / (2. * h)
+    };
+    for point in [-0.5, 0.3, 1., 1.7, 2.9, 3.6, 4.5] {
+        assert_approx_eq!(
+            pchip.gradient(&[point]).unwrap()[0],
+            fd(&pchip, point),
+            1e-4
+        );
+        assert_approx_eq!(
+            newton.gradient(&[point]).unwrap()[0],
+            fd(&newton, point),
+            1e-4
+        );
+    }
+}
+
+#[test]
+fn test_integrate() {
+    // Line, integrated exactly, including linear extrapolation
+    let x = Array1::linspace(0., 4., 5);
+    let line = Interp1D::new(
+        x.clone(),
+        x.mapv(|x| 2. * x + 1.),
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let exact = |a: f64, b: f64| (b * b + b) - (a * a + a);
+    for (a, b) in [
+        (0., 4.),
+        (0.5, 2.25),
+        (1., 1.),
+        (3.5, 0.2),
+        (-1., 5.),
+        (4.5, 6.),
+    ] {
+        assert_approx_eq!(line.integrate(a, b).unwrap(), exact(a, b));
+    }
+    // Parabola, integrated more accurately by Pchip's cubic segments than by trapezoids
+    let x = Array1::linspace(0., 2., 21);
+    let f_x = x.mapv(|x| x * x);
+    let linear =
+        Interp1D::new(x.clone(), f_x.clone(), strategy::Linear, Extrapolate::Error).unwrap();
+    let pchip = Interp1D::new(x, f_x, strategy::Pchip::new(), Extrapolate::Error).unwrap();
+    let exact = 8. / 3.;
+    let linear_error = (linear.integrate(0., 2.).unwrap() - exact).abs();
+    let pchip_error = (pchip.integrate(0., 2.).unwrap() - exact).abs();
+    // Trapezoid rule error `h^2 / 12 * (b - a) * f''`
+    assert_approx_eq!(linear_error, 0.01 / 12. * 2. * 2.);
+    assert!(pchip_error < linear_error / 10.);
+    assert_approx_eq!(
+        pchip.integrate(0.55, 1.3).unwrap(),
+        (1.3f64.powi(3) - 0.55f64.powi(3)) / 3.,
+        1e-3
+    );
+    assert!(linear.integrate(-1., 1.).is_err());
+    // Piecewise constant
+    let x = array![0., 1., 3.];
+    let f_x = array![1., 2., 4.];
+    let nearest = Interp1D::new(
+        x.clone(),
+        f_x.clone(),
+        strategy::Nearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(nearest.integrate(0., 3.).unwrap(), 0.5 + 0.5 * 2. + 2. + 4.);
+    let left = Interp1D::new(
+        x.clone(),
+        f_x.clone(),
+        strategy::LeftNearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(left.integrate(0.5, 3.).unwrap(), 0.5 + 2. * 2.);
+    let right = Interp1D::new(
+        x.clone(),
+        f_x.clone(),
+        strategy::RightNearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(right.integrate(0.5, 3.).unwrap(), 0.5 * 2. + 2. * 4.);
+    // Extrapolation beyond a grid integrating to 5 over [0, 3]
+    let mut interp = Interp1D::new(
+        x,
+        array![1., 2., 1.],
+        strategy::Linear,
+        Extrapolate::Fill(3.),
+    )
+    .unwrap();
+    assert_eq!(interp.integrate(0., 3.).unwrap(), 4.5);
+    assert_eq!(interp.integrate(-1., 4.).unwrap(), 4.5 + 3. + 3.);
+    assert_eq!(interp.integrate(-2., -1.).unwrap(), 3.);
+    interp.set_extrapolate(Extrapolate::Clamp).unwrap();
+    assert_eq!(interp.integrate(-1., 5.).unwrap(), 1. + 4.5 + 2.);
+    interp.set_extrapolate(Extrapolate::NearestValue).unwrap();
+    assert_eq!(interp.integrate(-1., 5.).unwrap(), 1. + 4.5 + 2.);
+    // Rises from 1 to 2 over 2 beyond the upper edge, then saturates
+    interp
+        .set_extrapolate(Extrapolate::SaturatingFill {
+            value: 2.,
+            rate: 0.5,
+        })
+        .unwrap();
+    assert_eq!(interp.integrate(3., 6.).unwrap(), 3. + 2.);
+    assert_eq!(interp.integrate(4., 6.).unwrap(), 1.75 + 2.);
+    interp.set_extrapolate(Extrapolate::Wrap).unwrap();
+    assert_eq!(interp.integrate(-3., 6.).unwrap(), 3. * 4.5);
+    assert_eq!(interp.integrate(1., 7.).unwrap(), 4.5 + 3. + 1.5);
+    interp
+        .set_extrapolate(Extrapolate::EnableBounded(1.))
+        .unwrap();
+    assert_eq!(interp.integrate(-1., 0.).unwrap(), 0.5);
+    assert!(interp.integrate(-2., 0.).is_err());
+}
+
+#[test]
+fn test_descending_grid() {
+    let x = array![3., 2.5, 1., 0.];
+    let f_x = array![6., 4., 1., -2.];
+    let descending =
+        Interp1D::new(x.view(), f_x.view(), strategy::Linear, Extrapolate::Enable).unwrap();
+    let ascending = Interp1D::new(
+        x.slice(s![..;-1]).to_owned(),
+        f_x.slice(s![..;-1]).to_owned(),
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    // Stored increasing, viewing the original data
+    assert_eq!(descending.data.grid[0], array![0., 1., 2.5, 3.]);
+    assert_eq!(descending.data.values, array![-2., 1., 4., 6.]);
+    for point in [-1., 0., 0.4, 1., 2., 2.5, 2.9, 3., 4.] {
+        assert_eq!(
+            descending.interpolate(&[point]).unwrap(),
+            ascending.interpolate(&[point]).unwrap()
+        );
+    }
+    // Reversed arrays are not contiguous until repacked
+    assert!(!descending.data.is_contiguous());
+    assert!(descending
+        .data
+        .clone()
+        .into_owned()
+        .into_contiguous()
+        .is_contiguous());
+    // Non-monotonic and non-strictly decreasing grids are still rejected
+    for x in [array![0., 2., 1.], array![2., 1., 1.]] {
+        assert!(matches!(
+            Interp1D::new(x, array![0., 1., 2.], strategy::Linear, Extrapolate::Error).unwrap_err(),
+            ValidateError::Monotonicity(0)
+        ));
+    }
+}
+
+#[test]
+fn test_linear_tail() {
+    let x = array![0., 1., 3., 4.];
+    let f_x = array![1., 3., 2., 0.];
+    let interps = [
+        strategy::enums::Strategy1DEnum::from(strategy::Nearest),
+        strategy::LeftNearest.into(),
+        strategy::RightNearest.into(),
+    ]
+    .map(|strategy| Interp1D::new(x.clone(), f_x.clone(), strategy, Extrapolate::Error).unwrap());
+    for interp in interps {
+        let tailed = Interp1D::new(
+            x.clone(),
+            f_x.clone(),
+            strategy::LinearTail(interp.strategy.clone()),
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        // Unchanged within the grid
+        let inside = Array1::linspace(0., 4., 17);
+        assert_eq!(
+            tailed.interpolate_batch(&inside.view()).unwrap(),
+            interp.interpolate_batch(&inside.view()).unwrap()
+        );
+        // Boundary segment slopes beyond the grid
+        assert_eq!(tailed.interpolate(&[-1.]).unwrap(), -1.);
+        assert_eq!(tailed.interpolate(&[5.5]).unwrap(), -3.);
+        assert_eq!(tailed.gradient(&[-1.]).unwrap(), [2.]);
+        assert_eq!(tailed.gradient(&[5.5]).unwrap(), [-2.]);
+        assert_eq!(
+            tailed
+                .interpolate_batch(&array![-1., 2., 5.5].view())
+                .unwrap(),
+            array![-1., interp.interpolate(&[2.]).unwrap(), -3.]
+        );
+    }
+    // Without the wrapper, the nearest strategies cannot extrapolate
+    assert!(Interp1D::new(x, f_x, strategy::Nearest, Extrapolate::Enable).is_err());
+}
+
+#[test]
+fn test_push() {
+    let mut interp = Interp1D::new(
+        array![0., 1.],
+        array![0., 2.],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    for (x, f_x) in [(2., 3.), (4., 7.), (4.5, 7.)] {
+        interp.push(x, f_x).unwrap();
+    }
+    assert_eq!(interp.data.grid[0], array![0., 1., 2., 4., 4.5]);
+    assert_eq!(interp.data.values, array![0., 2., 3., 7., 7.]);
+    assert_eq!(interp.interpolate(&[3.]).unwrap(), 5.);
+    assert_eq!(interp.interpolate(&[4.25]).unwrap(), 7.);
+    assert!(interp.interpolate(&[5.]).is_err());
+    // Out of order, repeated, or NaN points are rejected, leaving the data unchanged
+    for x in [3., 4.5, f64::NAN] {
+        assert!(matches!(
+            interp.push(x, 0.).unwrap_err(),
+            ValidateError::Monotonicity(0)
+        ));
+    }
+    assert_eq!(interp.data.grid[0].len(), 5);
+    assert!(interp.validate().is_ok());
+    assert_eq!(interp.interpolate(&[3.]).unwrap(), 5.);
+    // Strategies caching state are re-initialized
+    let mut pchip = Interp1D::new(
+        array![0., 1.],
+        array![0., 1.],
+        strategy::Pchip::new(),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    pchip.push(2., 4.).unwrap();
+    let rebuilt = Interp1D::new(
+        array![0., 1., 2.],
+        array![0., 1., 4.],
+        strategy::Pchip::new(),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(pchip, rebuilt);
+    // Strategy initialization errors roll back the push
+    let mut geometric = Interp1D::new(
+        array![0., 1.],
+        array![1., 2.],
+        strategy::Geometric,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(geometric.push(2., -1.).is_err());
+    assert_eq!(geometric.data.grid[0], array![0., 1.]);
+    assert_eq!(geometric.data.values, array![1., 2.]);
+}
+
+#[test]
+fn test_from_fn() {
+    let x = array![-2., -0.5, 0., 1., 3.];
+    let interp = Interp1D::from_fn(
+        x.clone(),
+        |x| x * x,
+        strategy::Pchip::new(),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    for x_i in x.iter() {
+        assert_eq!(interp.interpolate(&[*x_i]).unwrap(), x_i * x_i);
+    }
+    assert_eq!(
+        interp,
+        Interp1D::new(
+            x.clone(),
+            x.mapv(|x| x * x),
+            strategy::Pchip::new(),
+            Extrapolate::Error
+        )
+        .unwrap()
+    );
+    // Validated like `new`
+    assert!(Interp1D::from_fn(
+        array![],
+        |x: f64| x * x,
+        strategy::Linear,
+        Extrapolate::Error
+    )
+    .is_err());
 }
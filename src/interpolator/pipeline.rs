@@ -0,0 +1,602 @@
+//! Composable pre/post-processing around an [`Interpolator`].
+
+use super::*;
+
+/// Coordinate transform applied by an axis warping stage, see [`Pipeline::warp_axis`].
+pub type AxisWarp<T> = fn(T) -> T;
+
+/// Maximum extrapolation distance beyond each end of an axis,
+/// see [`Pipeline::limit_extrapolation`].
+///
+/// `None` leaves that end unlimited, and `Some(0)` disallows extrapolation beyond it.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct AxisLimit<T> {
+    /// Maximum distance below the lowest grid point.
+    pub below: Option<T>,
+    /// Maximum distance above the highest grid point.
+    pub above: Option<T>,
+}
+
+/// An [`Interpolator`] wrapped with optional pre/post-processing stages,
+/// for a fully-configured lookup in a single object.
+///
+/// Stages are applied in this order, each being optional:
+/// 1. Input clamping ([`Pipeline::clamp_input`]): restrict a point coordinate to `[min, max]`
+/// 2. Axis warping ([`Pipeline::warp_axis`]): transform a point coordinate, e.g. `f64::ln`
+///    for an interpolator whose grid is in log space
+/// 3. Extrapolation limits ([`Pipeline::limit_extrapolation`]): error if a point coordinate
+///    is further below or above the wrapped interpolator's domain than allowed
+/// 4. Axis wrapping ([`Pipeline::wrap_axis`]): map a periodic (e.g. angular) point coordinate
+///    into `[min, min + period)`
+/// 5. Interpolation by the wrapped interpolator, per its own [`Extrapolate`] setting
+/// 6. Output affine transform ([`Pipeline::affine_output`]): `scale * value + offset`,
+///    e.g. for unit conversions or calibration corrections atop a shared table
+/// 7. Output clamping ([`Pipeline::clamp_output`]): restrict the result to `[min, max]`
+///
+/// Input clamping applies to the point as supplied, i.e. *before* warping,
+/// whereas extrapolation limits and wrapping apply in the interpolator's grid coordinates,
+/// i.e. *after* warping.
+/// Multiple stages on the same axis are applied in the order they were added.
+///
+/// With the `serde` feature, pipelines can be (de)serialized along with their stages,
+/// except for axis warps: these are function pointers,
+/// so serializing a pipeline with axis warps is an error.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::pipeline::Pipeline;
+/// // Table of f(x) over ln(x)
+/// let interp = Interp1D::new(
+///     array![0., 1., 2.],
+///     array![0., 0.5, 2.],
+///     strategy::Linear,
+///     Extrapolate::Enable,
+/// )
+/// .unwrap();
+/// let pipeline = Pipeline::new(interp)
+///     .warp_axis(0, f64::ln)
+///     .clamp_output(0., 1.);
+/// assert_eq!(pipeline.interpolate(&[1f64.exp()]).unwrap(), 0.5);
+/// assert_eq!(pipeline.interpolate(&[2f64.exp()]).unwrap(), 1.);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Pipeline<T, I> {
+    /// Wrapped interpolator.
+    pub interpolator: I,
+    /// Input clamping stages, as `(dim, min, max)`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub input_clamps: Vec<(usize, T, T)>,
+    /// Axis warping stages, as `(dim, warp)`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Vec::is_empty",
+            serialize_with = "serialize_warps"
+        )
+    )]
+    pub warps: Vec<(usize, AxisWarp<T>)>,
+    /// Extrapolation limit stages, as `(dim, limit)`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extrapolation_limits: Vec<(usize, AxisLimit<T>)>,
+    /// Axis wrapping stages, as `(dim, min, period)`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub wraps: Vec<(usize, T, T)>,
+    /// Output affine transform stage, as `(scale, offset)`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub output_affine: Option<(T, T)>,
+    /// Output clamping stage, as `(min, max)`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub output_clamp: Option<(T, T)>,
+}
+
+/// Error upon serializing axis warps, which are function pointers.
+#[cfg(feature = "serde")]
+fn serialize_warps<T, S>(warps: &[(usize, AxisWarp<T>)], _serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    Err(serde::ser::Error::custom(format!(
+        "pipeline axis warps cannot be serialized, found {}",
+        warps.len()
+    )))
+}
+
+impl<T, I> Pipeline<T, I>
+where
+    I: Interpolator<T>,
+{
+    /// Wrap an interpolator, with no stages configured.
+    #[must_use]
+    pub fn new(interpolator: I) -> Self {
+        Self {
+            interpolator,
+            input_clamps: Vec::new(),
+            warps: Vec::new(),
+            extrapolation_limits: Vec::new(),
+            wraps: Vec::new(),
+            output_affine: None,
+            output_clamp: None,
+        }
+    }
+
+    /// Add an input clamping stage, restricting the point's `dim` coordinate to `[min, max]`.
+    pub fn clamp_input(mut self, dim: usize, min: T, max: T) -> Self {
+        self.input_clamps.push((dim, min, max));
+        self
+    }
+
+    /// Add an axis warping stage, transforming the point's `dim` coordinate with `warp`.
+    pub fn warp_axis(mut self, dim: usize, warp: AxisWarp<T>) -> Self {
+        self.warps.push((dim, warp));
+        self
+    }
+
+    /// Add an extrapolation limit stage, returning an error for points whose `dim` coordinate
+    /// is further below or above the wrapped interpolator's domain than `limit` allows,
+    /// e.g. for a model trusted slightly below the lowest measured point, but not above the highest.
+    ///
+    /// This generalizes [`Extrapolate::EnableBounded`] to distinct limits per axis and end.
+    /// Points within the limits are passed on to the wrapped interpolator,
+    /// which handles them per its own [`Extrapolate`] setting, e.g. [`Extrapolate::Enable`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// use ninterp::interpolator::pipeline::{AxisLimit, Pipeline};
+    /// let interp = Interp1D::new(
+    ///     array![0., 10.],
+    ///     array![0., 1.],
+    ///     strategy::Linear,
+    ///     Extrapolate::Enable,
+    /// )
+    /// .unwrap();
+    /// let pipeline = Pipeline::new(interp).limit_extrapolation(
+    ///     0,
+    ///     AxisLimit {
+    ///         below: Some(1.),
+    ///         above: None,
+    ///     },
+    /// );
+    /// assert_eq!(pipeline.interpolate(&[-1.]).unwrap(), -0.1);
+    /// assert!(pipeline.interpolate(&[-2.]).is_err());
+    /// assert_eq!(pipeline.interpolate(&[20.]).unwrap(), 2.);
+    /// ```
+    pub fn limit_extrapolation(mut self, dim: usize, limit: AxisLimit<T>) -> Self {
+        self.extrapolation_limits.push((dim, limit));
+        self
+    }
+
+    /// Add an axis wrapping stage, mapping the point's `dim` coordinate into `[min, min + period)`.
+    ///
+    /// See [`Interp2D::with_angular_axis`] for angular axes,
+    /// which also ensures the wrapped interpolator's data spans the full period.
+    pub fn wrap_axis(mut self, dim: usize, min: T, period: T) -> Self {
+        self.wraps.push((dim, min, period));
+        self
+    }
+
+    /// Set the output affine transform stage, mapping the interpolated value to `scale * value + offset`.
+    pub fn affine_output(mut self, scale: T, offset: T) -> Self {
+        self.set_output_affine(scale, offset);
+        self
+    }
+
+    /// Set the output affine transform stage in place, see [`Pipeline::affine_output`].
+    pub fn set_output_affine(&mut self, scale: T, offset: T) {
+        self.output_affine = Some((scale, offset));
+    }
+
+    /// Set the output clamping stage, restricting the interpolated value to `[min, max]`.
+    pub fn clamp_output(mut self, min: T, max: T) -> Self {
+        self.output_clamp = Some((min, max));
+        self
+    }
+
+    /// Set the output clamping stage to the range `[min, max]` of the wrapped interpolator's values,
+    /// e.g. so that strategies prone to overshoot (such as [`strategy::Newton`])
+    /// cannot return unphysical values outside the data.
+    ///
+    /// The range is computed when this is called, so should be reapplied if the values change.
+    /// Leaves the stage unchanged if the interpolator exposes no values
+    /// (see [`Interpolator::values_view`]).
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// use ninterp::interpolator::pipeline::Pipeline;
+    /// let interp = Interp1D::new(
+    ///     array![0., 1., 2., 3.],
+    ///     array![0., 1., 1., 0.],
+    ///     strategy::Newton::new(),
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.interpolate(&[1.5]).unwrap(), 1.125);
+    /// let pipeline = Pipeline::new(interp).clamp_output_to_data_range();
+    /// assert_eq!(pipeline.output_clamp, Some((0., 1.)));
+    /// assert_eq!(pipeline.interpolate(&[1.5]).unwrap(), 1.);
+    /// ```
+    pub fn clamp_output_to_data_range(mut self) -> Self
+    where
+        T: PartialOrd + Copy,
+    {
+        let range = self.interpolator.values_view().and_then(|values| {
+            values.iter().fold(None, |range, &v| match range {
+                None => Some((v, v)),
+                Some((min, max)) => {
+                    Some((if v < min { v } else { min }, if v > max { v } else { max }))
+                }
+            })
+        });
+        if range.is_some() {
+            self.output_clamp = range;
+        }
+        self
+    }
+}
+
+impl<T, I> Pipeline<T, I>
+where
+    T: Num + Euclid + PartialOrd + Copy + Debug,
+    I: Interpolator<T>,
+{
+    /// Check that stages refer to existing axes, have `min <= max`,
+    /// have non-negative extrapolation limits, and have positive wrap periods.
+    fn check_stages(&self) -> Result<(), String> {
+        let n = self.interpolator.ndim();
+        let dims = self
+            .input_clamps
+            .iter()
+            .map(|(dim, _, _)| dim)
+            .chain(self.warps.iter().map(|(dim, _)| dim))
+            .chain(self.extrapolation_limits.iter().map(|(dim, _)| dim))
+            .chain(self.wraps.iter().map(|(dim, _, _)| dim));
+        for &dim in dims {
+            if dim >= n {
+                return Err(format!(
+                    "pipeline stage refers to dim {dim}, but interpolator is {n}-D"
+                ));
+            }
+        }
+        let bounds = self
+            .input_clamps
+            .iter()
+            .map(|(_, min, max)| (min, max))
+            .chain(self.output_clamp.iter().map(|(min, max)| (min, max)));
+        for (min, max) in bounds {
+            if min.partial_cmp(max).is_none_or(std::cmp::Ordering::is_gt) {
+                return Err(format!(
+                    "pipeline clamp bounds must satisfy min <= max, got min = {min:?}, max = {max:?}"
+                ));
+            }
+        }
+        let limits = self
+            .extrapolation_limits
+            .iter()
+            .flat_map(|(_, limit)| limit.below.iter().chain(&limit.above));
+        for limit in limits {
+            if limit
+                .partial_cmp(&T::zero())
+                .is_none_or(std::cmp::Ordering::is_lt)
+            {
+                return Err(format!(
+                    "pipeline extrapolation limit must be non-negative, got {limit:?}"
+                ));
+            }
+        }
+        for (_, _, period) in &self.wraps {
+            if period
+                .partial_cmp(&T::zero())
+                .is_none_or(std::cmp::Ordering::is_le)
+            {
+                return Err(format!(
+                    "pipeline wrap period must be positive, got {period:?}"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Apply input stages (clamping, warping, extrapolation limits, then wrapping) to a point.
+    ///
+    /// Errors if any stage is invalid, see [`Pipeline::check_stages`].
+    fn preprocess(&self, point: &[T]) -> Result<Vec<T>, InterpolateError> {
+        let n = self.interpolator.ndim();
+        if point.len() != n {
+            return Err(InterpolateError::PointLength(n));
+        }
+        self.check_stages().map_err(InterpolateError::Other)?;
+        let mut point = point.to_vec();
+        for &(dim, min, max) in &self.input_clamps {
+            point[dim] = *clamp(&point[dim], &min, &max);
+        }
+        for &(dim, warp) in &self.warps {
+            point[dim] = warp(point[dim]);
+        }
+        for &(dim, limit) in &self.extrapolation_limits {
+            let Some((min, max)) = self.interpolator.domain_bounds(dim) else {
+                continue;
+            };
+            let x = point[dim];
+            if let Some(below) = limit.below.filter(|&below| x < min && min - x > below) {
+                return Err(InterpolateError::ExtrapolateError(format!(
+                    "\n    point[{dim}] = {x:?} is more than {below:?} below grid[{dim}] minimum {min:?}",
+                )));
+            }
+            if let Some(above) = limit.above.filter(|&above| x > max && x - max > above) {
+                return Err(InterpolateError::ExtrapolateError(format!(
+                    "\n    point[{dim}] = {x:?} is more than {above:?} above grid[{dim}] maximum {max:?}",
+                )));
+            }
+        }
+        for &(dim, min, period) in &self.wraps {
+            point[dim] = wrap(point[dim], min, min + period);
+        }
+        Ok(point)
+    }
+
+    /// Apply output stages (affine transform, then clamping) to an interpolated value.
+    fn postprocess(&self, value: T) -> T {
+        let value = match self.output_affine {
+            Some((scale, offset)) => scale * value + offset,
+            None => value,
+        };
+        match &self.output_clamp {
+            Some((min, max)) => *clamp(&value, min, max),
+            None => value,
+        }
+    }
+}
+
+impl<T, I> Interpolator<T> for Pipeline<T, I>
+where
+    T: Num + Euclid + PartialOrd + Copy + Debug,
+    I: Interpolator<T> + Clone,
+{
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.interpolator.ndim()
+    }
+
+    /// Validate the wrapped interpolator, and that stages refer to existing axes,
+    /// have `min <= max`, and have non-negative extrapolation limits.
+    fn validate(&mut self) -> Result<(), ValidateError> {
+        self.interpolator.validate()?;
+        self.check_stages().map_err(ValidateError::Other)
+    }
+
+    fn interpolate(&self, point: &[T]) -> Result<T, InterpolateError> {
+        let point = self.preprocess(point)?;
+        Ok(self.postprocess(self.interpolator.interpolate(&point)?))
+    }
+
+    /// Applies pipeline stages around [`Interpolator::interpolate_clamped`]
+    /// of the wrapped interpolator.
+    fn interpolate_clamped(&self, point: &[T]) -> Result<T, InterpolateError> {
+        let point = self.preprocess(point)?;
+        Ok(self.postprocess(self.interpolator.interpolate_clamped(&point)?))
+    }
+
+    /// Set [`Extrapolate`] variant of the wrapped interpolator.
+    #[inline]
+    fn set_extrapolate(&mut self, extrapolate: Extrapolate<T>) -> Result<(), ValidateError> {
+        self.interpolator.set_extrapolate(extrapolate)
+    }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.interpolator.is_contiguous()
+    }
+
+    /// Bounds of the wrapped interpolator, in its grid coordinates (i.e. after warping).
+    #[inline]
+    fn domain_bounds(&self, dim: usize) -> Option<(T, T)> {
+        self.interpolator.domain_bounds(dim)
+    }
+
+    /// Whether `point`, after the input stages, lies within the domain of the wrapped interpolator.
+    fn contains(&self, point: &[T]) -> bool {
+        self.preprocess(point)
+            .is_ok_and(|point| self.interpolator.contains(&point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_warp_output_clamp() {
+        let x = array![1., 10., 100., 1000.];
+        let interp = Interp1D::new(
+            x.mapv(f64::ln),
+            array![0., 0.4, 0.9, 1.5],
+            strategy::Linear,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        let pipeline = Pipeline::new(interp.clone())
+            .warp_axis(0, f64::ln)
+            .clamp_output(0.1, 1.2);
+        for point in [0.5, 1., 3., 10., 42., 100., 500., 1000., 5000.] {
+            let manual = interp
+                .interpolate(&[f64::ln(point)])
+                .unwrap()
+                .clamp(0.1, 1.2);
+            assert_eq!(pipeline.interpolate(&[point]).unwrap(), manual);
+        }
+        // Domain is checked after warping
+        assert!(pipeline.contains(&[10.]));
+        assert!(!interp.contains(&[10.]));
+        assert!(!pipeline.contains(&[5000.]));
+    }
+
+    #[test]
+    fn test_stage_order() {
+        let interp = Interp2D::new(
+            array![0., 1., 2.],
+            array![0., 1.],
+            array![[0., 1.], [2., 3.], [4., 5.]],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        // Input clamping happens before warping
+        let mut pipeline = Pipeline::new(interp)
+            .clamp_input(0, 0., 4.)
+            .warp_axis(0, f64::sqrt)
+            .clamp_input(1, 0., 1.);
+        pipeline.validate().unwrap();
+        assert_eq!(pipeline.interpolate(&[9., 0.5]).unwrap(), 4.5);
+        assert_eq!(pipeline.interpolate(&[1., -1.]).unwrap(), 2.);
+        assert!(matches!(
+            pipeline.interpolate(&[1.]).unwrap_err(),
+            InterpolateError::PointLength(2)
+        ));
+        // Stages must refer to existing axes
+        let mut invalid = pipeline.clone().warp_axis(2, f64::ln);
+        assert!(invalid.validate().is_err());
+        assert!(matches!(
+            invalid.interpolate(&[1., 0.5]).unwrap_err(),
+            InterpolateError::Other(_)
+        ));
+        // ...and have `min <= max`
+        let mut invalid = pipeline.clone().clamp_input(0, 1., 0.);
+        assert!(invalid.validate().is_err());
+        assert!(invalid.interpolate(&[1., 0.5]).is_err());
+        assert!(invalid.interpolate_clamped(&[1., 0.5]).is_err());
+        let invalid = pipeline.clamp_output(f64::NAN, 1.);
+        assert!(invalid.interpolate(&[1., 0.5]).is_err());
+    }
+
+    #[test]
+    fn test_output_affine() {
+        let interp = Interp1D::new(
+            array![0., 1., 2.],
+            array![0., 1., 4.],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let mut pipeline = Pipeline::new(interp.clone()).affine_output(2., 1.);
+        for x in [0., 0.5, 1., 1.5, 2.] {
+            let raw = interp.interpolate(&[x]).unwrap();
+            assert_eq!(pipeline.interpolate(&[x]).unwrap(), 2. * raw + 1.);
+        }
+        // Affine transform is applied before output clamping
+        pipeline = pipeline.clamp_output(0., 5.);
+        assert_eq!(pipeline.interpolate(&[1.]).unwrap(), 3.);
+        assert_eq!(pipeline.interpolate(&[2.]).unwrap(), 5.);
+        pipeline.set_output_affine(1., 0.);
+        assert_eq!(pipeline.interpolate(&[1.5]).unwrap(), 2.5);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let interp = Interp1D::new(
+            array![0., 1., 2.],
+            array![0., 1., 4.],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let pipeline = Pipeline::new(interp)
+            .affine_output(2., 1.)
+            .clamp_output(0., 8.);
+
+        let ser = serde_json::to_string(&pipeline).unwrap();
+        let de: Pipeline<f64, Interp1DOwned<f64, strategy::Linear>> =
+            serde_json::from_str(&ser).unwrap();
+        assert_eq!(pipeline, de);
+        assert_eq!(de.interpolate(&[1.5]).unwrap(), 6.);
+        // Axis warps are function pointers, which cannot be serialized
+        assert!(serde_json::to_string(&pipeline.warp_axis(0, f64::ln)).is_err());
+    }
+
+    #[test]
+    fn test_extrapolation_limits() {
+        let interp = Interp2D::new(
+            array![0., 5., 10.],
+            array![0., 1.],
+            array![[0., 1.], [5., 6.], [10., 11.]],
+            strategy::Linear,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        // 10% below on axis 0, nothing above on axis 1
+        let mut pipeline = Pipeline::new(interp)
+            .limit_extrapolation(
+                0,
+                AxisLimit {
+                    below: Some(1.),
+                    above: None,
+                },
+            )
+            .limit_extrapolation(
+                1,
+                AxisLimit {
+                    below: None,
+                    above: Some(0.),
+                },
+            );
+        pipeline.validate().unwrap();
+        assert_eq!(pipeline.interpolate(&[-1., 0.]).unwrap(), -1.);
+        assert!(matches!(
+            pipeline.interpolate(&[-1.5, 0.]).unwrap_err(),
+            InterpolateError::ExtrapolateError(_)
+        ));
+        assert_eq!(pipeline.interpolate(&[15., 0.]).unwrap(), 15.);
+        assert_eq!(pipeline.interpolate(&[5., 1.]).unwrap(), 6.);
+        assert!(matches!(
+            pipeline.interpolate(&[5., 1.01]).unwrap_err(),
+            InterpolateError::ExtrapolateError(_)
+        ));
+        assert_eq!(pipeline.interpolate(&[5., -3.]).unwrap(), 2.);
+        // Limits must be non-negative
+        let mut pipeline = pipeline.limit_extrapolation(
+            0,
+            AxisLimit {
+                below: None,
+                above: Some(-1.),
+            },
+        );
+        assert!(pipeline.validate().is_err());
+        assert!(pipeline.interpolate(&[5., 0.5]).is_err());
+        let pipeline = Pipeline::new(Interp0D(1.)).limit_extrapolation(3, AxisLimit::default());
+        assert!(pipeline.interpolate(&[]).is_err());
+    }
+
+    #[test]
+    fn test_clamp_output_to_data_range() {
+        let interp = Interp1D::new(
+            array![0., 1., 2., 3., 4.],
+            array![0.2, 0.9, 0.95, 0.5, 0.1],
+            strategy::Newton::new(),
+            Extrapolate::Error,
+        )
+        .unwrap();
+        let pipeline = Pipeline::new(interp.clone()).clamp_output_to_data_range();
+        assert_eq!(pipeline.output_clamp, Some((0.1, 0.95)));
+        let mut overshot = false;
+        for x in Array1::linspace(0., 4., 41) {
+            let raw = interp.interpolate(&[x]).unwrap();
+            overshot |= raw > 0.95;
+            assert_eq!(pipeline.interpolate(&[x]).unwrap(), raw.clamp(0.1, 0.95));
+        }
+        assert!(overshot);
+        // A single value gives a degenerate range, overriding any previous clamp
+        let pipeline = Pipeline::new(Interp0D(2.))
+            .clamp_output(0., 1.)
+            .clamp_output_to_data_range();
+        assert_eq!(pipeline.output_clamp, Some((2., 2.)));
+        assert_eq!(pipeline.interpolate(&[]).unwrap(), 2.);
+    }
+}
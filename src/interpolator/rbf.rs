@@ -0,0 +1,449 @@
+//! Radial basis function interpolation of scattered data.
+
+use super::*;
+use one::solve_linear_system;
+
+/// Radial kernel `phi` of an [`InterpRBF`], evaluated at the distance `r` from each sample point
+/// scaled by the shape parameter [`InterpRBF::epsilon`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum RadialBasis {
+    /// `exp(-(epsilon * r)^2)`, decaying away from each sample point.
+    Gaussian,
+    /// `sqrt(1 + (epsilon * r)^2)`, growing away from each sample point.
+    Multiquadric,
+    /// `(epsilon * r)^2 * ln(epsilon * r)`, the minimum-bending-energy surface in 2-D.
+    ///
+    /// Should be used with polynomial augmentation, without which the weight system can be singular.
+    ThinPlate,
+}
+
+impl RadialBasis {
+    /// Evaluate the kernel at distance `r`.
+    fn evaluate<T: Float>(self, r: T, epsilon: T) -> T {
+        let s = epsilon * r;
+        match self {
+            Self::Gaussian => (-s * s).exp(),
+            Self::Multiquadric => (T::one() + s * s).sqrt(),
+            Self::ThinPlate if s.is_zero() => T::zero(),
+            Self::ThinPlate => s * s * s.ln(),
+        }
+    }
+}
+
+/// An interpolator over scattered (non-gridded) data,
+/// as a weighted sum of [`RadialBasis`] kernels centered on each sample point.
+///
+/// The weights are solved for upon construction so that the interpolant passes exactly through
+/// the sample values. If [`InterpRBF::polynomial`] is set, the sum is augmented with a linear
+/// polynomial, which is then reproduced exactly, e.g. for trends in the data.
+///
+/// Out-of-bounds points, those outside the bounding box of the sample points, follow the
+/// [`Extrapolate`] setting. Only [`Extrapolate::Enable`], [`Extrapolate::Fill`],
+/// [`Extrapolate::Clamp`], and [`Extrapolate::Error`] are applicable.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::rbf::{InterpRBF, RadialBasis};
+/// let interp = InterpRBF::new(
+///     array![[0., 0.], [1., 0.], [0., 1.], [1., 1.], [0.4, 0.6]],
+///     array![0., 1., 2., 3., 1.5],
+///     RadialBasis::ThinPlate,
+///     1.,
+///     true,
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert!((interp.interpolate(&[0.4, 0.6]).unwrap() - 1.5_f64).abs() < 1e-12);
+/// assert!(interp.interpolate(&[1.5, 0.]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InterpRBF<T>
+where
+    T: Float + Debug,
+{
+    /// Sample point coordinates, one point per row.
+    pub points: Array2<T>,
+    /// Sample values, one per point.
+    pub values: Array1<T>,
+    /// Radial kernel.
+    pub kernel: RadialBasis,
+    /// Kernel shape parameter, scaling distances before evaluating the kernel.
+    /// Larger values give narrower kernels.
+    pub epsilon: T,
+    /// Augment the kernels with a linear polynomial.
+    pub polynomial: bool,
+    /// Setting for points outside the bounding box of [`InterpRBF::points`].
+    pub extrapolate: Extrapolate<T>,
+    /// Kernel weights, followed by polynomial coefficients (constant first) if augmented.
+    pub(crate) coefficients: Array1<T>,
+}
+
+impl<T> InterpRBF<T>
+where
+    T: Float + Debug,
+{
+    /// Construct and validate an RBF interpolator, solving for the kernel weights.
+    ///
+    /// Errors if `points` is empty or does not have one row per value,
+    /// if `epsilon` is not positive, if the [`Extrapolate`] setting is inapplicable,
+    /// or if the weight system is singular, e.g. for repeated sample points.
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn new(
+        points: Array2<T>,
+        values: Array1<T>,
+        kernel: RadialBasis,
+        epsilon: T,
+        polynomial: bool,
+        extrapolate: Extrapolate<T>,
+    ) -> Result<Self, ValidateError> {
+        let mut interp = Self {
+            points,
+            values,
+            kernel,
+            epsilon,
+            polynomial,
+            extrapolate,
+            coefficients: Array1::zeros(0),
+        };
+        interp.solve()?;
+        Ok(interp)
+    }
+
+    /// Validate inputs and solve for the kernel weights and polynomial coefficients.
+    fn solve(&mut self) -> Result<(), ValidateError> {
+        check_scattered(&self.points, &self.values, &self.extrapolate)?;
+        let (n, ndim) = self.points.dim();
+        if !(self.epsilon > T::zero() && self.epsilon.is_finite()) {
+            return Err(ValidateError::Other(format!(
+                "shape parameter must be positive and finite: epsilon = {:?}",
+                self.epsilon
+            )));
+        }
+        let size = if self.polynomial { n + ndim + 1 } else { n };
+        let mut a = Array2::zeros((size, size));
+        for (i, p_i) in self.points.outer_iter().enumerate() {
+            for (j, p_j) in self.points.outer_iter().enumerate() {
+                let r = distance(p_i, p_j);
+                a[[i, j]] = self.kernel.evaluate(r, self.epsilon);
+            }
+        }
+        if self.polynomial {
+            for (i, p_i) in self.points.outer_iter().enumerate() {
+                a[[i, n]] = T::one();
+                a[[n, i]] = T::one();
+                for (k, x) in p_i.iter().enumerate() {
+                    a[[i, n + 1 + k]] = *x;
+                    a[[n + 1 + k, i]] = *x;
+                }
+            }
+        }
+        let mut b = Array1::zeros(size);
+        b.slice_mut(s![..n]).assign(&self.values);
+        self.coefficients = solve_linear_system(a, b)
+            .filter(|c| c.iter().all(|c| c.is_finite()))
+            .ok_or_else(|| {
+                ValidateError::Other(
+                    "RBF weight system is singular, e.g. due to repeated sample points".into(),
+                )
+            })?;
+        Ok(())
+    }
+
+    /// Evaluate the weighted kernel sum at `point`, regardless of bounds.
+    fn evaluate(&self, point: &[T]) -> T {
+        let n = self.points.nrows();
+        let point_view = ArrayView1::from(point);
+        let mut sum = self
+            .points
+            .outer_iter()
+            .zip(&self.coefficients)
+            .fold(T::zero(), |sum, (p, w)| {
+                sum + *w * self.kernel.evaluate(distance(p, point_view), self.epsilon)
+            });
+        if self.polynomial {
+            sum = point
+                .iter()
+                .zip(self.coefficients.slice(s![n + 1..]))
+                .fold(sum + self.coefficients[n], |sum, (x, c)| sum + *c * *x);
+        }
+        sum
+    }
+}
+
+/// Check scattered sample points have one row per value, and the [`Extrapolate`] setting
+/// is applicable, i.e. one of `Enable`, `Fill`, `Clamp`, or `Error`.
+pub(crate) fn check_scattered<T: Debug>(
+    points: &Array2<T>,
+    values: &Array1<T>,
+    extrapolate: &Extrapolate<T>,
+) -> Result<(), ValidateError> {
+    let (n, ndim) = points.dim();
+    if n == 0 || ndim == 0 {
+        return Err(ValidateError::EmptyGrid(0));
+    }
+    if values.len() != n {
+        return Err(ValidateError::IncompatibleShapes(0));
+    }
+    check_scattered_extrapolate(extrapolate)
+}
+
+/// Check the [`Extrapolate`] setting is applicable to scattered data.
+pub(crate) fn check_scattered_extrapolate<T: Debug>(
+    extrapolate: &Extrapolate<T>,
+) -> Result<(), ValidateError> {
+    match extrapolate {
+        Extrapolate::Enable | Extrapolate::Fill(_) | Extrapolate::Clamp | Extrapolate::Error => {
+            Ok(())
+        }
+        _ => Err(ValidateError::ExtrapolateSelection(format!(
+            "{:?}",
+            extrapolate
+        ))),
+    }
+}
+
+/// Bounds of the bounding box of scattered sample points along axis `dim`.
+pub(crate) fn scattered_bounds<T: Float>(points: &Array2<T>, dim: usize) -> Option<(T, T)> {
+    if dim >= points.ncols() {
+        return None;
+    }
+    points
+        .column(dim)
+        .iter()
+        .fold(None, |bounds, &x| match bounds {
+            None => Some((x, x)),
+            Some((min, max)) => Some((min.min(x), max.max(x))),
+        })
+}
+
+/// Euclidean distance between `a` and `b`.
+pub(crate) fn distance<T: Float>(a: ArrayView1<T>, b: ArrayView1<T>) -> T {
+    a.iter()
+        .zip(b)
+        .fold(T::zero(), |sum, (a, b)| sum + (*a - *b) * (*a - *b))
+        .sqrt()
+}
+
+impl<T> Interpolator<T> for InterpRBF<T>
+where
+    T: Float + Debug,
+{
+    /// Number of columns of [`InterpRBF::points`].
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.points.ncols()
+    }
+
+    /// Validate and re-solve for the kernel weights, e.g. after modifying public fields.
+    fn validate(&mut self) -> Result<(), ValidateError> {
+        self.solve()
+    }
+
+    fn interpolate(&self, point: &[T]) -> Result<T, InterpolateError> {
+        if point.len() != self.ndim() {
+            return Err(InterpolateError::PointLength(self.ndim()));
+        }
+        if !self.contains(point) {
+            match &self.extrapolate {
+                Extrapolate::Enable => {}
+                Extrapolate::Fill(value) => return Ok(*value),
+                Extrapolate::Clamp => return self.interpolate_clamped(point),
+                _ => {
+                    return Err(InterpolateError::ExtrapolateError(format!(
+                        "\n    point = {point:?} is outside the bounding box of the sample points"
+                    )))
+                }
+            }
+        }
+        Ok(self.evaluate(point))
+    }
+
+    /// Clamps to the bounding box of the sample points.
+    fn interpolate_clamped(&self, point: &[T]) -> Result<T, InterpolateError> {
+        if point.len() != self.ndim() {
+            return Err(InterpolateError::PointLength(self.ndim()));
+        }
+        let clamped: Vec<T> = point
+            .iter()
+            .zip(self.bounds())
+            .map(|(x, (min, max))| clamp(*x, min, max))
+            .collect();
+        Ok(self.evaluate(&clamped))
+    }
+
+    fn set_extrapolate(&mut self, extrapolate: Extrapolate<T>) -> Result<(), ValidateError> {
+        check_scattered_extrapolate(&extrapolate)?;
+        self.extrapolate = extrapolate;
+        Ok(())
+    }
+
+    fn is_contiguous(&self) -> bool {
+        self.points.is_standard_layout() && self.values.is_standard_layout()
+    }
+
+    /// Bounds of the bounding box of the sample points.
+    fn domain_bounds(&self, dim: usize) -> Option<(T, T)> {
+        scattered_bounds(&self.points, dim)
+    }
+
+    /// Returns a view of the sample values.
+    fn values_view(&self) -> Option<ArrayViewD<'_, T>> {
+        Some(self.values.view().into_dyn())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scattered points filling the unit square, on a Fibonacci lattice.
+    fn scattered(n: usize) -> Array2<f64> {
+        let golden = (5f64.sqrt() - 1.) / 2.;
+        Array2::from_shape_fn((n, 2), |(i, j)| match j {
+            0 => (i as f64 + 0.5) / n as f64,
+            _ => (i as f64 * golden).fract(),
+        })
+    }
+
+    fn f(x: f64, y: f64) -> f64 {
+        (2. * x).sin() * (3. * y).cos()
+    }
+
+    #[test]
+    fn test_exact_at_samples() {
+        let points = scattered(30);
+        let values = points.map_axis(Axis(1), |p| f(p[0], p[1]));
+        for (kernel, epsilon, polynomial) in [
+            (RadialBasis::Gaussian, 3., false),
+            (RadialBasis::Gaussian, 3., true),
+            (RadialBasis::Multiquadric, 2., false),
+            (RadialBasis::ThinPlate, 1., true),
+        ] {
+            let interp = InterpRBF::new(
+                points.clone(),
+                values.clone(),
+                kernel,
+                epsilon,
+                polynomial,
+                Extrapolate::Error,
+            )
+            .unwrap();
+            for (p, v) in points.outer_iter().zip(&values) {
+                assert_approx_eq!(interp.interpolate(p.as_slice().unwrap()).unwrap(), *v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_smooth_between_samples() {
+        let points = scattered(40);
+        let values = points.map_axis(Axis(1), |p| f(p[0], p[1]));
+        for kernel in [RadialBasis::Multiquadric, RadialBasis::ThinPlate] {
+            let interp = InterpRBF::new(
+                points.clone(),
+                values.clone(),
+                kernel,
+                1.,
+                true,
+                Extrapolate::Error,
+            )
+            .unwrap();
+            // Close to the underlying function away from the samples
+            for x in [0.25, 0.5, 0.75] {
+                for y in [0.3, 0.5, 0.7] {
+                    assert_approx_eq!(interp.interpolate(&[x, y]).unwrap(), f(x, y), 0.05);
+                }
+            }
+            // No jumps along a line crossing between samples
+            let h = 1e-3;
+            let line: Vec<f64> = (0..=800)
+                .map(|i| interp.interpolate(&[0.1 + i as f64 * h, 0.45]).unwrap())
+                .collect();
+            for w in line.windows(2) {
+                assert!((w[1] - w[0]).abs() < 5. * h);
+            }
+        }
+    }
+
+    #[test]
+    fn test_polynomial_reproduction() {
+        let points = scattered(12);
+        let values = points.map_axis(Axis(1), |p| 1. + 2. * p[0] - 3. * p[1]);
+        let interp = InterpRBF::new(
+            points,
+            values,
+            RadialBasis::ThinPlate,
+            1.,
+            true,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        for point in [[0.2, 0.9], [0.5, 0.5], [1.5, -1.]] {
+            assert_approx_eq!(
+                interp.interpolate(&point).unwrap(),
+                1. + 2. * point[0] - 3. * point[1]
+            );
+        }
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        let interp = InterpRBF::new(
+            array![[0., 0.], [1., 0.], [0., 2.], [1., 2.]],
+            array![0., 1., 2., 3.],
+            RadialBasis::Multiquadric,
+            1.,
+            true,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert_eq!(interp.bounds(), vec![(0., 1.), (0., 2.)]);
+        assert!(interp.interpolate(&[1.5, 1.]).is_err());
+        assert!(matches!(
+            interp.interpolate(&[0.5]).unwrap_err(),
+            InterpolateError::PointLength(2)
+        ));
+        let clamped = interp.clone().with_extrapolate(Extrapolate::Clamp).unwrap();
+        assert_approx_eq!(
+            clamped.interpolate(&[1.5, 2.5]).unwrap(),
+            interp.interpolate(&[1., 2.]).unwrap()
+        );
+        let filled = interp
+            .clone()
+            .with_extrapolate(Extrapolate::Fill(-1.))
+            .unwrap();
+        assert_eq!(filled.interpolate(&[-1., 0.]).unwrap(), -1.);
+        assert!(matches!(
+            interp.clone().with_extrapolate(Extrapolate::Wrap),
+            Err(ValidateError::ExtrapolateSelection(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid() {
+        let new = |points, values, epsilon| {
+            InterpRBF::new(
+                points,
+                values,
+                RadialBasis::Gaussian,
+                epsilon,
+                false,
+                Extrapolate::Error,
+            )
+        };
+        assert!(matches!(
+            new(array![[0., 0.], [1., 1.]], array![0.], 1.),
+            Err(ValidateError::IncompatibleShapes(0))
+        ));
+        assert!(matches!(
+            new(Array2::zeros((0, 2)), array![], 1.),
+            Err(ValidateError::EmptyGrid(0))
+        ));
+        assert!(new(array![[0., 0.], [1., 1.]], array![0., 1.], 0.).is_err());
+        // Repeated sample points
+        assert!(new(array![[0., 0.], [0., 0.]], array![0., 1.], 1.).is_err());
+    }
+}
@@ -0,0 +1,145 @@
+//! Constructing [`Interp1D`]s from [Polars](https://docs.rs/polars) [`Series`].
+//!
+//! # Example:
+//! ```
+//! use ninterp::prelude::*;
+//! use ninterp::interpolator::series::Nulls;
+//! use polars::prelude::*;
+//! let x = Series::new("x".into(), [0., 1., 2.]);
+//! let f_x = Series::new("f_x".into(), [Some(0.), None, Some(4.)]);
+//! let interp = Interp1D::from_series(&x, &f_x, strategy::Linear, Extrapolate::Error, Nulls::Drop)
+//!     .unwrap();
+//! assert_eq!(interp.interpolate(&[1.]).unwrap(), 2.);
+//! ```
+
+use super::*;
+
+use polars::prelude::{Float64Chunked, Series};
+
+/// Handling of null entries in [`Series`] passed to [`Interp1D::from_series`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Nulls {
+    /// Error if either series contains nulls.
+    #[default]
+    Error,
+    /// Drop rows where either series is null.
+    Drop,
+}
+
+fn float64(series: &Series) -> Result<&Float64Chunked, ValidateError> {
+    series.f64().map_err(|e| {
+        ValidateError::Other(format!(
+            "series `{}` must be of dtype f64: {e}",
+            series.name()
+        ))
+    })
+}
+
+impl<S> Interp1DOwned<f64, S>
+where
+    S: Strategy1D<OwnedRepr<f64>> + Clone,
+{
+    /// Instantiate one-dimensional interpolator from `f64` [`Series`] of `x` and `f(x)`,
+    /// with nulls handled per [`Nulls`].
+    ///
+    /// Errors if either series is not of dtype `f64`, if the series differ in length,
+    /// for nulls with [`Nulls::Error`],
+    /// or for the same reasons as [`Interp1D::new`].
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn from_series(
+        x: &Series,
+        f_x: &Series,
+        strategy: S,
+        extrapolate: Extrapolate<f64>,
+        nulls: Nulls,
+    ) -> Result<Self, ValidateError> {
+        let (x, f_x) = (float64(x)?, float64(f_x)?);
+        if x.len() != f_x.len() {
+            return Err(ValidateError::IncompatibleShapes(0));
+        }
+        if nulls == Nulls::Error && (x.null_count() > 0 || f_x.null_count() > 0) {
+            return Err(ValidateError::Other(format!(
+                "series `{}` and `{}` must not contain nulls",
+                x.name(),
+                f_x.name()
+            )));
+        }
+        let (x, f_x): (Vec<f64>, Vec<f64>) = x
+            .iter()
+            .zip(f_x.iter())
+            .filter_map(|(x, f_x)| Some((x?, f_x?)))
+            .unzip();
+        Self::new(x.into(), f_x.into(), strategy, extrapolate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polars::prelude::NamedFrom;
+
+    #[test]
+    fn test_from_series() {
+        let x = Series::new("x".into(), [0., 1., 2., 3.]);
+        let f_x = Series::new("f_x".into(), [0., 1., 4., 9.]);
+        let interp =
+            Interp1D::from_series(&x, &f_x, strategy::Linear, Extrapolate::Error, Nulls::Error)
+                .unwrap();
+        let expected = Interp1D::new(
+            array![0., 1., 2., 3.],
+            array![0., 1., 4., 9.],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert_eq!(interp, expected);
+        for x in [0., 0.5, 1.25, 3.] {
+            assert_eq!(interp.interpolate(&[x]), expected.interpolate(&[x]));
+        }
+    }
+
+    #[test]
+    fn test_nulls() {
+        let x = Series::new("x".into(), [Some(0.), Some(1.), None, Some(3.)]);
+        let f_x = Series::new("f_x".into(), [Some(0.), None, Some(4.), Some(9.)]);
+        assert!(Interp1D::from_series(
+            &x,
+            &f_x,
+            strategy::Linear,
+            Extrapolate::Error,
+            Nulls::Error
+        )
+        .is_err());
+        let interp =
+            Interp1D::from_series(&x, &f_x, strategy::Linear, Extrapolate::Error, Nulls::Drop)
+                .unwrap();
+        assert_eq!(interp.data.grid[0], array![0., 3.]);
+        assert_eq!(interp.data.values, array![0., 9.]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let x = Series::new("x".into(), [0., 1., 2.]);
+        let ints = Series::new("f_x".into(), [0, 1, 2]);
+        let short = Series::new("f_x".into(), [0., 1.]);
+        assert!(Interp1D::from_series(
+            &x,
+            &ints,
+            strategy::Linear,
+            Extrapolate::Error,
+            Nulls::Error
+        )
+        .is_err());
+        assert_eq!(
+            Interp1D::from_series(
+                &x,
+                &short,
+                strategy::Linear,
+                Extrapolate::Error,
+                Nulls::Error
+            )
+            .unwrap_err(),
+            ValidateError::IncompatibleShapes(0)
+        );
+    }
+}
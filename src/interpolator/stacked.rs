@@ -0,0 +1,340 @@
+//! Aggregate interpolation across a stack of 1-D tables sharing a grid.
+
+use super::*;
+
+/// Aggregation applied across the members of a [`StackedInterp1D`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Aggregate<T> {
+    /// Arithmetic mean.
+    Mean,
+    /// Median, averaging the two middle values for an even number of members.
+    Median,
+    /// Minimum.
+    Min,
+    /// Maximum.
+    Max,
+    /// Weighted mean, with one finite weight per member. Weights are normalized by their sum,
+    /// which must be nonzero.
+    WeightedMean(Vec<T>),
+}
+
+/// A stack of 1-D interpolators over a shared grid, e.g. an ensemble of measured runs,
+/// whose results are aggregated per [`Aggregate`].
+///
+/// For points within the grid, members share a single bracket search
+/// (see [`Strategy1D::interpolate_bracketed`]).
+/// Out-of-bounds points are handled per each member's [`Extrapolate`] setting.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::stacked::{Aggregate, StackedInterp1D};
+/// let run = |f_x| {
+///     Interp1D::new(array![0., 1., 2.], f_x, strategy::Linear, Extrapolate::Error).unwrap()
+/// };
+/// let stacked = StackedInterp1D::new(
+///     vec![run(array![0., 1., 2.]), run(array![0., 3., 0.])],
+///     Aggregate::Max,
+/// )
+/// .unwrap();
+/// assert_eq!(stacked.interpolate(&[0.5]).unwrap(), 1.5);
+/// assert_eq!(stacked.interpolate(&[1.5]).unwrap(), 1.5);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct StackedInterp1D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+    S: Strategy1D<D> + Clone,
+{
+    /// Member interpolators.
+    pub members: Vec<Interp1D<D, S>>,
+    /// Aggregation across members.
+    pub aggregate: Aggregate<D::Elem>,
+}
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`
+impl<D, S> Clone for StackedInterp1D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug + Clone,
+    S: Strategy1D<D> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            members: self.members.clone(),
+            aggregate: self.aggregate.clone(),
+        }
+    }
+}
+
+impl<D, S> StackedInterp1D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+    S: Strategy1D<D> + Clone,
+{
+    /// Construct and validate a stacked interpolator.
+    ///
+    /// Errors if there are no members, if member grids differ,
+    /// if the number of [`Aggregate::WeightedMean`] weights does not match the number of members,
+    /// or if the weights are not finite or sum to zero.
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn new(
+        members: Vec<Interp1D<D, S>>,
+        aggregate: Aggregate<D::Elem>,
+    ) -> Result<Self, ValidateError> {
+        let stacked = Self { members, aggregate };
+        stacked.check_members()?;
+        Ok(stacked)
+    }
+
+    /// Check member count, shared grid, and weights.
+    fn check_members(&self) -> Result<(), ValidateError> {
+        let Some(first) = self.members.first() else {
+            return Err(ValidateError::Other(
+                "stacked interpolator requires at least one member".into(),
+            ));
+        };
+        if let Some(i) = self
+            .members
+            .iter()
+            .position(|member| !member.same_grid(first))
+        {
+            return Err(ValidateError::Other(format!(
+                "grid of stacked member {i} does not match grid of member 0"
+            )));
+        }
+        if let Aggregate::WeightedMean(weights) = &self.aggregate {
+            if weights.len() != self.members.len() {
+                return Err(ValidateError::Other(format!(
+                    "number of weights {} does not match number of members {}",
+                    weights.len(),
+                    self.members.len()
+                )));
+            }
+            if let Some(w) = weights.iter().find(|&&w| !is_finite(w)) {
+                return Err(ValidateError::Other(format!(
+                    "weights must be finite, got {w:?}"
+                )));
+            }
+            let total = weights.iter().fold(D::Elem::zero(), |total, &w| total + w);
+            if total.is_zero() {
+                return Err(ValidateError::Other(format!(
+                    "weights must not sum to zero: {weights:?}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Interpolate each member at a point within the shared grid,
+    /// with a single bracket search shared across members.
+    fn interpolate_members(&self, point: &[D::Elem; 1]) -> Result<Vec<D::Elem>, InterpolateError> {
+        let bracket = find_bracket(&self.members[0].data, &point[0])?;
+        self.members
+            .iter()
+            .map(|member| {
+                if !member.strategy.is_initialized() {
+                    return Err(InterpolateError::Uninitialized);
+                }
+                member
+                    .strategy
+                    .interpolate_bracketed(&member.data, point, bracket)
+            })
+            .collect()
+    }
+}
+
+impl<D, S> StackedInterp1D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Debug + Copy,
+    S: Strategy1D<D> + Clone,
+{
+    /// Aggregate member results.
+    fn aggregate(&self, mut results: Vec<D::Elem>) -> D::Elem {
+        match &self.aggregate {
+            Aggregate::Mean => {
+                let (sum, count) = results
+                    .iter()
+                    .fold((D::Elem::zero(), D::Elem::zero()), |(sum, count), &r| {
+                        (sum + r, count + D::Elem::one())
+                    });
+                sum / count
+            }
+            Aggregate::Median => {
+                // Propagate unordered results (e.g. NaN), as the other aggregates do
+                if let Some(&r) = results.iter().find(|r| r.partial_cmp(r).is_none()) {
+                    return r;
+                }
+                results.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let mid = results.len() / 2;
+                if results.len() % 2 == 0 {
+                    (results[mid - 1] + results[mid]) / (D::Elem::one() + D::Elem::one())
+                } else {
+                    results[mid]
+                }
+            }
+            Aggregate::Min => results
+                .into_iter()
+                .reduce(|a, b| if b < a { b } else { a })
+                .unwrap(),
+            Aggregate::Max => results
+                .into_iter()
+                .reduce(|a, b| if b > a { b } else { a })
+                .unwrap(),
+            Aggregate::WeightedMean(weights) => {
+                let (sum, total) = results.iter().zip(weights).fold(
+                    (D::Elem::zero(), D::Elem::zero()),
+                    |(sum, total), (&r, &w)| (sum + w * r, total + w),
+                );
+                sum / total
+            }
+        }
+    }
+}
+
+impl<D, S> Interpolator<D::Elem> for StackedInterp1D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
+    S: Strategy1D<D> + Clone,
+{
+    /// Returns `1`.
+    #[inline]
+    fn ndim(&self) -> usize {
+        1
+    }
+
+    /// Validate each member, and the member count, shared grid, and weights.
+    fn validate(&mut self) -> Result<(), ValidateError> {
+        for member in &mut self.members {
+            member.validate()?;
+        }
+        self.check_members()
+    }
+
+    fn interpolate(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let point: &[D::Elem; 1] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(1))?;
+        let results = if self.contains(point) {
+            self.interpolate_members(point)?
+        } else {
+            self.members
+                .iter()
+                .map(|member| member.interpolate(point))
+                .collect::<Result<_, _>>()?
+        };
+        Ok(self.aggregate(results))
+    }
+
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let point: &[D::Elem; 1] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(1))?;
+        let grid = &self.members[0].data.grid[0];
+        let clamped = [*clamp(
+            &point[0],
+            grid.first().unwrap(),
+            grid.last().unwrap(),
+        )];
+        Ok(self.aggregate(self.interpolate_members(&clamped)?))
+    }
+
+    /// Set [`Extrapolate`] variant of every member.
+    fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
+        extrapolate.check_limit()?;
+        for member in &self.members {
+            member.check_extrapolate(&extrapolate)?;
+        }
+        for member in &mut self.members {
+            member.extrapolate = extrapolate;
+        }
+        Ok(())
+    }
+
+    fn is_contiguous(&self) -> bool {
+        self.members.iter().all(|member| member.is_contiguous())
+    }
+
+    /// Bounds of the shared grid.
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        self.members.first()?.domain_bounds(dim)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(f_x: Array1<f64>) -> Interp1DOwned<f64, strategy::Linear> {
+        Interp1D::new(
+            array![0., 1., 2., 3.],
+            f_x,
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let a = member(array![0., 1., 4., 9.]);
+        let b = member(array![3., 2., 1., 0.]);
+        let mean = StackedInterp1D::new(vec![a.clone(), b.clone()], Aggregate::Mean).unwrap();
+        let max = StackedInterp1D::new(vec![a.clone(), b.clone()], Aggregate::Max).unwrap();
+        let min = StackedInterp1D::new(vec![a.clone(), b.clone()], Aggregate::Min).unwrap();
+        for x in [0., 0.5, 1., 1.25, 2., 2.5, 3.] {
+            let (f_a, f_b) = (a.interpolate(&[x]).unwrap(), b.interpolate(&[x]).unwrap());
+            assert_eq!(mean.interpolate(&[x]).unwrap(), (f_a + f_b) / 2.);
+            assert_eq!(max.interpolate(&[x]).unwrap(), f_a.max(f_b));
+            assert_eq!(min.interpolate(&[x]).unwrap(), f_a.min(f_b));
+        }
+        let c = member(array![1., 1., 1., 1.]);
+        let median =
+            StackedInterp1D::new(vec![a.clone(), b.clone(), c.clone()], Aggregate::Median).unwrap();
+        assert_eq!(median.interpolate(&[0.]).unwrap(), 1.);
+        assert_eq!(median.interpolate(&[3.]).unwrap(), 1.);
+        let nan = member(array![f64::NAN, 1., 1., 1.]);
+        let median =
+            StackedInterp1D::new(vec![a.clone(), b.clone(), nan], Aggregate::Median).unwrap();
+        assert!(median.interpolate(&[0.]).unwrap().is_nan());
+        assert_eq!(median.interpolate(&[1.]).unwrap(), 1.);
+        let weighted =
+            StackedInterp1D::new(vec![a, b], Aggregate::WeightedMean(vec![3., 1.])).unwrap();
+        assert_eq!(weighted.interpolate(&[0.]).unwrap(), 0.75);
+        assert!(weighted.interpolate(&[4.]).is_err());
+    }
+
+    #[test]
+    fn test_invalid() {
+        let a = member(array![0., 1., 4., 9.]);
+        let shifted = Interp1D::new(
+            array![1., 2., 3., 4.],
+            array![0., 1., 4., 9.],
+            strategy::Linear,
+            Extrapolate::Error,
+        )
+        .unwrap();
+        assert!(
+            StackedInterp1D::<OwnedRepr<f64>, strategy::Linear>::new(vec![], Aggregate::Mean)
+                .is_err()
+        );
+        assert!(StackedInterp1D::new(vec![a.clone(), shifted], Aggregate::Mean).is_err());
+        assert!(
+            StackedInterp1D::new(vec![a.clone()], Aggregate::WeightedMean(vec![1., 2.])).is_err()
+        );
+        let b = member(array![3., 2., 1., 0.]);
+        for weights in [vec![1., -1.], vec![f64::NAN, 1.], vec![f64::INFINITY, 1.]] {
+            assert!(StackedInterp1D::new(
+                vec![a.clone(), b.clone()],
+                Aggregate::WeightedMean(weights)
+            )
+            .is_err());
+        }
+    }
+}
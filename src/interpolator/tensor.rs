@@ -0,0 +1,207 @@
+//! Separable N-D interpolation from 1-D marginal interpolators.
+
+use super::*;
+
+/// An N-D interpolator whose value is `combine` of per-axis 1-D interpolations,
+/// i.e. `f(x_0, ..., x_n) = combine(&[f_0(x_0), ..., f_n(x_n)])`.
+///
+/// For separable functions this avoids materializing the full N-D values array,
+/// which grows with the product of the axis lengths rather than their sum.
+/// With [`strategy::Linear`] axes, a product `combine` reproduces an [`InterpND`]
+/// with [`strategy::Linear`] over the equivalent dense values.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::tensor::TensorProduct;
+/// let ramp = |f_x| {
+///     Interp1D::new(array![0., 1.], f_x, strategy::Linear, Extrapolate::Error).unwrap()
+/// };
+/// // f(x, y) = (1 + x) * 2y
+/// let interp = TensorProduct::new(
+///     vec![ramp(array![1., 2.]), ramp(array![0., 2.])],
+///     |values| values.iter().product(),
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[0.5, 0.5]).unwrap(), 1.5);
+/// ```
+#[derive(Debug)]
+pub struct TensorProduct<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+    S: Strategy1D<D> + Clone,
+{
+    /// Per-axis 1-D interpolators.
+    pub axes: Vec<Interp1D<D, S>>,
+    /// Combination of per-axis results, in axis order.
+    pub combine: fn(&[D::Elem]) -> D::Elem,
+}
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`
+impl<D, S> Clone for TensorProduct<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug + Clone,
+    S: Strategy1D<D> + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            axes: self.axes.clone(),
+            combine: self.combine,
+        }
+    }
+}
+
+impl<D, S> TensorProduct<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: PartialEq + Debug,
+    S: Strategy1D<D> + Clone,
+{
+    /// Construct a tensor-product interpolator from per-axis 1-D interpolators.
+    ///
+    /// Errors if there are no axes.
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn new(
+        axes: Vec<Interp1D<D, S>>,
+        combine: fn(&[D::Elem]) -> D::Elem,
+    ) -> Result<Self, ValidateError> {
+        if axes.is_empty() {
+            return Err(ValidateError::Other(
+                "tensor product requires at least one axis".into(),
+            ));
+        }
+        Ok(Self { axes, combine })
+    }
+}
+
+impl<D, S> Interpolator<D::Elem> for TensorProduct<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
+    S: Strategy1D<D> + Clone,
+{
+    /// Returns the number of axes.
+    #[inline]
+    fn ndim(&self) -> usize {
+        self.axes.len()
+    }
+
+    /// Validate each axis interpolator.
+    fn validate(&mut self) -> Result<(), ValidateError> {
+        if self.axes.is_empty() {
+            return Err(ValidateError::Other(
+                "tensor product requires at least one axis".into(),
+            ));
+        }
+        for axis in &mut self.axes {
+            axis.validate()?;
+        }
+        Ok(())
+    }
+
+    fn interpolate(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let n = self.ndim();
+        if point.len() != n {
+            return Err(InterpolateError::PointLength(n));
+        }
+        let values = self
+            .axes
+            .iter()
+            .zip(point)
+            .map(|(axis, &pt)| axis.interpolate(&[pt]))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((self.combine)(&values))
+    }
+
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let n = self.ndim();
+        if point.len() != n {
+            return Err(InterpolateError::PointLength(n));
+        }
+        let values = self
+            .axes
+            .iter()
+            .zip(point)
+            .map(|(axis, &pt)| axis.interpolate_clamped(&[pt]))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((self.combine)(&values))
+    }
+
+    /// Set [`Extrapolate`] variant of every axis.
+    fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
+        extrapolate.check_limit()?;
+        for axis in &self.axes {
+            axis.check_extrapolate(&extrapolate)?;
+        }
+        for axis in &mut self.axes {
+            axis.extrapolate = extrapolate;
+        }
+        Ok(())
+    }
+
+    fn is_contiguous(&self) -> bool {
+        self.axes.iter().all(|axis| axis.is_contiguous())
+    }
+
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        self.axes.get(dim)?.domain_bounds(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dense_equivalence() {
+        let grid = vec![array![0., 1., 3.], array![-1., 0., 0.5, 2.], array![1., 2.]];
+        let ramps = vec![
+            array![1., 2., 4.],
+            array![0.5, 1., 1.25, 2.],
+            array![3., 1.],
+        ];
+        let axes = grid
+            .iter()
+            .zip(&ramps)
+            .map(|(x, f_x)| {
+                Interp1D::new(
+                    x.clone(),
+                    f_x.clone(),
+                    strategy::Linear,
+                    Extrapolate::Enable,
+                )
+                .unwrap()
+            })
+            .collect();
+        let product = TensorProduct::new(axes, |values| values.iter().product()).unwrap();
+        let dense = InterpND::new(
+            grid,
+            Array3::from_shape_fn((3, 4, 2), |(i, j, k)| {
+                ramps[0][i] * ramps[1][j] * ramps[2][k]
+            })
+            .into_dyn(),
+            strategy::Linear,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        for point in [
+            [0., -1., 1.],
+            [0.5, 0.25, 1.5],
+            [2.9, 1.7, 1.1],
+            [3., 2., 2.],
+            [-0.5, 2.5, 2.25],
+        ] {
+            assert_approx_eq!(
+                product.interpolate(&point).unwrap(),
+                dense.interpolate(&point).unwrap()
+            );
+        }
+        assert!(matches!(
+            product.interpolate(&[0., 0.]).unwrap_err(),
+            InterpolateError::PointLength(3)
+        ));
+    }
+}
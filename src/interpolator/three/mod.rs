@@ -14,30 +14,38 @@ pub type InterpData3D<D> = InterpData<D, N>;
 pub type InterpData3DViewed<T> = InterpData3D<ViewRepr<T>>;
 /// [`InterpData3D`] that owns data.
 pub type InterpData3DOwned<T> = InterpData3D<OwnedRepr<T>>;
+/// [`InterpData3D`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type InterpData3DCow<'a, T> = InterpData3D<CowRepr<'a, T>>;
 
 impl<D> InterpData3D<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialOrd + Debug,
 {
     /// Construct and validate a new [`InterpData3D`].
+    ///
+    /// Strictly decreasing grid axes are accepted, and reversed along with the corresponding
+    /// axis of the values (by negating strides, without copying),
+    /// so that the stored grid is always increasing.
+    /// Arrays supplied later, e.g. to `set_values`, must follow the stored, increasing order.
     pub fn new(
         x: ArrayBase<D, Ix1>,
         y: ArrayBase<D, Ix1>,
         z: ArrayBase<D, Ix1>,
         f_xyz: ArrayBase<D, Ix3>,
     ) -> Result<Self, ValidateError> {
-        let data = Self {
+        let mut data = Self {
             grid: [x, y, z],
             values: f_xyz,
         };
+        orient_ascending(&mut data.grid, &mut data.values);
         data.validate()?;
         Ok(data)
     }
 }
 
 /// 3-D interpolator
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(
     feature = "serde",
@@ -55,7 +63,7 @@ where
 )]
 pub struct Interp3D<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
     S: Strategy3D<D> + Clone,
 {
@@ -71,14 +79,20 @@ where
 pub type Interp3DViewed<T, S> = Interp3D<ViewRepr<T>, S>;
 /// [`Interp3D`] that owns data.
 pub type Interp3DOwned<T, S> = Interp3D<OwnedRepr<T>, S>;
+/// [`Interp3D`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type Interp3DCow<'a, T, S> = Interp3D<CowRepr<'a, T>, S>;
 
 extrapolate_impl!(Interp3D, Strategy3D);
 partialeq_impl!(Interp3D, InterpData3D, Strategy3D);
+clone_impl!(Interp3D, Strategy3D);
+contiguous_impl!(Interp3D, Strategy3D);
+set_values_impl!(Interp3D, Strategy3D, Ix3);
+index_impl!(Interp3D, Strategy3D);
 
 impl<D, S> Interp3D<D, S>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialOrd + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
     S: Strategy3D<D> + Clone,
 {
     /// Construct and validate a 3-D interpolator.
@@ -126,6 +140,7 @@ where
     ///     ninterp::error::InterpolateError::ExtrapolateError(_)
     /// ));
     /// ```
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new(
         x: ArrayBase<D, Ix1>,
         y: ArrayBase<D, Ix1>,
@@ -144,6 +159,27 @@ where
         Ok(interpolator)
     }
 
+    /// Does `other` have an identical grid, e.g. to combine their values?
+    /// See [`InterpData::same_grid`].
+    pub fn same_grid<D2, S2>(&self, other: &Interp3D<D2, S2>) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        S2: Strategy3D<D2> + Clone,
+    {
+        self.data.same_grid(&other.data)
+    }
+
+    /// Does `other` have an identical grid, to within `epsilon`?
+    /// See [`InterpData::same_grid_approx`].
+    pub fn same_grid_approx<D2, S2>(&self, other: &Interp3D<D2, S2>, epsilon: D::Elem) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        D::Elem: Num + Copy,
+        S2: Strategy3D<D2> + Clone,
+    {
+        self.data.same_grid_approx(&other.data, epsilon)
+    }
+
     /// Return an interpolator with viewed data.
     pub fn view(&self) -> Interp3DViewed<&D::Elem, S>
     where
@@ -153,7 +189,7 @@ where
         Interp3DViewed {
             data: self.data.view(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
         }
     }
 
@@ -166,14 +202,38 @@ where
         Interp3DOwned {
             data: self.data.into_owned(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
         }
     }
 }
 
+impl<T, S> Interp3DOwned<T, S>
+where
+    T: Num + PartialOrd + Copy + Debug,
+    S: Strategy3D<OwnedRepr<T>> + Clone,
+{
+    /// Instantiate three-dimensional interpolator by evaluating `f` at each point `[x, y, z]` of the grid.
+    ///
+    /// Errors for the same reasons as [`Interp3D::new`].
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn from_fn(
+        x: Array1<T>,
+        y: Array1<T>,
+        z: Array1<T>,
+        f: impl Fn(&[T]) -> T,
+        strategy: S,
+        extrapolate: Extrapolate<T>,
+    ) -> Result<Self, ValidateError> {
+        let values = Array3::from_shape_fn((x.len(), y.len(), z.len()), |(i, j, k)| {
+            f(&[x[i], y[j], z[k]])
+        });
+        Self::new(x, y, z, values, strategy, extrapolate)
+    }
+}
+
 impl<D, S> Interpolator<D::Elem> for Interp3D<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
     S: Strategy3D<D> + Clone,
 {
@@ -194,6 +254,9 @@ where
         let point: &[D::Elem; N] = point
             .try_into()
             .map_err(|_| InterpolateError::PointLength(N))?;
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
         let mut errors = Vec::new();
         for dim in 0..N {
             if !(self.data.grid[dim].first().unwrap()..=self.data.grid[dim].last().unwrap())
@@ -201,7 +264,29 @@ where
             {
                 match &self.extrapolate {
                     Extrapolate::Enable => {}
+                    Extrapolate::EnableBounded(limit) => {
+                        let grid = &self.data.grid[dim];
+                        let (min, max) = (grid.first().unwrap(), grid.last().unwrap());
+                        if beyond_limit(point[dim], *min, *max, *limit) {
+                            errors.push(format!(
+                                "\n    point[{dim}] = {:?} is more than {limit:?} beyond grid[{dim}] = {grid:?}",
+                                point[dim],
+                            ));
+                        }
+                    }
                     Extrapolate::Fill(value) => return Ok(*value),
+                    Extrapolate::SaturatingFill { .. } => {
+                        return Err(InterpolateError::Other(format!(
+                            "{:?} is only applicable for 1-D interpolators",
+                            self.extrapolate
+                        )))
+                    }
+                    Extrapolate::NearestValue => {
+                        let index: [usize; N] = std::array::from_fn(|i| {
+                            nearest_index(self.data.grid[i].view(), point[i])
+                        });
+                        return Ok(self.data.values[index]);
+                    }
                     Extrapolate::Clamp => {
                         let clamped_point = std::array::from_fn(|i| {
                             *clamp(
@@ -214,11 +299,15 @@ where
                     }
                     Extrapolate::Wrap => {
                         let wrapped_point = std::array::from_fn(|i| {
-                            wrap(
-                                point[i],
-                                *self.data.grid[i].first().unwrap(),
-                                *self.data.grid[i].last().unwrap(),
-                            )
+                            let min = self.data.grid[i].first().unwrap();
+                            let max = self.data.grid[i].last().unwrap();
+                            // Leave in-bounds coordinates untouched,
+                            // as wrapping maps a coordinate exactly at `max` to `min`
+                            if (min..=max).contains(&&point[i]) {
+                                point[i]
+                            } else {
+                                wrap(point[i], *min, *max)
+                            }
                         });
                         return self.strategy.interpolate(&self.data, &wrapped_point);
                     }
@@ -237,19 +326,113 @@ where
         self.strategy.interpolate(&self.data, point)
     }
 
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let point: &[D::Elem; N] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(N))?;
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let clamped_point = std::array::from_fn(|i| {
+            *clamp(
+                &point[i],
+                self.data.grid[i].first().unwrap(),
+                self.data.grid[i].last().unwrap(),
+            )
+        });
+        self.strategy.interpolate(&self.data, &clamped_point)
+    }
+
     fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
         self.check_extrapolate(&extrapolate)?;
         self.extrapolate = extrapolate;
         Ok(())
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.data.is_contiguous()
+    }
+
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        if dim >= self.ndim() {
+            return None;
+        }
+        let grid = &self.data.grid[dim];
+        Some((*grid.first().unwrap(), *grid.last().unwrap()))
+    }
+
+    #[inline]
+    fn grid(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        self.data.grid.get(dim).map(|grid| grid.view())
+    }
+
+    #[inline]
+    fn values_view(&self) -> Option<ArrayViewD<'_, D::Elem>> {
+        Some(self.data.values.view().into_dyn())
+    }
+
+    /// Returns `Some` if the strategy is a [`Strategy3DEnum`](strategy::enums::Strategy3DEnum)
+    /// or one of its variants' strategies, converting it to the enum.
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<D::Elem>>>
+    where
+        D::Elem: 'static,
+    {
+        let strategy = strategy::enums::Strategy3DEnum::from_any(self.strategy.as_any()?)?;
+        Some(Box::new(Interp3D {
+            data: self.data.view().into_owned(),
+            strategy,
+            extrapolate: self.extrapolate,
+        }))
+    }
+}
+
+impl<D> Interp3D<D, strategy::Linear>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Copy + Debug,
+{
+    /// Analytic gradient `[df/dx, df/dy, df/dz]` of the trilinear interpolant at `point`.
+    ///
+    /// Each partial derivative is the slope along its axis of the enclosing cell,
+    /// varying linearly with the position along the other axes.
+    /// At interior grid planes the gradient is discontinuous,
+    /// and the cell below the plane is used (except at the upper grid edge).
+    /// Out-of-bounds points follow the [`Extrapolate`] setting, as for [`Interp2D::gradient`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// // f(x, y, z) = x * y + z
+    /// let interp = Interp3D::new(
+    ///     array![0., 2.],
+    ///     array![0., 2.],
+    ///     array![0., 1.],
+    ///     array![[[0., 1.], [0., 1.]], [[0., 1.], [4., 5.]]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.gradient(&[0.5, 1.5, 0.5]).unwrap(), vec![1.5, 0.5, 1.]);
+    /// ```
+    pub fn gradient(&self, point: &[D::Elem]) -> Result<Vec<D::Elem>, InterpolateError> {
+        multilinear_gradient(
+            &self.data.grid,
+            self.data.values.view().into_dyn(),
+            point,
+            &self.extrapolate,
+        )
+    }
 }
 
 impl<D> Interp3D<D, Box<dyn Strategy3D<D>>>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialEq + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(&mut self, strategy: Box<dyn Strategy3D<D>>) -> Result<(), ValidateError> {
         self.strategy = strategy;
         self.check_extrapolate(&self.extrapolate)
@@ -258,10 +441,11 @@ where
 
 impl<D> Interp3D<D, strategy::enums::Strategy3DEnum>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(
         &mut self,
         strategy: impl Into<strategy::enums::Strategy3DEnum>,
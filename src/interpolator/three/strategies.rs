@@ -3,7 +3,7 @@ use strategy::*;
 
 impl<D> Strategy3D<D> for Linear
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -11,6 +11,7 @@ where
         data: &InterpData3D<D>,
         point: &[D::Elem; 3],
     ) -> Result<D::Elem, InterpolateError> {
+        check_brackets(&data.grid)?;
         // Extrapolation is checked previously in Interpolator::interpolate,
         // meaning by now, point is within grid bounds or extrapolation is enabled
         let lowers: [usize; 3] = std::array::from_fn(|dim| {
@@ -22,6 +23,8 @@ where
                 find_nearest_index(data.grid[dim].view(), &point[dim])
             }
         });
+        // Fully unrolled trilinear interpolation,
+        // avoiding the generic N-D reduction used by `StrategyND` for `Linear`
         let x_l = lowers[0];
         let x_u = x_l + 1;
         let x_diff = (point[0] - data.grid[0][x_l]) / (data.grid[0][x_u] - data.grid[0][x_l]);
@@ -53,11 +56,15 @@ where
     fn allow_extrapolate(&self) -> bool {
         true
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
 }
 
 impl<D> Strategy3D<D> for Nearest
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -65,6 +72,7 @@ where
         data: &InterpData3D<D>,
         point: &[D::Elem; 3],
     ) -> Result<D::Elem, InterpolateError> {
+        check_brackets(&data.grid)?;
         // x
         let x_l = find_nearest_index(data.grid[0].view(), &point[0]);
         let x_u = x_l + 1;
@@ -97,4 +105,8 @@ where
     fn allow_extrapolate(&self) -> bool {
         false
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
 }
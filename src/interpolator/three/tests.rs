@@ -78,6 +78,48 @@ fn test_linear_extrapolation() {
     assert_approx_eq!(interp.interpolate(&[0.19, 0.36, 0.65]).unwrap(), 35.25);
 }
 
+#[test]
+fn test_linear_matches_nd() {
+    let x = array![0.05, 0.10, 0.15, 0.30];
+    let y = array![0.10, 0.20, 0.30];
+    let z = array![0.20, 0.40, 0.60, 0.70, 1.00];
+    let f_xyz = Array3::from_shape_fn((4, 3, 5), |(i, j, k)| {
+        ((i * 7 + j * 3 + k * 5) % 11) as f64 / 3. - 1.
+    });
+    let interp_3d = Interp3D::new(
+        x.view(),
+        y.view(),
+        z.view(),
+        f_xyz.view(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let interp_nd = InterpND::new(
+        vec![x.view(), y.view(), z.view()],
+        f_xyz.view().into_dyn(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // Specialized trilinear path gives identical results to the N-D path
+    for i in 0..15 {
+        for j in 0..10 {
+            for k in 0..40 {
+                let point = [
+                    0.05 + 0.25 * i as f64 / 15.,
+                    0.10 + 0.20 * j as f64 / 10.,
+                    0.20 + 0.80 * k as f64 / 40.,
+                ];
+                assert_eq!(
+                    interp_3d.interpolate(&point).unwrap(),
+                    interp_nd.interpolate(&point).unwrap()
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn test_linear_offset() {
     let interp = Interp3D::new(
@@ -199,6 +241,46 @@ fn test_extrapolate_clamp() {
     assert_eq!(interp.interpolate(&[2., 2., 2.]).unwrap(), 7.);
 }
 
+#[test]
+fn test_index() {
+    let interp = Interp3D::new(
+        array![0.05, 0.10, 0.15],
+        array![0.10, 0.20, 0.30],
+        array![0.20, 0.40, 0.60],
+        array![
+            [[0., 1., 2.], [3., 4., 5.], [6., 7., 8.]],
+            [[9., 10., 11.], [12., 13., 14.], [15., 16., 17.]],
+            [[18., 19., 20.], [21., 22., 23.], [24., 25., 26.],],
+        ],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp[[0, 0, 0]], 0.);
+    assert_eq!(interp[[1, 2, 0]], 15.);
+    assert_eq!(interp[[2, 1, 2]], 23.);
+}
+
+#[test]
+fn test_domain_volume() {
+    let interp = Interp3D::new(
+        array![1., 3.],
+        array![-1., 0., 2.],
+        array![0.5, 4.5],
+        Array3::zeros((2, 3, 2)),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp.domain_span(0), Some(2.));
+    assert_eq!(interp.domain_span(1), Some(3.));
+    assert_eq!(interp.domain_span(2), Some(4.));
+    assert_eq!(interp.domain_span(3), None);
+    assert_eq!(interp.domain_volume(), 24.);
+    let boxed: Box<dyn Interpolator<f64>> = Box::new(interp);
+    assert_eq!(boxed.domain_volume(), 24.);
+}
+
 #[test]
 fn test_partialeq() {
     #[derive(PartialEq)]
@@ -227,3 +309,61 @@ fn test_serde() {
     let de: Interp3DOwned<f64, strategy::Nearest> = serde_json::from_str(&ser).unwrap();
     assert_eq!(interp, de);
 }
+
+#[test]
+fn test_gradient_plane() {
+    let (a, b, c, d) = (1.5, -0.25, 2., 3.);
+    let x = array![0., 1., 3.];
+    let y = array![-1., 0., 2.];
+    let z = array![0., 0.5, 1., 4.];
+    let f_xyz = Array3::from_shape_fn((3, 3, 4), |(i, j, k)| a * x[i] + b * y[j] + c * z[k] + d);
+    let mut interp = Interp3D::new(x, y, z, f_xyz, strategy::Linear, Extrapolate::Error).unwrap();
+    for point in [
+        [0., -1., 0.],
+        [0.5, 0.5, 0.7],
+        [1., 0., 1.],
+        [2.9, 1.9, 3.5],
+        [3., 2., 4.],
+    ] {
+        let gradient = interp.gradient(&point).unwrap();
+        assert_approx_eq!(gradient[0], a);
+        assert_approx_eq!(gradient[1], b);
+        assert_approx_eq!(gradient[2], c);
+    }
+    assert!(matches!(
+        interp.gradient(&[0.5, 0.5, 5.]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    interp.set_extrapolate(Extrapolate::Enable).unwrap();
+    assert_approx_eq!(interp.gradient(&[0.5, 0.5, 5.]).unwrap()[2], c);
+    interp.set_extrapolate(Extrapolate::Clamp).unwrap();
+    assert_eq!(interp.gradient(&[0.5, 0.5, 5.]).unwrap()[2], 0.);
+}
+
+#[test]
+fn test_from_fn() {
+    let x = array![0., 1., 2.5];
+    let y = array![-1., 0.];
+    let z = array![0., 0.5, 1., 4.];
+    let f = |p: &[f64]| p[0] * p[0] + p[1] * p[2];
+    let interp = Interp3D::from_fn(
+        x.clone(),
+        y.clone(),
+        z.clone(),
+        f,
+        strategy::Nearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    for x_i in x.iter() {
+        for y_j in y.iter() {
+            for z_k in z.iter() {
+                assert_eq!(
+                    interp.interpolate(&[*x_i, *y_j, *z_k]).unwrap(),
+                    f(&[*x_i, *y_j, *z_k])
+                );
+            }
+        }
+    }
+    assert_eq!(interp.data.values.dim(), (3, 2, 4));
+}
@@ -1,6 +1,7 @@
 //! 2-dimensional interpolation
 
 use super::*;
+use pipeline::Pipeline;
 
 mod strategies;
 #[cfg(test)]
@@ -14,29 +15,37 @@ pub type InterpData2D<D> = InterpData<D, N>;
 pub type InterpData2DViewed<T> = InterpData2D<ViewRepr<T>>;
 /// [`InterpData2D`] that owns data.
 pub type InterpData2DOwned<T> = InterpData2D<OwnedRepr<T>>;
+/// [`InterpData2D`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type InterpData2DCow<'a, T> = InterpData2D<CowRepr<'a, T>>;
 
 impl<D> InterpData2D<D>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialOrd + Debug,
 {
     /// Construct and validate a new [`InterpData2D`].
+    ///
+    /// Strictly decreasing grid axes are accepted, and reversed along with the corresponding
+    /// axis of the values (by negating strides, without copying),
+    /// so that the stored grid is always increasing.
+    /// Arrays supplied later, e.g. to `set_values`, must follow the stored, increasing order.
     pub fn new(
         x: ArrayBase<D, Ix1>,
         y: ArrayBase<D, Ix1>,
         f_xy: ArrayBase<D, Ix2>,
     ) -> Result<Self, ValidateError> {
-        let data = Self {
+        let mut data = Self {
             grid: [x, y],
             values: f_xy,
         };
+        orient_ascending(&mut data.grid, &mut data.values);
         data.validate()?;
         Ok(data)
     }
 }
 
 /// 2-D interpolator
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 #[cfg_attr(
     feature = "serde",
@@ -54,7 +63,7 @@ where
 )]
 pub struct Interp2D<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
     S: Strategy2D<D> + Clone,
 {
@@ -70,14 +79,22 @@ where
 pub type Interp2DViewed<T, S> = Interp2D<ViewRepr<T>, S>;
 /// [`Interp2D`] that owns data.
 pub type Interp2DOwned<T, S> = Interp2D<OwnedRepr<T>, S>;
+/// [`Interp2D`] that holds either owned or borrowed data, see [`CowRepr`].
+pub type Interp2DCow<'a, T, S> = Interp2D<CowRepr<'a, T>, S>;
+/// [`Pipeline`] wrapping an [`Interp2DOwned`], see [`Interp2D::with_angular_axis`].
+pub type Interp2DPipeline<T, S> = Pipeline<T, Interp2DOwned<T, S>>;
 
 extrapolate_impl!(Interp2D, Strategy2D);
 partialeq_impl!(Interp2D, InterpData2D, Strategy2D);
+clone_impl!(Interp2D, Strategy2D);
+contiguous_impl!(Interp2D, Strategy2D);
+set_values_impl!(Interp2D, Strategy2D, Ix2);
+index_impl!(Interp2D, Strategy2D);
 
 impl<D, S> Interp2D<D, S>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialOrd + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
     S: Strategy2D<D> + Clone,
 {
     /// Construct and validate a 2-D interpolator.
@@ -85,8 +102,9 @@ where
     /// Applicable interpolation strategies:
     /// - [`strategy::Linear`]
     /// - [`strategy::Nearest`]
+    /// - [`strategy::Bicubic`], optionally periodic along one axis
     ///
-    /// [`Extrapolate::Enable`] is valid for [`strategy::Linear`]
+    /// [`Extrapolate::Enable`] is valid for [`strategy::Linear`] and [`strategy::Bicubic`]
     ///
     /// # Example:
     /// ```
@@ -115,6 +133,7 @@ where
     ///     interp.interpolate(&[0., 3.]).unwrap()
     /// ); // point is restricted to within grid bounds
     /// ```
+    #[must_use = "construction can fail validation, which should be handled"]
     pub fn new(
         x: ArrayBase<D, Ix1>,
         y: ArrayBase<D, Ix1>,
@@ -132,6 +151,27 @@ where
         Ok(interpolator)
     }
 
+    /// Does `other` have an identical grid, e.g. to combine their values?
+    /// See [`InterpData::same_grid`].
+    pub fn same_grid<D2, S2>(&self, other: &Interp2D<D2, S2>) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        S2: Strategy2D<D2> + Clone,
+    {
+        self.data.same_grid(&other.data)
+    }
+
+    /// Does `other` have an identical grid, to within `epsilon`?
+    /// See [`InterpData::same_grid_approx`].
+    pub fn same_grid_approx<D2, S2>(&self, other: &Interp2D<D2, S2>, epsilon: D::Elem) -> bool
+    where
+        D2: Data<Elem = D::Elem> + RawDataClone,
+        D::Elem: Num + Copy,
+        S2: Strategy2D<D2> + Clone,
+    {
+        self.data.same_grid_approx(&other.data, epsilon)
+    }
+
     /// Return an interpolator with viewed data.
     pub fn view(&self) -> Interp2DViewed<&D::Elem, S>
     where
@@ -141,7 +181,7 @@ where
         Interp2DViewed {
             data: self.data.view(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
         }
     }
 
@@ -154,14 +194,363 @@ where
         Interp2DOwned {
             data: self.data.into_owned(),
             strategy: self.strategy.clone(),
-            extrapolate: self.extrapolate.clone(),
+            extrapolate: self.extrapolate,
+        }
+    }
+
+    /// Return an interpolator with the x and y axes swapped, i.e. `f(x, y)` becomes `f(y, x)`.
+    ///
+    /// Useful when a table was constructed with its axes in the wrong order.
+    /// The strategy's axes are swapped (see [`Strategy2D::transpose_axes`]),
+    /// and it is re-initialized with the transposed data, erroring if it fails to initialize.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1., 2.],
+    ///     array![[0., 1., 2.], [3., 4., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let transposed = interp.transpose().unwrap();
+    /// assert_eq!(transposed.data.values, array![[0., 3.], [1., 4.], [2., 5.]]);
+    /// assert_eq!(
+    ///     transposed.interpolate(&[1.5, 0.5]).unwrap(),
+    ///     interp.interpolate(&[0.5, 1.5]).unwrap()
+    /// );
+    /// ```
+    #[must_use = "the strategy can fail to initialize, which should be handled"]
+    pub fn transpose(&self) -> Result<Interp2DOwned<D::Elem, S>, ValidateError>
+    where
+        S: Strategy2D<OwnedRepr<D::Elem>>,
+        D::Elem: Clone,
+    {
+        let mut interpolator = Interp2DOwned {
+            data: InterpData2DOwned {
+                grid: [self.data.grid[1].to_owned(), self.data.grid[0].to_owned()],
+                values: self.data.values.t().as_standard_layout().into_owned(),
+            },
+            strategy: self.strategy.clone(),
+            extrapolate: self.extrapolate,
+        };
+        Strategy2D::<OwnedRepr<D::Elem>>::transpose_axes(&mut interpolator.strategy);
+        interpolator.strategy.init(&interpolator.data)?;
+        Ok(interpolator)
+    }
+
+    /// Return a 1-D interpolator over the y-axis at grid row `i`, i.e. `f(x_i, y)`,
+    /// e.g. for plotting a cross-section of the table.
+    ///
+    /// The strategy and [`Extrapolate`] setting are carried over.
+    /// Errors if `i` is out of range.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1., 2.],
+    ///     array![[0., 1., 2.], [3., 4., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let row = interp.row_interp(1).unwrap();
+    /// assert_eq!(row.interpolate(&[1.5]).unwrap(), 4.5);
+    /// ```
+    pub fn row_interp(&self, i: usize) -> Result<Interp1DOwned<D::Elem, S>, ValidateError>
+    where
+        S: Strategy1D<OwnedRepr<D::Elem>>,
+        D::Elem: Clone,
+    {
+        let rows = self.data.grid[0].len();
+        if i >= rows {
+            return Err(ValidateError::Other(format!(
+                "row index {i} is out of range for x-axis of length {rows}"
+            )));
+        }
+        Interp1D::new(
+            self.data.grid[1].to_owned(),
+            self.data.values.row(i).to_owned(),
+            self.strategy.clone(),
+            self.extrapolate,
+        )
+    }
+
+    /// Return a 1-D interpolator over the x-axis at grid column `j`, i.e. `f(x, y_j)`,
+    /// e.g. for plotting a cross-section of the table.
+    ///
+    /// The strategy and [`Extrapolate`] setting are carried over.
+    /// Errors if `j` is out of range.
+    pub fn column_interp(&self, j: usize) -> Result<Interp1DOwned<D::Elem, S>, ValidateError>
+    where
+        S: Strategy1D<OwnedRepr<D::Elem>>,
+        D::Elem: Clone,
+    {
+        let columns = self.data.grid[1].len();
+        if j >= columns {
+            return Err(ValidateError::Other(format!(
+                "column index {j} is out of range for y-axis of length {columns}"
+            )));
         }
+        Interp1D::new(
+            self.data.grid[0].to_owned(),
+            self.data.values.column(j).to_owned(),
+            self.strategy.clone(),
+            self.extrapolate,
+        )
+    }
+
+    /// Return a [`Pipeline`] treating axis `dim` as periodic with the given `period`,
+    /// e.g. `360.` for an angle in degrees, so that interpolation across the seam
+    /// between the last and first grid points takes the short way around.
+    ///
+    /// See [`Pipeline::with_angular_axis`], which can be chained to make both axes periodic.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![10., 90., 180., 270., 350.], // heading (degrees)
+    ///     array![0., 1.],
+    ///     array![[1., 1.], [2., 2.], [3., 3.], [4., 4.], [5., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let angular = interp.with_angular_axis(0, 360.).unwrap();
+    /// // halfway between 350° and 370° (10°)
+    /// assert_eq!(angular.interpolate(&[0., 0.]).unwrap(), 3.);
+    /// assert_eq!(angular.interpolate(&[360., 0.]).unwrap(), 3.);
+    /// assert_eq!(angular.interpolate(&[-270., 0.]).unwrap(), 2.);
+    /// ```
+    pub fn with_angular_axis(
+        &self,
+        dim: usize,
+        period: D::Elem,
+    ) -> Result<Interp2DPipeline<D::Elem, S>, ValidateError>
+    where
+        S: Strategy2D<OwnedRepr<D::Elem>>,
+        D::Elem: Num + Euclid + Copy,
+    {
+        Pipeline::new(self.clone().into_owned()).with_angular_axis(dim, period)
+    }
+
+    /// Export function values as a row-major `f32` buffer and its `[rows, columns]` dimensions,
+    /// e.g. for uploading as a single-channel GPU texture.
+    ///
+    /// Texel `(i, j)` (row `i`, column `j`) holds `f(x_i, y_j)`,
+    /// i.e. rows run along the x-axis and columns along the y-axis.
+    ///
+    /// Hardware bilinear sampling reproduces [`strategy::Linear`] when sampling at
+    /// normalized texture coordinates `(u, v) = ((j + 0.5) / columns, (i + 0.5) / rows)`,
+    /// where `i` and `j` are *fractional* grid indices for the point `(x, y)`.
+    /// For uniform grids, `i = (x - x_0) / (x_1 - x_0)` (likewise for `j`).
+    /// Non-uniform grids require remapping coordinates to fractional indices
+    /// before sampling, e.g. with a lookup table built from a 1-D [`strategy::Linear`]
+    /// interpolator mapping `x` to `0, 1, ..., rows - 1`.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1., 2.],
+    ///     array![[0., 1., 2.], [3., 4., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let (buffer, dims) = interp.to_texture_data();
+    /// assert_eq!(buffer, vec![0., 1., 2., 3., 4., 5.]);
+    /// assert_eq!(dims, [2, 3]);
+    /// ```
+    pub fn to_texture_data(&self) -> (Vec<f32>, [usize; 2])
+    where
+        D::Elem: AsPrimitive<f32>,
+    {
+        let (rows, columns) = self.data.values.dim();
+        (
+            self.data.values.iter().map(|v| v.as_()).collect(),
+            [rows, columns],
+        )
+    }
+}
+
+impl<T, S> Interp2DOwned<T, S>
+where
+    T: Num + PartialOrd + Copy + Debug,
+    S: Strategy2D<OwnedRepr<T>> + Clone,
+{
+    /// Instantiate two-dimensional interpolator by evaluating `f` at each point `[x, y]` of the grid.
+    ///
+    /// Errors for the same reasons as [`Interp2D::new`].
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::from_fn(
+    ///     array![0., 1., 2.],
+    ///     array![0., 1.],
+    ///     |p| p[0] * p[0] + p[1],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.data.values, array![[0., 1.], [1., 2.], [4., 5.]]);
+    /// assert_eq!(interp.interpolate(&[1.5, 0.5]).unwrap(), 3.);
+    /// ```
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn from_fn(
+        x: Array1<T>,
+        y: Array1<T>,
+        f: impl Fn(&[T]) -> T,
+        strategy: S,
+        extrapolate: Extrapolate<T>,
+    ) -> Result<Self, ValidateError> {
+        let values = Array2::from_shape_fn((x.len(), y.len()), |(i, j)| f(&[x[i], y[j]]));
+        Self::new(x, y, values, strategy, extrapolate)
+    }
+}
+
+impl<D, S> Interp2D<D, S>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+    S: Strategy2D<D> + Clone,
+{
+    /// Bilinear shape functions at `point`: the flattened (row-major) indices into the values
+    /// of the four surrounding nodes, and their weights, which sum to 1.
+    ///
+    /// Nodes are ordered `(x_l, y_l)`, `(x_u, y_l)`, `(x_l, y_u)`, `(x_u, y_u)`,
+    /// where flattened index `i * ny + j` corresponds to node `(x_i, y_j)`.
+    /// Weighting the node values reproduces [`strategy::Linear`] interpolation,
+    /// and the weights can be applied to other per-node data, e.g. for FEM.
+    ///
+    /// Weights are always bilinear, regardless of the interpolator's strategy.
+    /// Out-of-bounds points are clamped for [`Extrapolate::Clamp`],
+    /// give extrapolating weights (possibly negative) for [`Extrapolate::Enable`],
+    /// and are otherwise an error.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1., 2.],
+    ///     array![[0., 1., 2.], [3., 4., 5.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let (indices, weights) = interp.shape_functions(&[0.5, 1.5]).unwrap();
+    /// assert_eq!(indices, [1, 4, 2, 5]);
+    /// assert_eq!(weights, [0.25; 4]);
+    /// ```
+    pub fn shape_functions(
+        &self,
+        point: &[D::Elem; 2],
+    ) -> Result<([usize; 4], [D::Elem; 4]), InterpolateError> {
+        let mut point = *point;
+        for (dim, pt) in point.iter_mut().enumerate() {
+            let grid = &self.data.grid[dim];
+            let (min, max) = (grid.first().unwrap(), grid.last().unwrap());
+            if !(min..=max).contains(&&*pt) {
+                match &self.extrapolate {
+                    Extrapolate::Enable => {}
+                    Extrapolate::Clamp => *pt = *clamp(&*pt, min, max),
+                    _ => {
+                        return Err(InterpolateError::ExtrapolateError(format!(
+                        "\n    point[{dim}] = {pt:?} is out of bounds for grid[{dim}] = {grid:?}",
+                    )))
+                    }
+                }
+            }
+        }
+        let ([x_l, y_l], [x_diff, y_diff]) = strategies::bilinear_cell(&self.data, &point)?;
+        let ny = self.data.grid[1].len();
+        let one = D::Elem::one();
+        Ok((
+            [
+                x_l * ny + y_l,
+                (x_l + 1) * ny + y_l,
+                x_l * ny + y_l + 1,
+                (x_l + 1) * ny + y_l + 1,
+            ],
+            [
+                (one - x_diff) * (one - y_diff),
+                x_diff * (one - y_diff),
+                (one - x_diff) * y_diff,
+                x_diff * y_diff,
+            ],
+        ))
+    }
+
+    /// Evaluate the interpolant on the grid spanned by `xs` and `ys`,
+    /// where element `[i, j]` of the result corresponds to `(xs[i], ys[j])`.
+    ///
+    /// Query points outside the data domain are set to `fill`,
+    /// regardless of the [`Extrapolate`] setting, e.g. for rendering a heatmap
+    /// over a window larger than the data.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp2D::new(
+    ///     array![0., 1.],
+    ///     array![0., 1.],
+    ///     array![[0., 1.], [2., 3.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// let grid = interp
+    ///     .eval_grid_filled(array![0.5, 2.].view(), array![-1., 0.5].view(), f64::NAN)
+    ///     .unwrap();
+    /// assert!(grid[[0, 0]].is_nan());
+    /// assert_eq!(grid[[0, 1]], 1.5);
+    /// assert!(grid[[1, 1]].is_nan());
+    /// ```
+    pub fn eval_grid_filled(
+        &self,
+        xs: ArrayView1<D::Elem>,
+        ys: ArrayView1<D::Elem>,
+        fill: D::Elem,
+    ) -> Result<Array2<D::Elem>, InterpolateError> {
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let [x, y] = &self.data.grid;
+        let x_range = x.first().unwrap()..=x.last().unwrap();
+        let y_range = y.first().unwrap()..=y.last().unwrap();
+        let mut out = Array2::from_elem((xs.len(), ys.len()), fill);
+        for (i, x_i) in xs.iter().enumerate() {
+            if !x_range.contains(&x_i) {
+                continue;
+            }
+            for (j, y_j) in ys.iter().enumerate() {
+                if y_range.contains(&y_j) {
+                    out[[i, j]] = self.strategy.interpolate(&self.data, &[*x_i, *y_j])?;
+                }
+            }
+        }
+        Ok(out)
     }
 }
 
 impl<D, S> Interpolator<D::Elem> for Interp2D<D, S>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + Euclid + PartialOrd + Debug + Copy,
     S: Strategy2D<D> + Clone,
 {
@@ -182,6 +571,9 @@ where
         let point: &[D::Elem; N] = point
             .try_into()
             .map_err(|_| InterpolateError::PointLength(N))?;
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
         let mut errors = Vec::new();
         for dim in 0..N {
             if !(self.data.grid[dim].first().unwrap()..=self.data.grid[dim].last().unwrap())
@@ -189,7 +581,29 @@ where
             {
                 match &self.extrapolate {
                     Extrapolate::Enable => {}
+                    Extrapolate::EnableBounded(limit) => {
+                        let grid = &self.data.grid[dim];
+                        let (min, max) = (grid.first().unwrap(), grid.last().unwrap());
+                        if beyond_limit(point[dim], *min, *max, *limit) {
+                            errors.push(format!(
+                                "\n    point[{dim}] = {:?} is more than {limit:?} beyond grid[{dim}] = {grid:?}",
+                                point[dim],
+                            ));
+                        }
+                    }
                     Extrapolate::Fill(value) => return Ok(*value),
+                    Extrapolate::SaturatingFill { .. } => {
+                        return Err(InterpolateError::Other(format!(
+                            "{:?} is only applicable for 1-D interpolators",
+                            self.extrapolate
+                        )))
+                    }
+                    Extrapolate::NearestValue => {
+                        let index: [usize; N] = std::array::from_fn(|i| {
+                            nearest_index(self.data.grid[i].view(), point[i])
+                        });
+                        return Ok(self.data.values[index]);
+                    }
                     Extrapolate::Clamp => {
                         let clamped_point = std::array::from_fn(|i| {
                             *clamp(
@@ -202,11 +616,15 @@ where
                     }
                     Extrapolate::Wrap => {
                         let wrapped_point = std::array::from_fn(|i| {
-                            wrap(
-                                point[i],
-                                *self.data.grid[i].first().unwrap(),
-                                *self.data.grid[i].last().unwrap(),
-                            )
+                            let min = self.data.grid[i].first().unwrap();
+                            let max = self.data.grid[i].last().unwrap();
+                            // Leave in-bounds coordinates untouched,
+                            // as wrapping maps a coordinate exactly at `max` to `min`
+                            if (min..=max).contains(&&point[i]) {
+                                point[i]
+                            } else {
+                                wrap(point[i], *min, *max)
+                            }
                         });
                         return self.strategy.interpolate(&self.data, &wrapped_point);
                     }
@@ -225,19 +643,175 @@ where
         self.strategy.interpolate(&self.data, point)
     }
 
+    fn interpolate_clamped(&self, point: &[D::Elem]) -> Result<D::Elem, InterpolateError> {
+        let point: &[D::Elem; N] = point
+            .try_into()
+            .map_err(|_| InterpolateError::PointLength(N))?;
+        if !self.strategy.is_initialized() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        let clamped_point = std::array::from_fn(|i| {
+            *clamp(
+                &point[i],
+                self.data.grid[i].first().unwrap(),
+                self.data.grid[i].last().unwrap(),
+            )
+        });
+        self.strategy.interpolate(&self.data, &clamped_point)
+    }
+
     fn set_extrapolate(&mut self, extrapolate: Extrapolate<D::Elem>) -> Result<(), ValidateError> {
         self.check_extrapolate(&extrapolate)?;
         self.extrapolate = extrapolate;
         Ok(())
     }
+
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        self.data.is_contiguous()
+    }
+
+    fn domain_bounds(&self, dim: usize) -> Option<(D::Elem, D::Elem)> {
+        if dim >= self.ndim() {
+            return None;
+        }
+        let grid = &self.data.grid[dim];
+        Some((*grid.first().unwrap(), *grid.last().unwrap()))
+    }
+
+    #[inline]
+    fn grid(&self, dim: usize) -> Option<ArrayView1<'_, D::Elem>> {
+        self.data.grid.get(dim).map(|grid| grid.view())
+    }
+
+    #[inline]
+    fn values_view(&self) -> Option<ArrayViewD<'_, D::Elem>> {
+        Some(self.data.values.view().into_dyn())
+    }
+
+    /// Returns `Some` if the strategy is a [`Strategy2DEnum`](strategy::enums::Strategy2DEnum)
+    /// or one of its variants' strategies, converting it to the enum.
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<D::Elem>>>
+    where
+        D::Elem: 'static,
+    {
+        let strategy = strategy::enums::Strategy2DEnum::from_any(self.strategy.as_any()?)?;
+        Some(Box::new(Interp2D {
+            data: self.data.view().into_owned(),
+            strategy,
+            extrapolate: self.extrapolate,
+        }))
+    }
+}
+
+impl<T, S> Interp2DPipeline<T, S>
+where
+    T: Num + Euclid + PartialOrd + Copy + Debug,
+    S: Strategy2D<OwnedRepr<T>> + Clone,
+{
+    /// Treat axis `dim` of the wrapped interpolator as periodic with the given `period`,
+    /// adding an axis wrapping stage (see [`Pipeline::wrap_axis`]) onto `[x_0, x_0 + period)`.
+    ///
+    /// If the grid along `dim` does not already end at `x_0 + period`,
+    /// the first slice of values is repeated there, closing the seam.
+    /// Errors if `dim` is out of range, `period` is not positive,
+    /// or the grid along `dim` spans more than `period`.
+    pub fn with_angular_axis(mut self, dim: usize, period: T) -> Result<Self, ValidateError> {
+        if dim >= N {
+            return Err(ValidateError::Other(format!(
+                "angular axis dim {dim} is out of range for 2-D interpolator"
+            )));
+        }
+        if period <= T::zero() {
+            return Err(ValidateError::Other(format!(
+                "angular axis period must be positive, got {period:?}"
+            )));
+        }
+        let grid = &self.interpolator.data.grid[dim];
+        let (first, last) = (*grid.first().unwrap(), *grid.last().unwrap());
+        let end = first + period;
+        if last > end {
+            return Err(ValidateError::Other(format!(
+                "grid along angular axis {dim} spans more than period {period:?}"
+            )));
+        }
+        if last < end {
+            let Interp2D {
+                data,
+                strategy,
+                extrapolate,
+            } = self.interpolator;
+            let mut grid = data.grid;
+            grid[dim] = ndarray::concatenate(Axis(0), &[grid[dim].view(), aview1(&[end])])
+                .expect("grid is 1-D");
+            let values = ndarray::concatenate(
+                Axis(dim),
+                &[
+                    data.values.view(),
+                    data.values
+                        .slice_axis(Axis(dim), ndarray::Slice::from(0..1)),
+                ],
+            )
+            .expect("slice shape matches values");
+            let [x, y] = grid;
+            self.interpolator = Interp2D::new(x, y, values, strategy, extrapolate)?;
+        }
+        self.wraps.push((dim, first, period));
+        Ok(self)
+    }
+}
+
+impl<D> Interp2D<D, strategy::Linear>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + Euclid + PartialOrd + Copy + Debug,
+{
+    /// Analytic gradient `[df/dx, df/dy]` of the bilinear interpolant at `point`.
+    ///
+    /// Within a cell, `df/dx` varies linearly with the `y` position and vice versa.
+    /// At interior grid lines the gradient is discontinuous,
+    /// and the cell below the line is used (except at the upper grid edge).
+    ///
+    /// Out-of-bounds points follow the [`Extrapolate`] setting, differentiating what
+    /// [`Interpolator::interpolate`] would return:
+    /// - [`Extrapolate::Enable`], [`Extrapolate::EnableBounded`]: gradient of the nearest edge cell
+    /// - [`Extrapolate::Fill`], [`Extrapolate::NearestValue`]: zero, as the value is constant
+    /// - [`Extrapolate::Clamp`]: gradient at the clamped point, zero along clamped axes
+    /// - [`Extrapolate::Wrap`]: gradient at the wrapped point
+    /// - [`Extrapolate::Error`]: an error
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// // f(x, y) = x * y
+    /// let interp = Interp2D::new(
+    ///     array![0., 2.],
+    ///     array![0., 2.],
+    ///     array![[0., 0.], [0., 4.]],
+    ///     strategy::Linear,
+    ///     Extrapolate::Error,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(interp.gradient(&[0.5, 1.5]).unwrap(), vec![1.5, 0.5]);
+    /// ```
+    pub fn gradient(&self, point: &[D::Elem]) -> Result<Vec<D::Elem>, InterpolateError> {
+        multilinear_gradient(
+            &self.data.grid,
+            self.data.values.view().into_dyn(),
+            point,
+            &self.extrapolate,
+        )
+    }
 }
 
 impl<D> Interp2D<D, Box<dyn Strategy2D<D>>>
 where
-    D: Data + RawDataClone + Clone,
-    D::Elem: PartialEq + Debug,
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(&mut self, strategy: Box<dyn Strategy2D<D>>) -> Result<(), ValidateError> {
         self.strategy = strategy;
         self.check_extrapolate(&self.extrapolate)
@@ -246,10 +820,11 @@ where
 
 impl<D> Interp2D<D, strategy::enums::Strategy2DEnum>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     /// Update strategy dynamically.
+    #[must_use = "the strategy can be inapplicable to the extrapolation setting, which should be handled"]
     pub fn set_strategy(
         &mut self,
         strategy: impl Into<strategy::enums::Strategy2DEnum>,
@@ -1,9 +1,39 @@
 use super::*;
 use strategy::*;
 
+/// Lower grid indices of the cell containing `point`,
+/// and the fractional position of `point` within that cell along each axis.
+///
+/// Points beyond the grid use the nearest edge cell, yielding fractions outside `[0, 1]`.
+/// Fails if either axis has a single point, as it then contains no cell.
+pub(crate) fn bilinear_cell<D>(
+    data: &InterpData2D<D>,
+    point: &[D::Elem; 2],
+) -> Result<([usize; 2], [D::Elem; 2]), InterpolateError>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    check_brackets(&data.grid)?;
+    let lowers: [usize; 2] = std::array::from_fn(|dim| {
+        if &point[dim] < data.grid[dim].first().unwrap() {
+            0
+        } else if &point[dim] > data.grid[dim].last().unwrap() {
+            data.grid[dim].len() - 2
+        } else {
+            find_nearest_index(data.grid[dim].view(), &point[dim])
+        }
+    });
+    let diffs = std::array::from_fn(|dim| {
+        let l = lowers[dim];
+        (point[dim] - data.grid[dim][l]) / (data.grid[dim][l + 1] - data.grid[dim][l])
+    });
+    Ok((lowers, diffs))
+}
+
 impl<D> Strategy2D<D> for Linear
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -13,23 +43,9 @@ where
     ) -> Result<D::Elem, InterpolateError> {
         // Extrapolation is checked previously in Interpolator::interpolate,
         // meaning by now, point is within grid bounds or extrapolation is enabled
-        let lowers: [usize; 2] = std::array::from_fn(|dim| {
-            if &point[dim] < data.grid[dim].first().unwrap() {
-                0
-            } else if &point[dim] > data.grid[dim].last().unwrap() {
-                data.grid[dim].len() - 2
-            } else {
-                find_nearest_index(data.grid[dim].view(), &point[dim])
-            }
-        });
-        // x
-        let x_l = lowers[0];
+        let ([x_l, y_l], [x_diff, y_diff]) = bilinear_cell(data, point)?;
         let x_u = x_l + 1;
-        let x_diff = (point[0] - data.grid[0][x_l]) / (data.grid[0][x_u] - data.grid[0][x_l]);
-        // y
-        let y_l = lowers[1];
         let y_u = y_l + 1;
-        let y_diff = (point[1] - data.grid[1][y_l]) / (data.grid[1][y_u] - data.grid[1][y_l]);
         // interpolate in the x-direction
         let f0 =
             data.values[[x_l, y_l]] * (D::Elem::one() - x_diff) + data.values[[x_u, y_l]] * x_diff;
@@ -43,11 +59,63 @@ where
     fn allow_extrapolate(&self) -> bool {
         true
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+impl<D> Strategy2D<D> for NanAwareLinear
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Debug,
+{
+    fn interpolate(
+        &self,
+        data: &InterpData2D<D>,
+        point: &[D::Elem; 2],
+    ) -> Result<D::Elem, InterpolateError> {
+        let ([x_l, y_l], [x_diff, y_diff]) = bilinear_cell(data, point)?;
+        let one = D::Elem::one();
+        let corners = [
+            ([x_l, y_l], (one - x_diff) * (one - y_diff)),
+            ([x_l + 1, y_l], x_diff * (one - y_diff)),
+            ([x_l, y_l + 1], (one - x_diff) * y_diff),
+            ([x_l + 1, y_l + 1], x_diff * y_diff),
+        ];
+        let (mut sum, mut weight, mut unweighted, mut count) = (
+            D::Elem::zero(),
+            D::Elem::zero(),
+            D::Elem::zero(),
+            D::Elem::zero(),
+        );
+        for (index, w) in corners {
+            let value = data.values[index];
+            if !value.is_nan() {
+                sum = sum + w * value;
+                weight = weight + w;
+                unweighted = unweighted + value;
+                count = count + one;
+            }
+        }
+        Ok(if count.is_zero() {
+            D::Elem::nan()
+        } else if weight.is_zero() {
+            unweighted / count
+        } else {
+            sum / weight
+        })
+    }
+
+    /// Returns `true`.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
 }
 
 impl<D> Strategy2D<D> for Nearest
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     fn interpolate(
@@ -55,6 +123,7 @@ where
         data: &InterpData2D<D>,
         point: &[D::Elem; 2],
     ) -> Result<D::Elem, InterpolateError> {
+        check_brackets(&data.grid)?;
         // x
         let x_l = find_nearest_index(data.grid[0].view(), &point[0]);
         let x_u = x_l + 1;
@@ -79,4 +148,273 @@ where
     fn allow_extrapolate(&self) -> bool {
         false
     }
+
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+/// Second derivatives of the natural cubic spline through `(x, f_x)`,
+/// solving the tridiagonal system with the Thomas algorithm.
+fn spline_second_derivatives<T, S1, S2>(
+    x: &ArrayBase<S1, Ix1>,
+    f_x: &ArrayBase<S2, Ix1>,
+) -> Array1<T>
+where
+    T: Float,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+{
+    let n = x.len();
+    let mut m = Array1::zeros(n);
+    if n < 3 {
+        return m;
+    }
+    let six = T::from(6).unwrap();
+    let two = T::one() + T::one();
+    // Forward sweep, with the natural boundary conditions `m[0] = m[n - 1] = 0`
+    let mut c_prime = vec![T::zero(); n];
+    let mut d_prime = vec![T::zero(); n];
+    for i in 1..n - 1 {
+        let (h_l, h_u) = (x[i] - x[i - 1], x[i + 1] - x[i]);
+        let rhs = six * ((f_x[i + 1] - f_x[i]) / h_u - (f_x[i] - f_x[i - 1]) / h_l);
+        let denom = two * (h_l + h_u) - h_l * c_prime[i - 1];
+        c_prime[i] = h_u / denom;
+        d_prime[i] = (rhs - h_l * d_prime[i - 1]) / denom;
+    }
+    // Back substitution
+    for i in (1..n - 1).rev() {
+        m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+    }
+    m
+}
+
+/// Second derivatives of the periodic cubic spline through `(x, f_x)`, with period `x[n - 1] - x[0]`.
+///
+/// Assumes `f_x[n - 1]` matches `f_x[0]`, which is used in its place.
+/// Solves the cyclic tridiagonal system with the Thomas algorithm and the Sherman–Morrison formula.
+fn periodic_spline_second_derivatives<T, S1, S2>(
+    x: &ArrayBase<S1, Ix1>,
+    f_x: &ArrayBase<S2, Ix1>,
+) -> Array1<T>
+where
+    T: Float + Debug,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+{
+    // Number of distinct points in one period
+    let n = x.len() - 1;
+    let mut m = Array1::zeros(n + 1);
+    if n < 2 {
+        return m;
+    }
+    let six = T::from(6).unwrap();
+    let two = T::one() + T::one();
+    let h = |i: usize| x[i + 1] - x[i];
+    let f = |i: usize| if i == n { f_x[0] } else { f_x[i] };
+    // Row `i` couples `m[i - 1]`, `m[i]`, `m[i + 1]`, cyclically
+    let (mut sub, mut diag, mut sup, mut rhs) = (
+        vec![T::zero(); n],
+        vec![T::zero(); n],
+        vec![T::zero(); n],
+        vec![T::zero(); n],
+    );
+    for i in 0..n {
+        let (h_l, h_u) = (h((i + n - 1) % n), h(i));
+        let f_l = if i == 0 { f(n - 1) } else { f(i - 1) };
+        sub[i] = h_l;
+        diag[i] = two * (h_l + h_u);
+        sup[i] = h_u;
+        rhs[i] = six * ((f(i + 1) - f(i)) / h_u - (f(i) - f_l) / h_l);
+    }
+    if n == 2 {
+        // Both neighbors of each point are the other point
+        let (a, b) = (diag[0], sub[0] + sup[0]);
+        let (c, d) = (sub[1] + sup[1], diag[1]);
+        let det = a * d - b * c;
+        m[0] = (d * rhs[0] - b * rhs[1]) / det;
+        m[1] = (a * rhs[1] - c * rhs[0]) / det;
+        m[2] = m[0];
+        return m;
+    }
+    // Corners of the cyclic system, moved into a rank-one correction
+    let (corner_bottom, corner_top) = (sup[n - 1], sub[0]);
+    let gamma = T::zero() - diag[0];
+    diag[0] = diag[0] - gamma;
+    diag[n - 1] = diag[n - 1] - corner_bottom * corner_top / gamma;
+    let thomas = |rhs: &[T]| {
+        let mut c_prime = vec![T::zero(); n];
+        let mut d_prime = vec![T::zero(); n];
+        c_prime[0] = sup[0] / diag[0];
+        d_prime[0] = rhs[0] / diag[0];
+        for i in 1..n {
+            let denom = diag[i] - sub[i] * c_prime[i - 1];
+            c_prime[i] = sup[i] / denom;
+            d_prime[i] = (rhs[i] - sub[i] * d_prime[i - 1]) / denom;
+        }
+        let mut solution = d_prime;
+        for i in (0..n - 1).rev() {
+            solution[i] = solution[i] - c_prime[i] * solution[i + 1];
+        }
+        solution
+    };
+    let y = thomas(&rhs);
+    let mut u = vec![T::zero(); n];
+    u[0] = gamma;
+    u[n - 1] = corner_bottom;
+    let z = thomas(&u);
+    let factor =
+        (y[0] + corner_top * y[n - 1] / gamma) / (T::one() + z[0] + corner_top * z[n - 1] / gamma);
+    for i in 0..n {
+        m[i] = y[i] - factor * z[i];
+    }
+    m[n] = m[0];
+    m
+}
+
+/// Evaluate the cubic spline through `(x, f_x)` with second derivatives `m` at `point`,
+/// extrapolating linearly beyond the grid.
+fn spline_eval<T, S1, S2, S3>(
+    x: &ArrayBase<S1, Ix1>,
+    f_x: &ArrayBase<S2, Ix1>,
+    m: &ArrayBase<S3, Ix1>,
+    point: T,
+) -> T
+where
+    T: Float,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+    S3: Data<Elem = T>,
+{
+    let n = x.len();
+    let six = T::from(6).unwrap();
+    let two = T::one() + T::one();
+    if point < x[0] {
+        let h = x[1] - x[0];
+        let slope = (f_x[1] - f_x[0]) / h - h * (two * m[0] + m[1]) / six;
+        return f_x[0] + slope * (point - x[0]);
+    }
+    if point > x[n - 1] {
+        let h = x[n - 1] - x[n - 2];
+        let slope = (f_x[n - 1] - f_x[n - 2]) / h + h * (m[n - 2] + two * m[n - 1]) / six;
+        return f_x[n - 1] + slope * (point - x[n - 1]);
+    }
+    let i = find_nearest_index(x.view(), &point);
+    let h = x[i + 1] - x[i];
+    let a = (x[i + 1] - point) / h;
+    let b = T::one() - a;
+    a * f_x[i]
+        + b * f_x[i + 1]
+        + ((a * a * a - a) * m[i] + (b * b * b - b) * m[i + 1]) * h * h / six
+}
+
+impl<T> Bicubic<T>
+where
+    T: Float + Debug,
+{
+    /// Second derivatives of the spline through `(x, f_x)` along `axis`.
+    fn second_derivatives_along<S1, S2>(
+        &self,
+        axis: usize,
+        x: &ArrayBase<S1, Ix1>,
+        f_x: &ArrayBase<S2, Ix1>,
+    ) -> Array1<T>
+    where
+        S1: Data<Elem = T>,
+        S2: Data<Elem = T>,
+    {
+        if self.periodic_axis == Some(axis) {
+            periodic_spline_second_derivatives(x, f_x)
+        } else {
+            spline_second_derivatives(x, f_x)
+        }
+    }
+}
+
+impl<D> Strategy2D<D> for Bicubic<D::Elem>
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Euclid + Debug,
+{
+    fn init(&mut self, data: &InterpData2D<D>) -> Result<(), ValidateError> {
+        if let Some(dim) = data.grid.iter().position(|g| g.len() < 2) {
+            return Err(ValidateError::Other(format!(
+                "at least 2 grid points are required for bicubic interpolation: dim {dim}"
+            )));
+        }
+        if let Some(axis) = self.periodic_axis {
+            if axis > 1 {
+                return Err(ValidateError::Other(format!(
+                    "periodic axis {axis} is out of range for bicubic interpolation"
+                )));
+            }
+            // Values at either end of the periodic axis must match
+            let values = &data.values;
+            let (first, last) = (
+                values.index_axis(Axis(axis), 0),
+                values.index_axis(Axis(axis), values.len_of(Axis(axis)) - 1),
+            );
+            let tolerance = D::Elem::epsilon().sqrt();
+            if let Some((a, b)) = first.iter().zip(&last).find(|(a, b)| {
+                (**a - **b).abs() > tolerance * D::Elem::one().max(a.abs()).max(b.abs())
+            }) {
+                return Err(ValidateError::Other(format!(
+                    "values at either end of periodic axis {axis} must match: {a:?} != {b:?}"
+                )));
+            }
+        }
+        let mut second_derivatives = Array2::zeros(data.values.dim());
+        for (row, mut m) in data
+            .values
+            .rows()
+            .into_iter()
+            .zip(second_derivatives.rows_mut())
+        {
+            m.assign(&self.second_derivatives_along(1, &data.grid[1], &row));
+        }
+        self.second_derivatives = second_derivatives;
+        Ok(())
+    }
+
+    fn interpolate(
+        &self,
+        data: &InterpData2D<D>,
+        point: &[D::Elem; 2],
+    ) -> Result<D::Elem, InterpolateError> {
+        if self.second_derivatives.dim() != data.values.dim() {
+            return Err(InterpolateError::Uninitialized);
+        }
+        check_brackets(&data.grid)?;
+        let mut point = *point;
+        if let Some(axis) = self.periodic_axis {
+            let grid = &data.grid[axis];
+            point[axis] = wrap(point[axis], grid[0], grid[grid.len() - 1]);
+        }
+        // Evaluate the spline along y of each row, then the spline along x through the results
+        let column: Array1<D::Elem> = data
+            .values
+            .rows()
+            .into_iter()
+            .zip(self.second_derivatives.rows())
+            .map(|(row, m)| spline_eval(&data.grid[1], &row, &m, point[1]))
+            .collect();
+        let m = self.second_derivatives_along(0, &data.grid[0], &column);
+        Ok(spline_eval(&data.grid[0], &column, &m, point[0]))
+    }
+
+    /// Swaps the periodic axis, if any. Out-of-range axes are left to fail [`Strategy2D::init`].
+    fn transpose_axes(&mut self) {
+        if let Some(axis @ (0 | 1)) = self.periodic_axis {
+            self.periodic_axis = Some(1 - axis);
+        }
+    }
+
+    /// Returns `true`, extending each spline linearly from the edges.
+    fn allow_extrapolate(&self) -> bool {
+        true
+    }
+
+    fn is_initialized(&self) -> bool {
+        !self.second_derivatives.is_empty()
+    }
 }
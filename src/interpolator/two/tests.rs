@@ -173,6 +173,410 @@ fn test_extrapolate_clamp() {
     assert_eq!(interp.interpolate(&[2., 2.]).unwrap(), 3.);
 }
 
+#[test]
+fn test_extrapolate_wrap_mixed_bounds() {
+    let interp = Interp2D::new(
+        array![0., 1.],
+        array![0., 1., 2.],
+        array![[0., 1., 2.], [3., 4., 5.]],
+        strategy::Linear,
+        Extrapolate::Wrap,
+    )
+    .unwrap();
+    // In-bounds axis exactly at its max keeps its edge value,
+    // while the out-of-bounds axis is wrapped
+    assert_eq!(interp.interpolate(&[1., 2.5]).unwrap(), 3.5);
+    assert_eq!(interp.interpolate(&[1.5, 2.]).unwrap(), 3.5);
+}
+
+#[test]
+fn test_index() {
+    let interp = Interp2D::new(
+        array![0.05, 0.10, 0.15],
+        array![0.10, 0.20, 0.30],
+        array![[0., 1., 2.], [3., 4., 5.], [6., 7., 8.]],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(interp[[0, 0]], 0.);
+    assert_eq!(interp[[1, 2]], 5.);
+    assert_eq!(interp[[2, 1]], 7.);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bounds() {
+    let interp = Interp2D::new(
+        array![0.05, 0.10, 0.15],
+        array![0.10, 0.20, 0.30],
+        array![[0., 1., 2.], [3., 4., 5.], [6., 7., 8.]],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let _ = interp[[3, 0]];
+}
+
+#[test]
+fn test_transpose() {
+    let x = array![0.05, 0.10, 0.15];
+    let y = array![0.10, 0.20, 0.30, 0.40];
+    let f_xy = array![[0., 1., 2., 3.], [4., 5., 6., 7.], [8., 9., 10., 11.]];
+    let interp = Interp2D::new(x, y, f_xy, strategy::Linear, Extrapolate::Error).unwrap();
+    let transposed = interp.transpose().unwrap();
+    assert_eq!(transposed.data.grid[0], interp.data.grid[1]);
+    assert_eq!(transposed.data.grid[1], interp.data.grid[0]);
+    assert_eq!(transposed.data.values.dim(), (4, 3));
+    assert_eq!(transposed.transpose().unwrap(), interp);
+    for x in [0.05, 0.07, 0.1, 0.12, 0.15] {
+        for y in [0.1, 0.17, 0.25, 0.33, 0.4] {
+            assert_approx_eq!(
+                transposed.interpolate(&[y, x]).unwrap(),
+                interp.interpolate(&[x, y]).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_contiguous() {
+    let x = array![0.05, 0.10, 0.15];
+    let y = array![0.10, 0.20, 0.30, 0.40];
+    // Values stored transposed, i.e. in column-major layout
+    let f_yx = array![[0., 4., 8.], [1., 5., 9.], [2., 6., 10.], [3., 7., 11.]];
+    let f_xy = f_yx.reversed_axes();
+    assert!(!f_xy.is_standard_layout());
+    let interp = Interp2D::new(x, y, f_xy, strategy::Linear, Extrapolate::Error).unwrap();
+    assert!(!interp.is_contiguous());
+    let repacked = interp.clone().into_contiguous();
+    assert!(repacked.is_contiguous());
+    assert!(repacked.data.values.is_standard_layout());
+    assert_eq!(repacked, interp);
+    for x in [0.05, 0.07, 0.1, 0.12, 0.15] {
+        for y in [0.1, 0.17, 0.25, 0.33, 0.4] {
+            assert_eq!(
+                repacked.interpolate(&[x, y]).unwrap(),
+                interp.interpolate(&[x, y]).unwrap()
+            );
+        }
+    }
+    // Already-contiguous data is unchanged
+    assert_eq!(repacked.clone().into_contiguous(), repacked);
+}
+
+#[test]
+fn test_shape_functions() {
+    let x = array![0.05, 0.10, 0.15];
+    let y = array![0.10, 0.20, 0.30, 0.45];
+    let f_xy = array![[0., 1., 2., 3.], [4., 5., 6., 7.], [8., 9., 10., 11.]];
+    let mut interp = Interp2D::new(x, y, f_xy, strategy::Linear, Extrapolate::Error).unwrap();
+    let values = interp.data.values.as_slice().unwrap().to_vec();
+    for x in [0.05, 0.07, 0.1, 0.12, 0.15] {
+        for y in [0.1, 0.17, 0.25, 0.33, 0.45] {
+            let (indices, weights) = interp.shape_functions(&[x, y]).unwrap();
+            assert_approx_eq!(weights.iter().sum::<f64>(), 1.);
+            let dotted: f64 = indices
+                .iter()
+                .zip(weights)
+                .map(|(&i, w)| values[i] * w)
+                .sum();
+            assert_approx_eq!(dotted, interp.interpolate(&[x, y]).unwrap());
+        }
+    }
+    assert!(matches!(
+        interp.shape_functions(&[0.2, 0.2]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    interp.set_extrapolate(Extrapolate::Clamp).unwrap();
+    assert_eq!(
+        interp.shape_functions(&[0.2, 0.5]).unwrap(),
+        interp.shape_functions(&[0.15, 0.45]).unwrap()
+    );
+    interp.set_extrapolate(Extrapolate::Enable).unwrap();
+    let (indices, weights) = interp.shape_functions(&[0.2, 0.2]).unwrap();
+    assert_approx_eq!(weights.iter().sum::<f64>(), 1.);
+    let dotted: f64 = indices
+        .iter()
+        .zip(weights)
+        .map(|(&i, w)| values[i] * w)
+        .sum();
+    assert_approx_eq!(dotted, interp.interpolate(&[0.2, 0.2]).unwrap());
+}
+
+#[test]
+fn test_gradient() {
+    let x = array![0., 1., 3.];
+    let y = array![0., 0.5, 2.];
+    let f_xy = array![[0., 1., -1.], [2., 4., 3.], [1., 5., 9.]];
+    let mut interp = Interp2D::new(x, y, f_xy, strategy::Linear, Extrapolate::Error).unwrap();
+    let h = 1e-6;
+    // Cell interiors, where the bilinear form is smooth
+    for x in [0.2, 0.7, 1.5, 2.8] {
+        for y in [0.1, 0.4, 1., 1.9] {
+            let gradient = interp.gradient(&[x, y]).unwrap();
+            let fd_x = (interp.interpolate(&[x + h, y]).unwrap()
+                - interp.interpolate(&[x - h, y]).unwrap())
+                / (2. * h);
+            let fd_y = (interp.interpolate(&[x, y + h]).unwrap()
+                - interp.interpolate(&[x, y - h]).unwrap())
+                / (2. * h);
+            assert_approx_eq!(gradient[0], fd_x, 1e-4);
+            assert_approx_eq!(gradient[1], fd_y, 1e-4);
+        }
+    }
+    // df/dx depends on y and df/dy depends on x within a cell
+    assert_ne!(
+        interp.gradient(&[0.5, 0.1]).unwrap()[0],
+        interp.gradient(&[0.5, 0.4]).unwrap()[0]
+    );
+    assert_ne!(
+        interp.gradient(&[0.1, 0.25]).unwrap()[1],
+        interp.gradient(&[0.9, 0.25]).unwrap()[1]
+    );
+    assert!(matches!(
+        interp.gradient(&[3.5, 1.]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    interp.set_extrapolate(Extrapolate::Enable).unwrap();
+    // Extrapolation continues the edge cell, so df/dx is unchanged along x
+    assert_eq!(
+        interp.gradient(&[3.5, 1.]).unwrap()[0],
+        interp.gradient(&[2.5, 1.]).unwrap()[0]
+    );
+    let edge = interp.gradient(&[3.5, 1.]).unwrap();
+    interp
+        .set_extrapolate(Extrapolate::EnableBounded(1.))
+        .unwrap();
+    assert_eq!(interp.gradient(&[3.5, 1.]).unwrap(), edge);
+    assert!(matches!(
+        interp.gradient(&[5., 1.]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    // Constant beyond the grid
+    for extrapolate in [Extrapolate::Fill(0.), Extrapolate::NearestValue] {
+        interp.set_extrapolate(extrapolate).unwrap();
+        assert_eq!(interp.gradient(&[3.5, 1.]).unwrap(), [0., 0.]);
+    }
+    // Zero along the clamped axis, evaluated at the clamped point along the other
+    interp.set_extrapolate(Extrapolate::Clamp).unwrap();
+    assert_eq!(
+        interp.gradient(&[3.5, 1.]).unwrap(),
+        [0., interp.gradient(&[3., 1.]).unwrap()[1]]
+    );
+    interp.set_extrapolate(Extrapolate::Wrap).unwrap();
+    assert_eq!(
+        interp.gradient(&[3.5, 1.]).unwrap(),
+        interp.gradient(&[0.5, 1.]).unwrap()
+    );
+    assert!(matches!(
+        interp.gradient(&[0.5]).unwrap_err(),
+        InterpolateError::PointLength(2)
+    ));
+}
+
+#[test]
+fn test_extrapolate_enable_bounded() {
+    let interp = Interp2D::new(
+        array![0., 1.],
+        array![0., 1.],
+        array![[0., 1.], [2., 3.]],
+        strategy::Linear,
+        Extrapolate::EnableBounded(0.5),
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[1.5, 0.]).unwrap(), 3.);
+    assert_eq!(interp.interpolate(&[-0.5, 1.5]).unwrap(), 0.5);
+    assert!(matches!(
+        interp.interpolate(&[0.5, 3.]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    assert!(matches!(
+        interp.interpolate(&[-5., 0.5]).unwrap_err(),
+        InterpolateError::ExtrapolateError(_)
+    ));
+    let mut interp = interp;
+    assert!(interp
+        .set_extrapolate(Extrapolate::EnableBounded(-0.5))
+        .is_err());
+}
+
+#[test]
+fn test_extrapolate_nearest_value() {
+    let interp = Interp2D::new(
+        array![0.05, 0.10, 0.15],
+        array![0.10, 0.20, 0.30],
+        array![[0., 1., 2.], [3., 4., 5.], [6., 7., 8.]],
+        strategy::Linear,
+        Extrapolate::NearestValue,
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[0., 0.]).unwrap(), 0.);
+    assert_eq!(interp.interpolate(&[0.2, 0.4]).unwrap(), 8.);
+    // In-bounds coordinates snap to the nearest grid point
+    assert_eq!(interp.interpolate(&[0.2, 0.18]).unwrap(), 7.);
+    assert_eq!(interp.interpolate(&[0.09, 0.]).unwrap(), 3.);
+    // In-bounds points are interpolated as usual
+    assert_eq!(interp.interpolate(&[0.1, 0.2]).unwrap(), 4.);
+}
+
+#[test]
+fn test_row_column_interp() {
+    let x = array![0.05, 0.10, 0.15];
+    let y = array![0.10, 0.20, 0.30, 0.45];
+    let f_xy = array![[0., 1., 2., 3.], [4., 5., 6., 7.], [8., 9., 10., 11.]];
+    let nearest = Interp2D::new(
+        x.view(),
+        y.view(),
+        f_xy.view(),
+        strategy::Nearest,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(
+        nearest.row_interp(1).unwrap().interpolate(&[0.26]).unwrap(),
+        nearest.interpolate(&[0.1, 0.26]).unwrap()
+    );
+    let interp = Interp2D::new(
+        x.clone(),
+        y.clone(),
+        f_xy,
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    for (i, &x_i) in x.iter().enumerate() {
+        let row = interp.row_interp(i).unwrap();
+        for y in [0.1, 0.17, 0.25, 0.33, 0.45] {
+            assert_eq!(
+                row.interpolate(&[y]).unwrap(),
+                interp.interpolate(&[x_i, y]).unwrap()
+            );
+        }
+    }
+    for (j, &y_j) in y.iter().enumerate() {
+        let column = interp.column_interp(j).unwrap();
+        for x in [0.05, 0.07, 0.1, 0.12, 0.15] {
+            assert_eq!(
+                column.interpolate(&[x]).unwrap(),
+                interp.interpolate(&[x, y_j]).unwrap()
+            );
+        }
+    }
+    assert!(interp.row_interp(3).is_err());
+    assert!(interp.column_interp(4).is_err());
+}
+
+#[test]
+fn test_texture_data() {
+    let interp = Interp2D::new(
+        array![0.05, 0.10, 0.15],
+        array![0.10, 0.20],
+        array![[0., 1.], [2., 3.], [4., 5.]],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let (buffer, dims) = interp.to_texture_data();
+    assert_eq!(dims, [3, 2]);
+    assert_eq!(buffer, vec![0., 1., 2., 3., 4., 5.]);
+    // Row-major order regardless of memory layout
+    let values_t = array![[0., 2., 4.], [1., 3., 5.]];
+    let interp_t = Interp2D::new(
+        array![0.05, 0.10, 0.15],
+        array![0.10, 0.20],
+        values_t.reversed_axes(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let (buffer_t, dims_t) = interp_t.to_texture_data();
+    assert_eq!(dims_t, dims);
+    assert_eq!(buffer_t, buffer);
+    for (i, j) in [(0, 0), (1, 1), (2, 0)] {
+        assert_eq!(buffer[i * dims[1] + j] as f64, interp.data.values[[i, j]]);
+    }
+}
+
+#[test]
+fn test_angular_axis() {
+    // Values are the heading itself, as a signed angle in (-180, 180]
+    let heading = array![10., 90., 180., 270., 350.];
+    let signed = heading.mapv(|h: f64| if h > 180. { h - 360. } else { h });
+    let interp = Interp2D::new(
+        array![0., 1.],
+        heading,
+        ndarray::stack(Axis(0), &[signed.view(), signed.view()]).unwrap(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let mut angular = interp.with_angular_axis(1, 360.).unwrap();
+    angular.validate().unwrap();
+    // Halfway between 350° and 10° is the seam, not 180°
+    assert_eq!(angular.interpolate(&[0.5, 0.]).unwrap(), 0.);
+    assert_eq!(angular.interpolate(&[0.5, 360.]).unwrap(), 0.);
+    assert_eq!(angular.interpolate(&[0.5, -720.]).unwrap(), 0.);
+    assert_eq!(angular.interpolate(&[0.5, 355.]).unwrap(), -5.);
+    assert_eq!(angular.interpolate(&[0.5, 370.]).unwrap(), 10.);
+    // Non-angular axis is unaffected
+    assert!(angular.interpolate(&[2., 0.]).is_err());
+    // Grid already closing the seam is not padded
+    let closed = Interp2D::new(
+        array![0., 1.],
+        array![0., 180., 360.],
+        array![[0., 1., 0.], [0., 1., 0.]],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap()
+    .with_angular_axis(1, 360.)
+    .unwrap();
+    assert_eq!(closed.interpolator.data.grid[1].len(), 3);
+    assert_eq!(closed.interpolate(&[0., 450.]).unwrap(), 0.5);
+    // Grid spanning more than the period
+    assert!(interp.with_angular_axis(1, 180.).is_err());
+    assert!(interp.with_angular_axis(2, 360.).is_err());
+}
+
+#[test]
+fn test_extrapolation_used() {
+    let mut interp = Interp2D::new(
+        array![0., 1., 2.],
+        array![0., 1.],
+        array![[0., 1.], [2., 3.], [4., 5.]],
+        strategy::Linear,
+        Extrapolate::Clamp,
+    )
+    .unwrap();
+    assert_eq!(
+        interp.extrapolation_used(&[1.5, 1.]).unwrap(),
+        ExtrapolationUsed::Interior
+    );
+    assert_eq!(
+        interp.extrapolation_used(&[-1., 0.5]).unwrap(),
+        ExtrapolationUsed::ClampedLow(0)
+    );
+    assert_eq!(
+        interp.extrapolation_used(&[1., 1.5]).unwrap(),
+        ExtrapolationUsed::ClampedHigh(1)
+    );
+    assert!(matches!(
+        interp.extrapolation_used(&[1.]).unwrap_err(),
+        InterpolateError::PointLength(2)
+    ));
+    interp.set_extrapolate(Extrapolate::Error).unwrap();
+    assert_eq!(
+        interp.extrapolation_used(&[3., 0.5]).unwrap(),
+        ExtrapolationUsed::Rejected(0)
+    );
+    interp.set_extrapolate(Extrapolate::Enable).unwrap();
+    assert_eq!(
+        interp.extrapolation_used(&[0.5, -2.]).unwrap(),
+        ExtrapolationUsed::Evaluated(1)
+    );
+}
+
 #[test]
 fn test_partialeq() {
     #[derive(PartialEq)]
@@ -200,3 +604,493 @@ fn test_serde() {
     let de: Interp2DOwned<f64, strategy::Linear> = serde_json::from_str(&ser).unwrap();
     assert_eq!(interp, de);
 }
+
+#[test]
+fn test_single_point_axis() {
+    let interp = Interp2D::new(
+        array![0., 1.],
+        array![0.],
+        array![[1.], [3.]],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(matches!(
+        interp.interpolate(&[0.5, 0.]),
+        Err(InterpolateError::Other(_))
+    ));
+    assert!(interp.gradient(&[0.5, 0.]).is_err());
+    // Wrapping around a single point returns it, rather than dividing by a zero period,
+    // so bracketing strategies error cleanly instead of panicking
+    let interp = Interp2D::new(
+        array![0i64],
+        array![0i64, 2],
+        array![[1i64, 3]],
+        strategy::Nearest,
+        Extrapolate::Wrap,
+    )
+    .unwrap();
+    for point in [[4, 2], [-1, 0], [-1, 3]] {
+        assert!(matches!(
+            interp.interpolate(&point),
+            Err(InterpolateError::Other(_))
+        ));
+    }
+    let interp = Interp3D::new(
+        array![0i64],
+        array![0i64, 2],
+        array![1i64],
+        array![[[1i64], [3]]],
+        strategy::Nearest,
+        Extrapolate::Wrap,
+    )
+    .unwrap();
+    assert!(matches!(
+        interp.interpolate(&[4, 2, -3]),
+        Err(InterpolateError::Other(_))
+    ));
+    let interp = InterpND::new(
+        vec![array![0i64], array![0i64, 2]],
+        array![[1i64, 3]].into_dyn(),
+        strategy::Nearest,
+        Extrapolate::Wrap,
+    )
+    .unwrap();
+    assert_eq!(interp.interpolate(&[4, 2]).unwrap(), 3);
+}
+
+#[test]
+fn test_eval_grid_filled() {
+    let interp = Interp2D::new(
+        array![0., 1., 2.],
+        array![0., 2.],
+        array![[0., 2.], [1., 3.], [2., 4.]],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let xs = array![-1., 0.5, 2., 3.];
+    let ys = array![0., 1., 2.5];
+    let grid = interp.eval_grid_filled(xs.view(), ys.view(), -99.).unwrap();
+    assert_eq!(
+        grid,
+        array![
+            [-99., -99., -99.],
+            [0.5, 1.5, -99.],
+            [2., 3., -99.],
+            [-99., -99., -99.],
+        ]
+    );
+    // Interior cells match per-point interpolation
+    for (i, x) in xs.iter().enumerate().skip(1).take(2) {
+        for (j, y) in ys.iter().enumerate().take(2) {
+            assert_eq!(grid[[i, j]], interp.interpolate(&[*x, *y]).unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_nan_aware_linear() {
+    let x = array![0., 1., 2.];
+    let y = array![0., 2.];
+    let values = array![[1., 3.], [5., f64::NAN], [f64::NAN, f64::NAN]];
+    let interp = Interp2D::new(
+        x.view(),
+        y.view(),
+        values.view(),
+        strategy::Linear::nan_aware(),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // One NaN corner: weights of the other three renormalized
+    let (w_00, w_10, w_01) = (0.75 * 0.5, 0.25 * 0.5, 0.75 * 0.5);
+    assert_approx_eq!(
+        interp.interpolate(&[0.25, 1.]).unwrap(),
+        (w_00 * 1. + w_10 * 5. + w_01 * 3.) / (w_00 + w_10 + w_01)
+    );
+    // Valid nodes return their value
+    assert_eq!(interp.interpolate(&[1., 0.]).unwrap(), 5.);
+    // Point on a NaN node uses the mean of valid corners
+    assert_eq!(interp.interpolate(&[1., 2.]).unwrap(), 3.);
+    assert_eq!(interp.interpolate(&[2., 2.]).unwrap(), 5.);
+    // All corners NaN
+    let masked = Interp2D::new(
+        array![0., 1.],
+        array![0., 1.],
+        Array2::from_elem((2, 2), f64::NAN),
+        strategy::Linear::nan_aware(),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert!(masked.interpolate(&[0.5, 0.5]).unwrap().is_nan());
+    // Without NaNs, identical to `Linear`
+    let values = array![[1., 3.], [5., 4.], [0., 2.]];
+    let nan_aware = Interp2D::new(
+        x.view(),
+        y.view(),
+        values.view(),
+        strategy::Linear::nan_aware(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let linear = Interp2D::new(
+        x.view(),
+        y.view(),
+        values.view(),
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    for point in [[0.25, 1.], [1.5, 0.5], [2.5, -1.]] {
+        assert_approx_eq!(
+            nan_aware.interpolate(&point).unwrap(),
+            linear.interpolate(&point).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_validate_strict() {
+    let x_full = array![0., 1., 2., 3.];
+    let y = array![0., 1., 2.];
+    let values_full = Array2::from_shape_fn((4, 3), |(i, j)| (i * 3 + j) as f64);
+    // Matching views
+    let data = InterpData2D {
+        grid: [x_full.slice(s![1..3]), y.view()],
+        values: values_full.slice(s![1..3, ..]),
+    };
+    assert!(data.validate_strict().is_ok());
+    // Sliced grid paired with full values, matching along the leading dimension
+    let data = InterpData2D {
+        grid: [x_full.view(), y.slice(s![..2])],
+        values: values_full.view(),
+    };
+    assert!(matches!(
+        data.validate_strict(),
+        Err(ValidateError::IncompatibleShapes(1))
+    ));
+    // Broadcast values pass `validate`, but not `validate_strict`
+    let row = array![0., 1., 2.];
+    let data = InterpData2D {
+        grid: [x_full.view(), y.view()],
+        values: row.broadcast((4, 3)).unwrap(),
+    };
+    assert!(data.validate().is_ok());
+    assert!(matches!(
+        data.validate_strict(),
+        Err(ValidateError::Other(_))
+    ));
+    // Repeated grid points
+    let x_repeated = array![0., 1., 1., 2.];
+    let data = InterpData2D {
+        grid: [x_repeated.view(), y.view()],
+        values: values_full.view(),
+    };
+    assert!(data.validate().is_ok());
+    assert!(matches!(
+        data.validate_strict(),
+        Err(ValidateError::Monotonicity(0))
+    ));
+    // N-D
+    let data = InterpDataND {
+        grid: vec![x_full.view(), y.view()],
+        values: row.broadcast((4, 3)).unwrap().into_dyn(),
+    };
+    assert!(data.validate().is_ok());
+    assert!(data.validate_strict().is_err());
+}
+
+#[test]
+fn test_bicubic() {
+    let f = |x: f64, y: f64| x.sin() * y.cos();
+    // Zero second derivatives at the edges, matching the natural spline boundary conditions
+    let (pi, half_pi) = (std::f64::consts::PI, std::f64::consts::FRAC_PI_2);
+    let x = Array1::linspace(0., pi, 7);
+    let y = Array1::linspace(-half_pi, half_pi, 7);
+    let f_xy = Array2::from_shape_fn((7, 7), |(i, j)| f(x[i], y[j]));
+    let bicubic = Interp2D::new(
+        x.clone(),
+        y.clone(),
+        f_xy.clone(),
+        strategy::Bicubic::new(),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    let bilinear = Interp2D::new(
+        x.clone(),
+        y.clone(),
+        f_xy,
+        strategy::Linear,
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    // Grid points are reproduced
+    assert_approx_eq!(bicubic.interpolate(&[x[2], y[5]]).unwrap(), f(x[2], y[5]));
+    let (x_samples, y_samples) = (
+        Array1::linspace(0., pi, 31),
+        Array1::linspace(-half_pi, half_pi, 31),
+    );
+    let (mut bicubic_error, mut bilinear_error) = (0f64, 0f64);
+    for &x in &x_samples {
+        for &y in &y_samples {
+            let expected = f(x, y);
+            bicubic_error =
+                bicubic_error.max((bicubic.interpolate(&[x, y]).unwrap() - expected).abs());
+            bilinear_error =
+                bilinear_error.max((bilinear.interpolate(&[x, y]).unwrap() - expected).abs());
+        }
+    }
+    assert!(bicubic_error < bilinear_error / 50.);
+    // Linear tails beyond the grid
+    let edge = bicubic.interpolate(&[pi, 0.5]).unwrap();
+    let step = bicubic.interpolate(&[pi + 0.5, 0.5]).unwrap() - edge;
+    assert_approx_eq!(
+        bicubic.interpolate(&[pi + 1., 0.5]).unwrap() - edge,
+        2. * step
+    );
+    // Too few grid points
+    assert!(Interp2D::new(
+        array![0.],
+        array![0., 1.],
+        array![[0., 1.]],
+        strategy::Bicubic::new(),
+        Extrapolate::Error,
+    )
+    .is_err());
+}
+
+#[test]
+fn test_bicubic_periodic() {
+    let two_pi = 2. * std::f64::consts::PI;
+    let f = |theta: f64, r: f64| r * theta.sin() + (1. + r) * (2. * theta).cos();
+    let theta = Array1::linspace(0., two_pi, 17);
+    let r = array![0., 0.5, 1., 2.];
+    let f_xy = Array2::from_shape_fn((17, 4), |(i, j)| f(theta[i], r[j]));
+    let periodic = Interp2D::new(
+        theta.clone(),
+        r.clone(),
+        f_xy.clone(),
+        strategy::Bicubic::periodic(0),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    // Same surface with the periodic axis second
+    let transposed = Interp2D::new(
+        r.clone(),
+        theta.clone(),
+        f_xy.t().to_owned(),
+        strategy::Bicubic::periodic(1),
+        Extrapolate::Enable,
+    )
+    .unwrap();
+    // Transposing swaps the periodic axis along with the data
+    assert_eq!(periodic.transpose().unwrap(), transposed);
+    assert_eq!(transposed.transpose().unwrap(), periodic);
+    let boxed: Box<dyn Strategy2D<_>> = Box::new(strategy::Bicubic::periodic(0));
+    let boxed = Interp2D::new(
+        theta.clone(),
+        r.clone(),
+        f_xy.clone(),
+        boxed,
+        Extrapolate::Enable,
+    )
+    .unwrap()
+    .transpose()
+    .unwrap();
+    assert_eq!(
+        boxed.interpolate(&[1.5, -0.1]).unwrap(),
+        transposed.interpolate(&[1.5, -0.1]).unwrap()
+    );
+    for r in [0., 0.7, 1.5, 2.] {
+        assert_approx_eq!(
+            periodic.interpolate(&[-0.1, r]).unwrap(),
+            periodic.interpolate(&[two_pi - 0.1, r]).unwrap(),
+            1e-12
+        );
+        assert_approx_eq!(
+            periodic.interpolate(&[two_pi + 1., r]).unwrap(),
+            periodic.interpolate(&[1., r]).unwrap(),
+            1e-12
+        );
+        for theta in [-0.1, 0.2, 3., 6.2] {
+            assert_approx_eq!(
+                periodic.interpolate(&[theta, r]).unwrap(),
+                f(theta, r),
+                2e-2
+            );
+            assert_approx_eq!(
+                transposed.interpolate(&[r, theta]).unwrap(),
+                periodic.interpolate(&[theta, r]).unwrap(),
+                1e-12
+            );
+        }
+        // C1 across the seam: one-sided slopes agree
+        let h = 1e-6;
+        let below = (periodic.interpolate(&[two_pi, r]).unwrap()
+            - periodic.interpolate(&[two_pi - h, r]).unwrap())
+            / h;
+        let above =
+            (periodic.interpolate(&[h, r]).unwrap() - periodic.interpolate(&[0., r]).unwrap()) / h;
+        assert_approx_eq!(below, above, 1e-4);
+        assert_approx_eq!(above, r, 2e-2);
+    }
+    // Ends of the periodic axis must match
+    let mut mismatched = f_xy.clone();
+    mismatched[[16, 2]] += 0.1;
+    assert!(Interp2D::new(
+        theta.clone(),
+        r.clone(),
+        mismatched,
+        strategy::Bicubic::periodic(0),
+        Extrapolate::Enable,
+    )
+    .is_err());
+    assert!(Interp2D::new(
+        theta,
+        r,
+        f_xy,
+        strategy::Bicubic::periodic(2),
+        Extrapolate::Enable,
+    )
+    .is_err());
+}
+
+#[test]
+fn test_gradient_plane() {
+    let (a, b, c) = (1.5, -0.25, 3.);
+    let x = array![0., 1., 3., 4.5];
+    let y = array![-1., 0., 2.];
+    let f_xy = Array2::from_shape_fn((4, 3), |(i, j)| a * x[i] + b * y[j] + c);
+    let interp = Interp2D::new(x, y, f_xy, strategy::Linear, Extrapolate::Enable).unwrap();
+    for point in [
+        [0., -1.],
+        [0.5, 0.5],
+        [1., 0.],
+        [3.7, 1.9],
+        [4.5, 2.],
+        [6., -3.],
+    ] {
+        let gradient = interp.gradient(&point).unwrap();
+        assert_approx_eq!(gradient[0], a);
+        assert_approx_eq!(gradient[1], b);
+    }
+}
+
+#[test]
+fn test_descending_grid() {
+    // x descending, y ascending
+    let x = array![2., 1., 0.];
+    let y = array![0., 1.];
+    let f_xy = array![[4., 5.], [2., 3.], [0., 1.]];
+    let mixed = Interp2D::new(
+        x.clone(),
+        y.clone(),
+        f_xy.clone(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let ascending = Interp2D::new(
+        x.slice(s![..;-1]).to_owned(),
+        y,
+        f_xy.slice(s![..;-1, ..]).to_owned(),
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    assert_eq!(mixed.data, ascending.data);
+    for point in [[0., 0.], [0.5, 0.25], [1.2, 0.9], [2., 1.]] {
+        assert_eq!(
+            mixed.interpolate(&point).unwrap(),
+            ascending.interpolate(&point).unwrap()
+        );
+    }
+    assert_eq!(mixed.interpolate(&[1.5, 0.5]).unwrap(), 3.5);
+}
+
+#[test]
+fn test_set_values() {
+    let x = array![0., 1., 2., 3.];
+    let y = array![0., 1., 2.];
+    let f = |a: f64| Array2::from_shape_fn((4, 3), |(i, j)| a * x[i] * x[i] + y[j]);
+    let mut interp = Interp2D::new(
+        x.clone(),
+        y.clone(),
+        f(1.),
+        strategy::Bicubic::new(),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let grid = interp.data.grid.clone();
+    let before = interp.interpolate(&[1.5, 0.5]).unwrap();
+    interp.set_values(f(2.)).unwrap();
+    let rebuilt = Interp2D::new(
+        x.clone(),
+        y.clone(),
+        f(2.),
+        strategy::Bicubic::new(),
+        Extrapolate::Error,
+    )
+    .unwrap();
+    // Cached spline state is refreshed, and the grid untouched
+    assert_eq!(interp, rebuilt);
+    assert_eq!(interp.data.grid, grid);
+    assert_ne!(interp.interpolate(&[1.5, 0.5]).unwrap(), before);
+    assert_eq!(
+        interp.interpolate(&[1.5, 0.5]).unwrap(),
+        rebuilt.interpolate(&[1.5, 0.5]).unwrap()
+    );
+    // Incompatible shapes leave the interpolator unchanged
+    assert!(matches!(
+        interp.set_values(Array2::zeros((4, 2))).unwrap_err(),
+        ValidateError::IncompatibleShapes(1)
+    ));
+    assert!(matches!(
+        interp.set_values(Array2::zeros((3, 3))).unwrap_err(),
+        ValidateError::IncompatibleShapes(0)
+    ));
+    assert_eq!(interp, rebuilt);
+}
+
+#[test]
+fn test_data_accessors() {
+    let interp = Interp2D::new(
+        array![0., 1., 2.],
+        array![-1., 1.],
+        array![[0., 1.], [2., 3.], [4., 5.]],
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    let data = &interp.data;
+    assert_eq!(data.grid().len(), 2);
+    assert_eq!(data.grid()[1], array![-1., 1.]);
+    assert_eq!(data.axis(0).unwrap(), array![0., 1., 2.]);
+    assert_eq!(data.axis(2), None);
+    assert_eq!(
+        data.values(),
+        array![[0., 1.], [2., 3.], [4., 5.]].into_dyn()
+    );
+    assert_eq!(data.shape(), vec![3, 2]);
+}
+
+#[test]
+fn test_from_fn() {
+    let x = array![0., 1., 2.5];
+    let y = array![-1., 0., 2.];
+    let f = |p: &[f64]| p[0] * p[0] + p[0] * p[1];
+    let interp = Interp2D::from_fn(
+        x.clone(),
+        y.clone(),
+        f,
+        strategy::Linear,
+        Extrapolate::Error,
+    )
+    .unwrap();
+    for x_i in x.iter() {
+        for y_j in y.iter() {
+            assert_eq!(interp.interpolate(&[*x_i, *y_j]).unwrap(), f(&[*x_i, *y_j]));
+        }
+    }
+    assert!(Interp2D::from_fn(x, array![], f, strategy::Linear, Extrapolate::Error).is_err());
+}
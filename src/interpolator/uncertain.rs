@@ -0,0 +1,235 @@
+//! 1-D interpolation with propagation of per-point uncertainty.
+
+use super::*;
+
+/// A 1-D linear interpolator over values with a standard deviation at each grid point,
+/// e.g. a table of measurements with their errors.
+///
+/// [`UncertainInterp1D::interpolate`] returns the interpolated value along with its standard deviation,
+/// propagated through the linear weights `w_i` of the bracketing points as `sqrt(sum(w_i^2 * sigma_i^2))`,
+/// assuming independent errors.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::interpolator::uncertain::UncertainInterp1D;
+/// let interp = UncertainInterp1D::new(
+///     array![0., 1.],
+///     array![10., 20.],
+///     array![3., 4.],
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[0.]).unwrap(), (10., 3.));
+/// assert_eq!(interp.interpolate(&[0.5]).unwrap(), (15., 2.5));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct UncertainInterp1D<D>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    /// Interpolator of the central values.
+    pub values: Interp1D<D, strategy::Linear>,
+    /// Standard deviation at each grid point.
+    pub sigma: ArrayBase<D, Ix1>,
+}
+
+// Implemented manually rather than derived, as deriving would require `D: Clone`
+impl<D> Clone for UncertainInterp1D<D>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            sigma: self.sigma.clone(),
+        }
+    }
+}
+
+impl<D> UncertainInterp1D<D>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    /// Construct and validate an uncertain interpolator.
+    ///
+    /// Errors if the values interpolator is invalid,
+    /// or if `sigma_x` does not match the grid length or has non-finite or negative entries.
+    ///
+    /// A strictly decreasing `x` is reversed (see [`InterpData1D::new`]), along with `sigma_x`.
+    #[must_use = "construction can fail validation, which should be handled"]
+    pub fn new(
+        x: ArrayBase<D, Ix1>,
+        f_x: ArrayBase<D, Ix1>,
+        mut sigma_x: ArrayBase<D, Ix1>,
+        extrapolate: Extrapolate<D::Elem>,
+    ) -> Result<Self, ValidateError> {
+        if is_descending(&x) {
+            sigma_x.invert_axis(Axis(0));
+        }
+        let interp = Self {
+            values: Interp1D::new(x, f_x, strategy::Linear, extrapolate)?,
+            sigma: sigma_x,
+        };
+        interp.check_sigma()?;
+        Ok(interp)
+    }
+
+    /// Check length, finiteness, and sign of standard deviations.
+    fn check_sigma(&self) -> Result<(), ValidateError> {
+        if self.sigma.len() != self.values.data.grid[0].len() {
+            return Err(ValidateError::IncompatibleShapes(0));
+        }
+        if let Some(i) = self
+            .sigma
+            .iter()
+            .position(|&s| !is_finite(s) || s < D::Elem::zero())
+        {
+            return Err(ValidateError::InvalidValues(format!(
+                "standard deviations must be finite and non-negative: sigma[{i}] = {:?}",
+                self.sigma[i]
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate the values interpolator and standard deviations.
+    #[must_use = "validation errors should be handled"]
+    pub fn validate(&mut self) -> Result<(), ValidateError>
+    where
+        D::Elem: Euclid,
+    {
+        self.values.validate()?;
+        self.check_sigma()
+    }
+}
+
+impl<D> UncertainInterp1D<D>
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Euclid + Debug,
+{
+    /// Interpolate at `point`, returning `(value, sigma)`.
+    ///
+    /// Out-of-bounds points follow the [`Extrapolate`] setting of [`UncertainInterp1D::values`],
+    /// with `sigma` propagated from the same point the value is evaluated at.
+    /// Fill values are treated as exact, with zero `sigma`.
+    pub fn interpolate(
+        &self,
+        point: &[D::Elem; 1],
+    ) -> Result<(D::Elem, D::Elem), InterpolateError> {
+        let value = self.values.interpolate(point)?;
+        let grid = &self.values.data.grid[0];
+        let (min, max) = (*grid.first().unwrap(), *grid.last().unwrap());
+        let mut x = point[0];
+        if !(min..=max).contains(&x) {
+            match self.values.extrapolate {
+                Extrapolate::Enable | Extrapolate::EnableBounded(_) => {}
+                Extrapolate::Clamp | Extrapolate::NearestValue => x = clamp(x, min, max),
+                Extrapolate::Wrap => x = wrap(x, min, max),
+                Extrapolate::Fill(_) | Extrapolate::SaturatingFill { .. } => {
+                    return Ok((value, D::Elem::zero()))
+                }
+                Extrapolate::Error => unreachable!("out-of-bounds point errors above"),
+            }
+        }
+        if grid.len() == 1 {
+            return Ok((value, self.sigma[0]));
+        }
+        let (x_l, t) = find_bracket(&self.values.data, &x)?;
+        let (w_l, w_u) = (D::Elem::one() - t, t);
+        let (s_l, s_u) = (self.sigma[x_l], self.sigma[x_l + 1]);
+        Ok((
+            value,
+            (w_l * w_l * s_l * s_l + w_u * w_u * s_u * s_u).sqrt(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uncertain() {
+        let interp = UncertainInterp1D::new(
+            array![0., 1., 3.],
+            array![0., 2., 6.],
+            array![0.3, 0.4, 1.2],
+            Extrapolate::Error,
+        )
+        .unwrap();
+        // Nodes give their stored sigma
+        for (x, f, s) in [(0., 0., 0.3), (1., 2., 0.4), (3., 6., 1.2)] {
+            assert_eq!(interp.interpolate(&[x]).unwrap(), (f, s));
+        }
+        // Midpoint adds neighbors in quadrature with weight 1/2
+        let (f, s) = interp.interpolate(&[0.5]).unwrap();
+        assert_eq!(f, 1.);
+        assert_approx_eq!(s, 0.5 * (0.3f64.powi(2) + 0.4f64.powi(2)).sqrt());
+        let (f, s) = interp.interpolate(&[2.5]).unwrap();
+        assert_eq!(f, 5.);
+        assert_approx_eq!(s, (0.0625f64 * 0.16 + 0.5625 * 1.44).sqrt());
+        assert!(interp.interpolate(&[4.]).is_err());
+        // Sigma is reversed along with a descending grid
+        let descending = UncertainInterp1D::new(
+            array![3., 1., 0.],
+            array![6., 2., 0.],
+            array![1.2, 0.4, 0.3],
+            Extrapolate::Error,
+        )
+        .unwrap();
+        for x in [0., 0.5, 1., 2.5, 3.] {
+            assert_eq!(
+                descending.interpolate(&[x]).unwrap(),
+                interp.interpolate(&[x]).unwrap()
+            );
+        }
+        // Extrapolation
+        let clamped = UncertainInterp1D {
+            values: interp
+                .values
+                .clone()
+                .with_extrapolate(Extrapolate::Clamp)
+                .unwrap(),
+            ..interp.clone()
+        };
+        assert_eq!(clamped.interpolate(&[4.]).unwrap(), (6., 1.2));
+        let filled = UncertainInterp1D {
+            values: interp
+                .values
+                .clone()
+                .with_extrapolate(Extrapolate::Fill(-1.))
+                .unwrap(),
+            ..interp
+        };
+        assert_eq!(filled.interpolate(&[4.]).unwrap(), (-1., 0.));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            UncertainInterp1D::new(
+                array![0., 1.],
+                array![0., 1.],
+                array![0.1],
+                Extrapolate::Error
+            ),
+            Err(ValidateError::IncompatibleShapes(0))
+        ));
+        for sigma_x in [
+            array![0.1, -0.1],
+            array![f64::NAN, 0.1],
+            array![0.1, f64::INFINITY],
+        ] {
+            assert!(matches!(
+                UncertainInterp1D::new(array![0., 1.], array![0., 1.], sigma_x, Extrapolate::Error),
+                Err(ValidateError::InvalidValues(_))
+            ));
+        }
+    }
+}
@@ -25,9 +25,33 @@ where
     ///     const_value
     /// );
     /// ```
+    #[must_use]
     pub fn new(value: T) -> Self {
         Self(value)
     }
+
+    /// Construct a constant-value 'interpolator' from a 0-dimensional array,
+    /// for uniformity with higher-dimensional interpolators constructed from [`ArrayBase`]s.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// let interp = Interp0D::from_array0(arr0(0.5));
+    /// assert_eq!(interp.interpolate(&[]).unwrap(), 0.5);
+    /// assert_eq!(interp.to_array0(), arr0(0.5));
+    /// ```
+    pub fn from_array0(value: Array0<T>) -> Self {
+        Self(value.into_scalar())
+    }
+
+    /// Return the contained value as a 0-dimensional array.
+    pub fn to_array0(&self) -> Array0<T>
+    where
+        T: Clone,
+    {
+        arr0(self.0.clone())
+    }
 }
 impl<T> Interpolator<T> for Interp0D<T>
 where
@@ -55,17 +79,79 @@ where
         Ok(self.0.clone())
     }
 
+    /// Equivalent to [`Interp0D::interpolate`].
+    #[inline]
+    fn interpolate_clamped(&self, point: &[T]) -> Result<T, InterpolateError> {
+        self.interpolate(point)
+    }
+
     /// Returns `Ok(())`.
     #[inline]
     fn set_extrapolate(&mut self, _extrapolate: Extrapolate<T>) -> Result<(), ValidateError> {
         Ok(())
     }
+
+    /// Returns `true`.
+    #[inline]
+    fn is_contiguous(&self) -> bool {
+        true
+    }
+
+    /// Returns `None`, as there are no axes.
+    #[inline]
+    fn domain_bounds(&self, _dim: usize) -> Option<(T, T)> {
+        None
+    }
+
+    /// Returns a 0-dimensional view of the contained value.
+    #[inline]
+    fn values_view(&self) -> Option<ArrayViewD<'_, T>> {
+        ArrayView::from_shape(IxDyn(&[]), std::slice::from_ref(&self.0)).ok()
+    }
+
+    fn to_owned_boxed(&self) -> Option<Box<dyn Interpolator<T>>>
+    where
+        T: 'static,
+    {
+        Some(Box::new(self.clone()))
+    }
 }
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
     use super::*;
 
+    #[test]
+    fn test_dispatch() {
+        let concrete = Interp0D::new(0.5);
+        let enumerated = enums::InterpolatorEnumOwned::new_0d(0.5);
+        let boxed: Box<dyn Interpolator<f64>> = Box::new(concrete.clone());
+        let interps: [&dyn Interpolator<f64>; 3] = [&concrete, &enumerated, &boxed];
+        for interp in interps {
+            assert_eq!(interp.ndim(), 0);
+            assert_eq!(interp.interpolate(&[]).unwrap(), 0.5);
+            assert_eq!(interp.interpolate_clamped(&[]).unwrap(), 0.5);
+            assert_eq!(interp.domain_span(0), None);
+            assert_eq!(interp.domain_volume(), 1.);
+            assert!(matches!(
+                interp.interpolate(&[1.]).unwrap_err(),
+                InterpolateError::PointLength(0)
+            ));
+            assert!(matches!(
+                interp.interpolate_clamped(&[1., 2.]).unwrap_err(),
+                InterpolateError::PointLength(0)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_array0() {
+        let interp = Interp0D::from_array0(Array0::from_elem((), 0.5));
+        assert_eq!(interp, Interp0D::new(0.5));
+        assert_eq!(interp.interpolate(&[]).unwrap(), 0.5);
+        assert_eq!(interp.to_array0().into_scalar(), 0.5);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn test_serde() {
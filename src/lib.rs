@@ -9,37 +9,51 @@
 ///   - [`Interp3D`](`interpolator::Interp3D`)
 ///   - [`InterpND`](`interpolator::InterpND`)
 ///   - A `serde`-compatible interpolator enum [`InterpolatorEnum`](`interpolator::enums::InterpolatorEnum`)
-///   - `Owned` and `Viewed` type aliases for all of the above
+///   - `Owned`, `Viewed`, and `Cow` (owned or borrowed, see [`ndarray::CowRepr`]) type aliases for all of the above
 /// - Their common trait: [`Interpolator`]
 /// - The [`strategy`] mod, containing pre-defined interpolation strategies:
-///   - [`strategy::Linear`]
+///   - [`strategy::Linear`], and [`strategy::NanAwareLinear`] for gappy 2-D data
 ///   - [`strategy::Nearest`]
 ///   - [`strategy::LeftNearest`]
 ///   - [`strategy::RightNearest`]
+///   - [`strategy::LinearTail`], extending any of the above linearly beyond the grid
+///   - [`strategy::Geometric`]
+///   - [`strategy::PolyFit`]
+///   - [`strategy::Newton`]
+///   - [`strategy::Pchip`]
+///   - [`strategy::Bicubic`]
+///   - [`strategy::StaircaseLinear`]
 ///   - `serde`-compatible strategy enums: [`strategy::enums::Strategy1DEnum`]/etc.
 /// - The extrapolation setting enum: [`Extrapolate`]
+/// - Construction macros: [`interp1d!`], [`interp2d!`], and their `Result`-returning forms
 pub mod prelude {
     pub use crate::strategy;
 
+    pub use crate::{interp1d, interp2d, try_interp1d, try_interp2d};
+
     pub use crate::interpolator::{Extrapolate, Interpolator};
 
     pub use crate::interpolator::Interp0D;
-    pub use crate::interpolator::{Interp1D, Interp1DOwned, Interp1DViewed};
-    pub use crate::interpolator::{Interp2D, Interp2DOwned, Interp2DViewed};
-    pub use crate::interpolator::{Interp3D, Interp3DOwned, Interp3DViewed};
-    pub use crate::interpolator::{InterpND, InterpNDOwned, InterpNDViewed};
+    pub use crate::interpolator::{Interp1D, Interp1DCow, Interp1DOwned, Interp1DViewed};
+    pub use crate::interpolator::{Interp2D, Interp2DCow, Interp2DOwned, Interp2DViewed};
+    pub use crate::interpolator::{Interp3D, Interp3DCow, Interp3DOwned, Interp3DViewed};
+    pub use crate::interpolator::{InterpND, InterpNDCow, InterpNDOwned, InterpNDViewed};
 
     pub use crate::interpolator::enums::{
-        InterpolatorEnum, InterpolatorEnumOwned, InterpolatorEnumViewed,
+        InterpolatorEnum, InterpolatorEnumCow, InterpolatorEnumOwned, InterpolatorEnumViewed,
     };
 }
 
 pub mod error;
 pub mod strategy;
 
+mod macros;
+
 pub mod interpolator;
+pub mod recommend;
 pub use interpolator::data;
 pub(crate) use interpolator::data::*;
+#[allow(unused_imports)] // used for intra-doc links
 pub(crate) use interpolator::*;
 
 pub(crate) use error::*;
@@ -49,10 +63,10 @@ pub(crate) use std::fmt::Debug;
 
 pub use ndarray;
 pub(crate) use ndarray::prelude::*;
-pub(crate) use ndarray::{Data, Ix, OwnedRepr, RawDataClone, ViewRepr};
+pub(crate) use ndarray::{CowRepr, Data, Ix, OwnedRepr, RawDataClone, ViewRepr};
 
 pub use num_traits;
-pub(crate) use num_traits::{clamp, Euclid, Num, One};
+pub(crate) use num_traits::{clamp, AsPrimitive, Euclid, Float, Num, One, Zero};
 
 pub(crate) use dyn_clone::*;
 
@@ -77,14 +91,73 @@ macro_rules! assert_approx_eq {
 pub(crate) use assert_approx_eq;
 
 /// Wrap value around data bounds.
-/// Assumes `min` < `max`.
+/// Assumes `min` <= `max`. For a single point (`min == max`), there is nothing to wrap around,
+/// so `min` is returned rather than dividing by a zero period.
 pub(crate) fn wrap<T: Num + Euclid + Copy>(input: T, min: T, max: T) -> T {
+    if min == max {
+        return min;
+    }
     min + (input - min).rem_euclid(&(max - min))
 }
 
+/// Is `input` finite, i.e. neither infinite nor NaN?
+/// For types without such values, always `true`.
+pub(crate) fn is_finite<T: Num + Copy>(input: T) -> bool {
+    // `input * 0` is NaN, rather than zero, for infinite or NaN `input`
+    (input * T::zero()).is_zero()
+}
+
+/// Distance of `input` beyond the interval `[min, max]`, assuming it lies outside.
+pub(crate) fn distance_beyond<T: Num + PartialOrd + Copy>(input: T, min: T, max: T) -> T {
+    if input < min {
+        min - input
+    } else {
+        input - max
+    }
+}
+
+/// Is `input` more than `limit` beyond the interval `[min, max]`, assuming it lies outside?
+/// Also `true` if the distance and limit are incomparable, e.g. for a NaN limit,
+/// so that an invalid limit rejects rather than admits the point.
+pub(crate) fn beyond_limit<T: Num + PartialOrd + Copy>(input: T, min: T, max: T, limit: T) -> bool {
+    !matches!(
+        distance_beyond(input, min, max).partial_cmp(&limit),
+        Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal)
+    )
+}
+
+/// Index of the grid point nearest to `target`, preferring the lower index on ties.
+/// Assumes `grid` is non-empty and sorted.
+pub(crate) fn nearest_index<T: Num + PartialOrd + Copy>(grid: ArrayView1<T>, target: T) -> usize {
+    if &target <= grid.first().unwrap() {
+        return 0;
+    }
+    if &target >= grid.last().unwrap() {
+        return grid.len() - 1;
+    }
+    let lower = find_nearest_index(grid, &target);
+    if target - grid[lower] <= grid[lower + 1] - target {
+        lower
+    } else {
+        lower + 1
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::wrap;
+    use super::*;
+
+    #[test]
+    fn test_nearest_index() {
+        let grid = array![0., 1., 3.];
+        assert_eq!(nearest_index(grid.view(), -1.), 0);
+        assert_eq!(nearest_index(grid.view(), 0.4), 0);
+        assert_eq!(nearest_index(grid.view(), 0.5), 0);
+        assert_eq!(nearest_index(grid.view(), 0.6), 1);
+        assert_eq!(nearest_index(grid.view(), 2.5), 2);
+        assert_eq!(nearest_index(grid.view(), 4.), 2);
+        assert_eq!(nearest_index(array![2.].view(), 4.), 0);
+    }
 
     #[test]
     fn test_wrap() {
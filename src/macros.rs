@@ -0,0 +1,185 @@
+//! Declarative macros for concise interpolator construction.
+
+/// Construct an [`Interp1D`](crate::interpolator::Interp1D) from inline data,
+/// returning the [`Result`] of [`Interp1D::new`](crate::interpolator::Interp1D::new).
+///
+/// Takes the grid `x` and values `f` as in [`ndarray::array!`],
+/// followed by the name of a unit strategy in [`strategy`](crate::strategy)
+/// and an [`Extrapolate`](crate::interpolator::Extrapolate) variant.
+/// See [`interp1d!`](crate::interp1d!) for a panicking form.
+///
+/// # Example:
+/// ```
+/// use ninterp::prelude::*;
+/// let interp = try_interp1d!(x = [0., 1., 2.], f = [0., 10., 0.], Linear, Fill(-1.)).unwrap();
+/// assert_eq!(interp.interpolate(&[0.5]).unwrap(), 5.);
+/// assert_eq!(interp.interpolate(&[3.]).unwrap(), -1.);
+/// // Unsorted grid
+/// assert!(try_interp1d!(x = [1., 0., 2.], f = [0., 1., 2.], Linear, Error).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_interp1d {
+    (
+        x = [$($x:expr),* $(,)?],
+        f = [$($f:expr),* $(,)?],
+        $strategy:ident,
+        $extrapolate:ident $(($fill:expr))? $(,)?
+    ) => {
+        $crate::interpolator::Interp1D::new(
+            $crate::ndarray::array![$($x),*],
+            $crate::ndarray::array![$($f),*],
+            $crate::strategy::$strategy,
+            $crate::interpolator::Extrapolate::$extrapolate $(($fill))?,
+        )
+    };
+}
+
+/// Construct an [`Interp1D`](crate::interpolator::Interp1D) from inline data,
+/// panicking if it is invalid.
+///
+/// See [`try_interp1d!`](crate::try_interp1d!) for the accepted syntax and a [`Result`]-returning form.
+///
+/// # Example:
+/// ```
+/// use ninterp::prelude::*;
+/// let interp = interp1d!(x = [0., 1., 2.], f = [0., 10., 0.], Nearest, Clamp);
+/// assert_eq!(interp.interpolate(&[0.8]).unwrap(), 10.);
+/// assert_eq!(interp.interpolate(&[3.]).unwrap(), 0.);
+/// ```
+#[macro_export]
+macro_rules! interp1d {
+    ($($args:tt)*) => {
+        $crate::try_interp1d!($($args)*).unwrap()
+    };
+}
+
+/// Construct an [`Interp2D`](crate::interpolator::Interp2D) from inline data,
+/// returning the [`Result`] of [`Interp2D::new`](crate::interpolator::Interp2D::new).
+///
+/// Takes the grids `x` and `y` and nested values `f` as in [`ndarray::array!`],
+/// followed by the name of a unit strategy in [`strategy`](crate::strategy)
+/// and an [`Extrapolate`](crate::interpolator::Extrapolate) variant.
+/// See [`interp2d!`](crate::interp2d!) for a panicking form.
+///
+/// # Example:
+/// ```
+/// use ninterp::prelude::*;
+/// let interp = try_interp2d!(
+///     x = [0., 1.],
+///     y = [0., 1., 2.],
+///     f = [[0., 1., 2.], [3., 4., 5.]],
+///     Linear,
+///     Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[0.5, 1.5]).unwrap(), 3.);
+/// // Values shape does not match grid
+/// assert!(try_interp2d!(x = [0., 1.], y = [0.], f = [[0., 1.], [2., 3.]], Linear, Error).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_interp2d {
+    (
+        x = [$($x:expr),* $(,)?],
+        y = [$($y:expr),* $(,)?],
+        f = [$([$($f:expr),* $(,)?]),* $(,)?],
+        $strategy:ident,
+        $extrapolate:ident $(($fill:expr))? $(,)?
+    ) => {
+        $crate::interpolator::Interp2D::new(
+            $crate::ndarray::array![$($x),*],
+            $crate::ndarray::array![$($y),*],
+            $crate::ndarray::array![$([$($f),*]),*],
+            $crate::strategy::$strategy,
+            $crate::interpolator::Extrapolate::$extrapolate $(($fill))?,
+        )
+    };
+}
+
+/// Construct an [`Interp2D`](crate::interpolator::Interp2D) from inline data,
+/// panicking if it is invalid.
+///
+/// See [`try_interp2d!`](crate::try_interp2d!) for the accepted syntax and a [`Result`]-returning form.
+///
+/// # Example:
+/// ```
+/// use ninterp::prelude::*;
+/// let interp = interp2d!(
+///     x = [0., 1.],
+///     y = [0., 1., 2.],
+///     f = [[0., 1., 2.], [3., 4., 5.]],
+///     Nearest,
+///     Error,
+/// );
+/// assert_eq!(interp.interpolate(&[0.8, 0.4]).unwrap(), 3.);
+/// ```
+#[macro_export]
+macro_rules! interp2d {
+    ($($args:tt)*) => {
+        $crate::try_interp2d!($($args)*).unwrap()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ValidateError;
+    use crate::prelude::*;
+    use ndarray::prelude::*;
+
+    #[test]
+    fn test_interp1d() {
+        let expected = Interp1D::new(
+            array![0., 1., 3.],
+            array![1., -1., 2.],
+            strategy::Linear,
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        let interp = interp1d!(x = [0., 1., 3.], f = [1., -1., 2.], Linear, Enable);
+        assert_eq!(interp, expected);
+        let interp = try_interp1d!(x = [0., 1., 3.], f = [1., -1., 2.], Linear, Enable).unwrap();
+        assert_eq!(interp, expected);
+        assert_eq!(interp.interpolate(&[2.]).unwrap(), 0.5);
+        // Extrapolation not applicable to strategy
+        assert!(matches!(
+            try_interp1d!(x = [0., 1.], f = [0., 1.], Nearest, Enable),
+            Err(ValidateError::ExtrapolateSelection(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interp1d_invalid() {
+        interp1d!(x = [0., 1.], f = [0., 1., 2.], Linear, Error);
+    }
+
+    #[test]
+    fn test_interp2d() {
+        let expected = Interp2D::new(
+            array![0., 1.],
+            array![0., 2.],
+            array![[0., 2.], [1., 3.]],
+            strategy::Linear,
+            Extrapolate::Fill(f64::NAN),
+        )
+        .unwrap();
+        let interp = interp2d!(
+            x = [0., 1.],
+            y = [0., 2.],
+            f = [[0., 2.], [1., 3.]],
+            Linear,
+            Fill(f64::NAN),
+        );
+        assert_eq!(interp.data, expected.data);
+        assert_eq!(interp.interpolate(&[0.5, 1.]).unwrap(), 1.5);
+        assert!(interp.interpolate(&[2., 1.]).unwrap().is_nan());
+        let interp = try_interp2d!(
+            x = [0., 1.],
+            y = [0., 2.],
+            f = [[0., 2.], [1., 3.]],
+            Linear,
+            Fill(f64::NAN)
+        )
+        .unwrap();
+        assert_eq!(interp.data, expected.data);
+    }
+}
@@ -0,0 +1,155 @@
+//! Heuristic selection of an interpolation strategy from the data.
+//!
+//! These are rules of thumb for choosing a reasonable default, not guarantees of accuracy.
+
+use super::*;
+
+/// Strategy suggested by [`suggest_strategy_1d`].
+///
+/// Convert into a boxed strategy with [`From`], e.g. to construct an [`Interp1D`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Suggestion1D {
+    /// [`strategy::Linear`]
+    Linear,
+    /// [`strategy::Pchip`]
+    Pchip,
+    /// [`strategy::Nearest`]
+    Nearest,
+}
+
+impl<D> From<Suggestion1D> for Box<dyn Strategy1D<D>>
+where
+    D: Data + RawDataClone,
+    D::Elem: Float + Debug + 'static,
+{
+    fn from(suggestion: Suggestion1D) -> Self {
+        match suggestion {
+            Suggestion1D::Linear => Box::new(strategy::Linear),
+            Suggestion1D::Pchip => Box::new(strategy::Pchip::new()),
+            Suggestion1D::Nearest => Box::new(strategy::Nearest),
+        }
+    }
+}
+
+/// Suggest a strategy for 1-D data.
+///
+/// The data are characterized by their slopes between consecutive grid points,
+/// and by their second differences, i.e. the changes in slope:
+/// - steps: at least half of consecutive values are equal, but not all of them
+/// - noise: second differences alternate in sign for more than half of consecutive pairs
+/// - monotonicity: slopes all share a sign
+/// - smoothness: second differences total at most half of the slopes, in magnitude
+///
+/// Rules, in order:
+/// 1. Steps suggest [`Suggestion1D::Nearest`], which preserves the steps rather than blurring them.
+/// 2. Fewer than 4 points are too few to tell, and suggest [`Suggestion1D::Linear`].
+/// 3. Noise suggests [`Suggestion1D::Linear`], which does not amplify it.
+/// 4. Monotone or smooth data suggest [`Suggestion1D::Pchip`],
+///    which follows curvature without overshooting the data.
+/// 5. Otherwise, [`Suggestion1D::Linear`].
+///
+/// Cubic splines are not available, so are never suggested.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::recommend::{suggest_strategy_1d, Suggestion1D};
+/// let data = ninterp::data::InterpData1D::new(
+///     array![0., 1., 2., 3., 4., 5.],
+///     array![0., 0., 0., 1., 1., 1.],
+/// )
+/// .unwrap();
+/// assert_eq!(suggest_strategy_1d(&data), Suggestion1D::Nearest);
+/// ```
+pub fn suggest_strategy_1d<D>(data: &InterpData1D<D>) -> Suggestion1D
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    let (x, f_x) = (&data.grid[0], &data.values);
+    let n = f_x.len();
+    let zero = D::Elem::zero();
+    let flat = f_x.windows(2).into_iter().filter(|w| w[0] == w[1]).count();
+    if n > 1 && flat < n - 1 && 2 * flat >= n - 1 {
+        return Suggestion1D::Nearest;
+    }
+    if n < 4 {
+        return Suggestion1D::Linear;
+    }
+    let slopes: Vec<_> = (0..n - 1)
+        .map(|i| (f_x[i + 1] - f_x[i]) / (x[i + 1] - x[i]))
+        .collect();
+    let second_diffs: Vec<_> = slopes.windows(2).map(|w| w[1] - w[0]).collect();
+    let alternations = second_diffs
+        .windows(2)
+        .filter(|w| w[0] * w[1] < zero)
+        .count();
+    if 2 * alternations > second_diffs.len() - 1 {
+        return Suggestion1D::Linear;
+    }
+    let monotone = slopes.iter().all(|&s| s >= zero) || slopes.iter().all(|&s| s <= zero);
+    let abs_sum = |v: &[D::Elem]| {
+        v.iter()
+            .fold(zero, |sum, &v| if v < zero { sum - v } else { sum + v })
+    };
+    let smooth = abs_sum(&second_diffs) + abs_sum(&second_diffs) <= abs_sum(&slopes);
+    if monotone || smooth {
+        Suggestion1D::Pchip
+    } else {
+        Suggestion1D::Linear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggest(f_x: Array1<f64>) -> Suggestion1D {
+        let x = Array1::range(0., f_x.len() as f64, 1.);
+        suggest_strategy_1d(&InterpData1D::new(x, f_x).unwrap())
+    }
+
+    #[test]
+    fn test_suggest_strategy_1d() {
+        // Smooth and monotone
+        assert_eq!(
+            suggest(Array1::range(0., 2., 0.1).mapv(f64::exp)),
+            Suggestion1D::Pchip
+        );
+        // Smooth, not monotone
+        assert_eq!(
+            suggest(Array1::range(0., 6., 0.3).mapv(f64::sin)),
+            Suggestion1D::Pchip
+        );
+        // Noisy
+        assert_eq!(
+            suggest(array![0.1, -0.3, 0.25, 0.05, -0.2, 0.4, -0.1, 0.15]),
+            Suggestion1D::Linear
+        );
+        // Noisy, but monotone
+        assert_eq!(
+            suggest(array![0., 1.2, 1.9, 3.3, 3.8, 5.2, 5.9]),
+            Suggestion1D::Linear
+        );
+        // Neither noisy, smooth, nor monotone
+        assert_eq!(
+            suggest(array![0., 1., 2., 0., -2., -1., 0., 2.]),
+            Suggestion1D::Linear
+        );
+        // Steps
+        assert_eq!(
+            suggest(array![1., 1., 1., 4., 4., 2., 2., 2.]),
+            Suggestion1D::Nearest
+        );
+        // Constant, or too short to tell
+        assert_eq!(suggest(array![3., 3., 3.]), Suggestion1D::Linear);
+        assert_eq!(suggest(array![3.]), Suggestion1D::Linear);
+        // Suggestions are usable strategies
+        let x = Array1::range(0., 2., 0.1);
+        let f_x = x.mapv(f64::exp);
+        let strategy: Box<dyn Strategy1D<_>> = suggest(f_x.clone()).into();
+        let interp = Interp1D::new(x, f_x, strategy, Extrapolate::Error).unwrap();
+        assert_approx_eq!(interp.interpolate(&[0.55]).unwrap(), 0.55f64.exp(), 1e-4);
+    }
+}
@@ -186,4 +186,50 @@ mod tests {
         assert_eq!(interp.interpolate(&[0.09, 0.19, 0.22]).unwrap(), 12.);
         assert_eq!(interp.interpolate(&[0.09, 0.19, 0.31]).unwrap(), 13.);
     }
+
+    #[test]
+    fn test_box_conversion() {
+        type Data = ndarray::OwnedRepr<f64>;
+        let x = array![0., 1., 3.];
+        let f_x = array![2., -1., 4.];
+        let enumed = Interp1D::new(
+            x.clone(),
+            f_x.clone(),
+            Strategy1DEnum::from(strategy::Linear),
+            Extrapolate::Enable,
+        )
+        .unwrap();
+        let boxed: Box<dyn Strategy1D<Data>> = Strategy1DEnum::from(strategy::Linear).into();
+        let boxed = Interp1D::new(x, f_x, boxed, Extrapolate::Enable).unwrap();
+        for point in [-1., 0., 0.5, 2., 3., 4.] {
+            assert_eq!(
+                boxed.interpolate(&[point]).unwrap(),
+                enumed.interpolate(&[point]).unwrap()
+            );
+        }
+        // Back to an enum
+        assert_eq!(
+            Strategy1DEnum::try_from(boxed.strategy).unwrap(),
+            Strategy1DEnum::Linear(strategy::Linear)
+        );
+        let boxed: Box<dyn Strategy1D<Data>> = Box::new(strategy::RightNearest);
+        assert_eq!(
+            Strategy1DEnum::try_from(boxed).unwrap(),
+            Strategy1DEnum::RightNearest(strategy::RightNearest)
+        );
+        let boxed: Box<dyn Strategy1D<Data>> = Box::new(Strategy1DEnum::from(strategy::Nearest));
+        assert_eq!(
+            Strategy1DEnum::try_from(boxed).unwrap(),
+            Strategy1DEnum::Nearest(strategy::Nearest)
+        );
+        // Strategies without a variant
+        let boxed: Box<dyn Strategy1D<Data>> = Box::new(strategy::Geometric);
+        assert!(Strategy1DEnum::try_from(boxed).is_err());
+        // Other dimensionalities
+        let boxed: Box<dyn StrategyND<Data>> = StrategyNDEnum::from(strategy::Nearest).into();
+        assert_eq!(
+            StrategyNDEnum::try_from(boxed).unwrap(),
+            StrategyNDEnum::Nearest(strategy::Nearest)
+        );
+    }
 }
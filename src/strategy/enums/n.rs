@@ -24,9 +24,34 @@ impl From<Nearest> for StrategyNDEnum {
     }
 }
 
+impl StrategyNDEnum {
+    /// Name of the currently held strategy variant, e.g. `"Linear"`.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::Linear(_) => "Linear",
+            Self::Nearest(_) => "Nearest",
+        }
+    }
+
+    /// Downcast a strategy (see e.g. [`StrategyND::as_any`]) to a [`StrategyNDEnum`],
+    /// if it is one or one of its variants' strategies.
+    pub(crate) fn from_any(any: &dyn std::any::Any) -> Option<Self> {
+        if let Some(strategy) = any.downcast_ref::<StrategyNDEnum>() {
+            return Some(strategy.clone());
+        }
+        if let Some(strategy) = any.downcast_ref::<Linear>() {
+            return Some(strategy.clone().into());
+        }
+        if let Some(strategy) = any.downcast_ref::<Nearest>() {
+            return Some(strategy.clone().into());
+        }
+        None
+    }
+}
+
 impl<D> StrategyND<D> for StrategyNDEnum
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -51,6 +76,23 @@ where
         }
     }
 
+    #[inline]
+    fn interpolate_with_hint(
+        &self,
+        data: &InterpDataND<D>,
+        point: &[D::Elem],
+        hint: &mut [usize],
+    ) -> Result<D::Elem, InterpolateError> {
+        match self {
+            StrategyNDEnum::Linear(strategy) => {
+                StrategyND::<D>::interpolate_with_hint(strategy, data, point, hint)
+            }
+            StrategyNDEnum::Nearest(strategy) => {
+                StrategyND::<D>::interpolate_with_hint(strategy, data, point, hint)
+            }
+        }
+    }
+
     #[inline]
     fn allow_extrapolate(&self) -> bool {
         match self {
@@ -58,6 +100,53 @@ where
             StrategyNDEnum::Nearest(strategy) => StrategyND::<D>::allow_extrapolate(strategy),
         }
     }
+
+    #[inline]
+    fn is_initialized(&self) -> bool {
+        match self {
+            StrategyNDEnum::Linear(strategy) => StrategyND::<D>::is_initialized(strategy),
+            StrategyNDEnum::Nearest(strategy) => StrategyND::<D>::is_initialized(strategy),
+        }
+    }
+
+    /// Returns `Some`, for downcasting to the enum itself.
+    #[inline]
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+impl<D> From<StrategyNDEnum> for Box<dyn StrategyND<D>>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    /// Box the contained strategy, which can be converted back with [`TryFrom`].
+    fn from(strategy: StrategyNDEnum) -> Self {
+        match strategy {
+            StrategyNDEnum::Linear(strategy) => Box::new(strategy),
+            StrategyNDEnum::Nearest(strategy) => Box::new(strategy),
+        }
+    }
+}
+
+impl<D> TryFrom<Box<dyn StrategyND<D>>> for StrategyNDEnum
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    type Error = ValidateError;
+
+    /// Convert a boxed strategy, if it is a [`StrategyNDEnum`] or one of its variants' strategies.
+    ///
+    /// Other (e.g. custom) strategies have no enum variant, and are an error.
+    fn try_from(strategy: Box<dyn StrategyND<D>>) -> Result<Self, Self::Error> {
+        strategy.as_any().and_then(Self::from_any).ok_or_else(|| {
+            ValidateError::Other(format!(
+                "strategy {strategy:?} has no `StrategyNDEnum` variant"
+            ))
+        })
+    }
 }
 
 #[cfg(test)]
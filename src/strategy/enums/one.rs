@@ -40,9 +40,42 @@ impl From<RightNearest> for Strategy1DEnum {
     }
 }
 
+impl Strategy1DEnum {
+    /// Name of the currently held strategy variant, e.g. `"Linear"`.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::Linear(_) => "Linear",
+            Self::Nearest(_) => "Nearest",
+            Self::LeftNearest(_) => "LeftNearest",
+            Self::RightNearest(_) => "RightNearest",
+        }
+    }
+
+    /// Downcast a strategy (see e.g. [`Strategy1D::as_any`]) to a [`Strategy1DEnum`],
+    /// if it is one or one of its variants' strategies.
+    pub(crate) fn from_any(any: &dyn std::any::Any) -> Option<Self> {
+        if let Some(strategy) = any.downcast_ref::<Strategy1DEnum>() {
+            return Some(strategy.clone());
+        }
+        if let Some(strategy) = any.downcast_ref::<Linear>() {
+            return Some(strategy.clone().into());
+        }
+        if let Some(strategy) = any.downcast_ref::<Nearest>() {
+            return Some(strategy.clone().into());
+        }
+        if let Some(strategy) = any.downcast_ref::<LeftNearest>() {
+            return Some(strategy.clone().into());
+        }
+        if let Some(strategy) = any.downcast_ref::<RightNearest>() {
+            return Some(strategy.clone().into());
+        }
+        None
+    }
+}
+
 impl<D> Strategy1D<D> for Strategy1DEnum
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -75,6 +108,92 @@ where
         }
     }
 
+    #[inline]
+    fn interpolate_batch(
+        &self,
+        data: &InterpData1D<D>,
+        points: &ArrayView1<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        match self {
+            Strategy1DEnum::Linear(strategy) => {
+                Strategy1D::<D>::interpolate_batch(strategy, data, points)
+            }
+            Strategy1DEnum::Nearest(strategy) => {
+                Strategy1D::<D>::interpolate_batch(strategy, data, points)
+            }
+            Strategy1DEnum::LeftNearest(strategy) => {
+                Strategy1D::<D>::interpolate_batch(strategy, data, points)
+            }
+            Strategy1DEnum::RightNearest(strategy) => {
+                Strategy1D::<D>::interpolate_batch(strategy, data, points)
+            }
+        }
+    }
+
+    #[inline]
+    fn interpolate_bracketed(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+        bracket: (usize, D::Elem),
+    ) -> Result<D::Elem, InterpolateError> {
+        match self {
+            Strategy1DEnum::Linear(strategy) => {
+                Strategy1D::<D>::interpolate_bracketed(strategy, data, point, bracket)
+            }
+            Strategy1DEnum::Nearest(strategy) => {
+                Strategy1D::<D>::interpolate_bracketed(strategy, data, point, bracket)
+            }
+            Strategy1DEnum::LeftNearest(strategy) => {
+                Strategy1D::<D>::interpolate_bracketed(strategy, data, point, bracket)
+            }
+            Strategy1DEnum::RightNearest(strategy) => {
+                Strategy1D::<D>::interpolate_bracketed(strategy, data, point, bracket)
+            }
+        }
+    }
+
+    #[inline]
+    fn derivative(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        match self {
+            Strategy1DEnum::Linear(strategy) => Strategy1D::<D>::derivative(strategy, data, point),
+            Strategy1DEnum::Nearest(strategy) => Strategy1D::<D>::derivative(strategy, data, point),
+            Strategy1DEnum::LeftNearest(strategy) => {
+                Strategy1D::<D>::derivative(strategy, data, point)
+            }
+            Strategy1DEnum::RightNearest(strategy) => {
+                Strategy1D::<D>::derivative(strategy, data, point)
+            }
+        }
+    }
+
+    #[inline]
+    fn integral(
+        &self,
+        data: &InterpData1D<D>,
+        lower: D::Elem,
+        upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        match self {
+            Strategy1DEnum::Linear(strategy) => {
+                Strategy1D::<D>::integral(strategy, data, lower, upper)
+            }
+            Strategy1DEnum::Nearest(strategy) => {
+                Strategy1D::<D>::integral(strategy, data, lower, upper)
+            }
+            Strategy1DEnum::LeftNearest(strategy) => {
+                Strategy1D::<D>::integral(strategy, data, lower, upper)
+            }
+            Strategy1DEnum::RightNearest(strategy) => {
+                Strategy1D::<D>::integral(strategy, data, lower, upper)
+            }
+        }
+    }
+
     #[inline]
     fn allow_extrapolate(&self) -> bool {
         match self {
@@ -84,6 +203,67 @@ where
             Strategy1DEnum::RightNearest(strategy) => Strategy1D::<D>::allow_extrapolate(strategy),
         }
     }
+
+    #[inline]
+    fn is_initialized(&self) -> bool {
+        match self {
+            Strategy1DEnum::Linear(strategy) => Strategy1D::<D>::is_initialized(strategy),
+            Strategy1DEnum::Nearest(strategy) => Strategy1D::<D>::is_initialized(strategy),
+            Strategy1DEnum::LeftNearest(strategy) => Strategy1D::<D>::is_initialized(strategy),
+            Strategy1DEnum::RightNearest(strategy) => Strategy1D::<D>::is_initialized(strategy),
+        }
+    }
+
+    #[inline]
+    fn continuity(&self) -> u8 {
+        match self {
+            Strategy1DEnum::Linear(strategy) => Strategy1D::<D>::continuity(strategy),
+            Strategy1DEnum::Nearest(strategy) => Strategy1D::<D>::continuity(strategy),
+            Strategy1DEnum::LeftNearest(strategy) => Strategy1D::<D>::continuity(strategy),
+            Strategy1DEnum::RightNearest(strategy) => Strategy1D::<D>::continuity(strategy),
+        }
+    }
+
+    /// Returns `Some`, for downcasting to the enum itself.
+    #[inline]
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+impl<D> From<Strategy1DEnum> for Box<dyn Strategy1D<D>>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    /// Box the contained strategy, which can be converted back with [`TryFrom`].
+    fn from(strategy: Strategy1DEnum) -> Self {
+        match strategy {
+            Strategy1DEnum::Linear(strategy) => Box::new(strategy),
+            Strategy1DEnum::Nearest(strategy) => Box::new(strategy),
+            Strategy1DEnum::LeftNearest(strategy) => Box::new(strategy),
+            Strategy1DEnum::RightNearest(strategy) => Box::new(strategy),
+        }
+    }
+}
+
+impl<D> TryFrom<Box<dyn Strategy1D<D>>> for Strategy1DEnum
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    type Error = ValidateError;
+
+    /// Convert a boxed strategy, if it is a [`Strategy1DEnum`] or one of its variants' strategies.
+    ///
+    /// Other (e.g. custom) strategies have no enum variant, and are an error.
+    fn try_from(strategy: Box<dyn Strategy1D<D>>) -> Result<Self, Self::Error> {
+        strategy.as_any().and_then(Self::from_any).ok_or_else(|| {
+            ValidateError::Other(format!(
+                "strategy {strategy:?} has no `Strategy1DEnum` variant"
+            ))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -24,9 +24,34 @@ impl From<Nearest> for Strategy3DEnum {
     }
 }
 
+impl Strategy3DEnum {
+    /// Name of the currently held strategy variant, e.g. `"Linear"`.
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::Linear(_) => "Linear",
+            Self::Nearest(_) => "Nearest",
+        }
+    }
+
+    /// Downcast a strategy (see e.g. [`Strategy3D::as_any`]) to a [`Strategy3DEnum`],
+    /// if it is one or one of its variants' strategies.
+    pub(crate) fn from_any(any: &dyn std::any::Any) -> Option<Self> {
+        if let Some(strategy) = any.downcast_ref::<Strategy3DEnum>() {
+            return Some(strategy.clone());
+        }
+        if let Some(strategy) = any.downcast_ref::<Linear>() {
+            return Some(strategy.clone().into());
+        }
+        if let Some(strategy) = any.downcast_ref::<Nearest>() {
+            return Some(strategy.clone().into());
+        }
+        None
+    }
+}
+
 impl<D> Strategy3D<D> for Strategy3DEnum
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: Num + PartialOrd + Copy + Debug,
 {
     #[inline]
@@ -58,6 +83,53 @@ where
             Strategy3DEnum::Nearest(strategy) => Strategy3D::<D>::allow_extrapolate(strategy),
         }
     }
+
+    #[inline]
+    fn is_initialized(&self) -> bool {
+        match self {
+            Strategy3DEnum::Linear(strategy) => Strategy3D::<D>::is_initialized(strategy),
+            Strategy3DEnum::Nearest(strategy) => Strategy3D::<D>::is_initialized(strategy),
+        }
+    }
+
+    /// Returns `Some`, for downcasting to the enum itself.
+    #[inline]
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        Some(self)
+    }
+}
+
+impl<D> From<Strategy3DEnum> for Box<dyn Strategy3D<D>>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    /// Box the contained strategy, which can be converted back with [`TryFrom`].
+    fn from(strategy: Strategy3DEnum) -> Self {
+        match strategy {
+            Strategy3DEnum::Linear(strategy) => Box::new(strategy),
+            Strategy3DEnum::Nearest(strategy) => Box::new(strategy),
+        }
+    }
+}
+
+impl<D> TryFrom<Box<dyn Strategy3D<D>>> for Strategy3DEnum
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    type Error = ValidateError;
+
+    /// Convert a boxed strategy, if it is a [`Strategy3DEnum`] or one of its variants' strategies.
+    ///
+    /// Other (e.g. custom) strategies have no enum variant, and are an error.
+    fn try_from(strategy: Box<dyn Strategy3D<D>>) -> Result<Self, Self::Error> {
+        strategy.as_any().and_then(Self::from_any).ok_or_else(|| {
+            ValidateError::Other(format!(
+                "strategy {strategy:?} has no `Strategy3DEnum` variant"
+            ))
+        })
+    }
 }
 
 #[cfg(test)]
@@ -13,6 +13,162 @@ pub mod traits;
 )]
 pub struct Linear;
 
+impl Linear {
+    /// Linear interpolation skipping NaN (masked) values, see [`NanAwareLinear`].
+    pub fn nan_aware() -> NanAwareLinear {
+        NanAwareLinear
+    }
+}
+
+/// Linear interpolation over gappy data, where NaN values are treated as masked.
+///
+/// The weights of the NaN corners of the cell containing a point are dropped,
+/// and the remaining weights renormalized to sum to 1,
+/// so the result uses only valid data.
+/// If the valid corners all have zero weight, i.e. the point lies on masked data,
+/// their unweighted mean is used.
+/// The result is NaN only if all corners are NaN.
+/// Without NaN corners, this is identical to [`Linear`].
+///
+/// Construct with [`Linear::nan_aware`]. Only applicable for 2-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// let interp = Interp2D::new(
+///     array![0., 1.],
+///     array![0., 1.],
+///     array![[0., 1.], [2., f64::NAN]],
+///     strategy::Linear::nan_aware(),
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[0.5, 0.]).unwrap(), 1.);
+/// // Weights 1/4 of the three valid corners, renormalized
+/// assert_eq!(interp.interpolate(&[0.5, 0.5]).unwrap(), 1.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize_unit_struct, Serialize_unit_struct)
+)]
+pub struct NanAwareLinear;
+
+/// Linear interpolation with a deterministic rounding rule, for fixed-point (scaled integer) data.
+///
+/// Computes `f_l + floor((f_u - f_l) * (x - x_l) / (x_u - x_l))` entirely in the element type,
+/// i.e. the quotient is rounded toward negative infinity (see [`Euclid::div_euclid`]).
+/// For integer element types (e.g. values scaled by `1000` and stored as `i64`),
+/// results are therefore bit-identical across platforms,
+/// unlike floating-point [`Linear`] blends, e.g. for lockstep networked simulations.
+///
+/// The product `(f_u - f_l) * (x - x_l)` must not overflow the element type.
+///
+/// Only applicable for 1-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// // values in thousandths
+/// let interp = Interp1D::new(
+///     array![0i64, 3],
+///     array![0i64, 1000],
+///     strategy::FixedLinear,
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[1]).unwrap(), 333);
+/// assert_eq!(interp.interpolate(&[2]).unwrap(), 666);
+/// ```
+#[cfg(feature = "fixed-point")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize_unit_struct, Serialize_unit_struct)
+)]
+pub struct FixedLinear;
+
+/// Base for custom 1-D strategies, implementing [`Strategy1D`]
+/// given only a [`Blend1D`] of the values at the two grid points bracketing each point.
+///
+/// Points coincident with a grid point return its value,
+/// and all others are bracketed with [`find_bracket`],
+/// so the search internals need not be reimplemented.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// use ninterp::strategy::{traits::Blend1D, BaseStrategy1D};
+///
+/// /// Linear blend plus a triangular bump of height `peak` at the middle of each segment
+/// #[derive(Debug, Clone)]
+/// struct Triangular {
+///     peak: f64,
+/// }
+///
+/// impl Blend1D<f64> for Triangular {
+///     fn blend(&self, f_l: f64, f_u: f64, t: f64) -> f64 {
+///         f_l * (1. - t) + f_u * t + self.peak * (1. - (2. * t - 1.).abs())
+///     }
+/// }
+///
+/// let interp = Interp1D::new(
+///     array![0., 1., 3.],
+///     array![0., 2., 2.],
+///     BaseStrategy1D(Triangular { peak: 1. }),
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[1.]).unwrap(), 2.);
+/// assert_eq!(interp.interpolate(&[0.5]).unwrap(), 2.);
+/// assert_eq!(interp.interpolate(&[1.5]).unwrap(), 2.5);
+/// assert_eq!(interp.interpolate(&[2.]).unwrap(), 3.);
+/// // Extrapolation is disallowed unless `Blend1D::EXTRAPOLATE` is set
+/// assert!(Interp1D::new(
+///     array![0., 1.],
+///     array![0., 1.],
+///     BaseStrategy1D(Triangular { peak: 1. }),
+///     Extrapolate::Enable,
+/// )
+/// .is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct BaseStrategy1D<B>(pub B);
+
+/// Geometric interpolation, blending values multiplicatively: `f(x) = f_l^(1 - t) * f_u^t`,
+/// where `t` is the fractional position of `x` between the bracketing grid points.
+///
+/// Suited to quantities that vary by ratios (e.g. concentrations, prices),
+/// being equivalent to linear interpolation of `ln(f)` on a linear x-axis.
+/// Requires strictly positive values, which is checked upon initialization.
+///
+/// Only applicable for 1-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// let interp: Interp1DOwned<f64, _> = Interp1D::new(
+///     array![0., 2.],
+///     array![1., 100.],
+///     strategy::Geometric,
+///     Extrapolate::Enable,
+/// )
+/// .unwrap();
+/// assert!((interp.interpolate(&[1.]).unwrap() - 10.).abs() < 1e-12);
+/// assert!((interp.interpolate(&[4.]).unwrap() - 10_000.).abs() < 1e-8);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize_unit_struct, Serialize_unit_struct)
+)]
+pub struct Geometric;
+
 /// Nearest value interpolation: <https://en.wikipedia.org/wiki/Nearest-neighbor_interpolation>
 ///
 /// # Note
@@ -40,6 +196,354 @@ pub struct LeftNearest;
 )]
 pub struct RightNearest;
 
+/// Wrapper extending a 1-D strategy linearly beyond the grid,
+/// e.g. to give [`Nearest`], [`LeftNearest`], or [`RightNearest`] a constant-slope tail
+/// for [`Extrapolate::Enable`].
+///
+/// Within the grid, the wrapped strategy is used unchanged.
+/// Beyond the grid, the first or last linear segment (between the two outermost grid points)
+/// is extended.
+///
+/// Only applicable for 1-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// let interp = Interp1D::new(
+///     array![0., 1., 2.],
+///     array![0., 4., 6.],
+///     strategy::LinearTail(strategy::Nearest),
+///     Extrapolate::Enable,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[1.2]).unwrap(), 4.);
+/// assert_eq!(interp.interpolate(&[-0.5]).unwrap(), -2.);
+/// assert_eq!(interp.interpolate(&[3.]).unwrap(), 8.);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct LinearTail<S>(pub S);
+
+/// Least-squares polynomial fit: <https://en.wikipedia.org/wiki/Polynomial_regression>
+///
+/// Fits a single global polynomial of degree [`PolyFit::degree`] to all data points upon initialization,
+/// optionally weighting each point's squared residual by [`PolyFit::weights`].
+///
+/// Unlike other strategies, this does **not** pass through the data exactly,
+/// smoothing noisy data into a low-order trend.
+///
+/// Only applicable for 1-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// // f(x) = 2 * x + 1, with some noise
+/// let interp: Interp1DOwned<f64, _> = Interp1D::new(
+///     array![0., 1., 2., 3., 4.],
+///     array![1.1, 2.9, 5.1, 6.9, 9.0],
+///     strategy::PolyFit::new(1),
+///     Extrapolate::Enable,
+/// )
+/// .unwrap();
+/// assert!((interp.interpolate(&[10.]).unwrap() - 21.).abs() < 0.2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct PolyFit<T> {
+    /// Polynomial degree.
+    pub degree: usize,
+    /// Optional per-point weights, must match the number of data points.
+    pub weights: Option<Array1<T>>,
+    /// Fitted coefficients (in ascending order) of the polynomial in the normalized coordinate.
+    pub(crate) coeffs: Array1<T>,
+    /// Coordinate normalization `(shift, scale)`, mapping the grid onto `[-1, 1]`.
+    pub(crate) normalization: Option<(T, T)>,
+}
+
+impl<T> PolyFit<T> {
+    /// Least-squares polynomial fit of the supplied degree.
+    pub fn new(degree: usize) -> Self {
+        Self {
+            degree,
+            weights: None,
+            coeffs: Array1::from_vec(Vec::new()),
+            normalization: None,
+        }
+    }
+
+    /// Weighted least-squares polynomial fit of the supplied degree.
+    pub fn weighted(degree: usize, weights: Array1<T>) -> Self {
+        Self {
+            weights: Some(weights),
+            ..Self::new(degree)
+        }
+    }
+}
+
+/// Newton divided-difference polynomial interpolation: <https://en.wikipedia.org/wiki/Newton_polynomial>
+///
+/// Builds the unique polynomial passing through all data points upon initialization,
+/// evaluated with a nested (Horner-like) scheme.
+/// Points can be appended cheaply with [`Newton::extend`] (`O(n)`),
+/// without recomputing the whole divided-difference table.
+///
+/// High-degree polynomials through many points can oscillate strongly between nodes
+/// ([Runge's phenomenon](https://en.wikipedia.org/wiki/Runge%27s_phenomenon)),
+/// so this is best suited to small datasets.
+///
+/// Only applicable for 1-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// // f(x) = x^2
+/// let interp: Interp1DOwned<f64, _> = Interp1D::new(
+///     array![0., 1., 2.],
+///     array![0., 1., 4.],
+///     strategy::Newton::new(),
+///     Extrapolate::Enable,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[3.]).unwrap(), 9.);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Newton<T> {
+    /// Interpolation nodes, in the order they were added.
+    pub(crate) nodes: Vec<T>,
+    /// Newton form coefficients `f[x_0], f[x_0, x_1], ..., f[x_0, ..., x_n]`.
+    pub(crate) coeffs: Vec<T>,
+    /// Last row of the divided-difference table `f[x_n], f[x_(n-1), x_n], ..., f[x_0, ..., x_n]`,
+    /// retained so that [`Newton::extend`] need not recompute the table.
+    pub(crate) diagonal: Vec<T>,
+}
+
+impl<T> Newton<T> {
+    /// Newton divided-difference polynomial interpolation.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            coeffs: Vec::new(),
+            diagonal: Vec::new(),
+        }
+    }
+}
+
+impl<T> Newton<T>
+where
+    T: Num + PartialOrd + Copy + Debug,
+{
+    /// Append a node `(x, f_x)` to the polynomial in `O(n)`,
+    /// raising its degree by one.
+    ///
+    /// This only updates the strategy, not interpolator data,
+    /// see [`Interp1D::extend`](crate::interpolator::Interp1D::extend) to update both.
+    ///
+    /// Errors if `x` is not finite or coincides with an existing node.
+    pub fn extend(&mut self, x: T, f_x: T) -> Result<(), ValidateError> {
+        if !is_finite(x) {
+            return Err(ValidateError::InvalidValues(format!(
+                "Newton polynomial node must be finite: {x:?}"
+            )));
+        }
+        if self.nodes.contains(&x) {
+            return Err(ValidateError::Other(format!(
+                "node {x:?} already exists in Newton polynomial"
+            )));
+        }
+        let n = self.nodes.len();
+        let mut diagonal = Vec::with_capacity(n + 1);
+        diagonal.push(f_x);
+        for k in 1..=n {
+            diagonal.push((diagonal[k - 1] - self.diagonal[k - 1]) / (x - self.nodes[n - k]));
+        }
+        self.coeffs.push(diagonal[n]);
+        self.diagonal = diagonal;
+        self.nodes.push(x);
+        Ok(())
+    }
+}
+
+/// Bicubic spline interpolation: <https://en.wikipedia.org/wiki/Bicubic_interpolation>
+///
+/// Tensor product of natural cubic splines, for smooth (C2) surfaces from coarse tables.
+/// Upon initialization, a spline is fitted along y for each x grid point,
+/// and its second derivatives cached.
+/// Interpolation evaluates these splines at the y coordinate of the point,
+/// then fits and evaluates a spline along x through the results,
+/// solving a tridiagonal system of size `nx` per point, rather than the `O(1)` work of [`Linear`].
+///
+/// Beyond the grid, each spline is extrapolated linearly with its edge derivative.
+///
+/// With [`Bicubic::periodic`], splines along the given axis are periodic instead,
+/// e.g. for an angular axis: the point is wrapped onto that axis's grid before evaluation,
+/// so [`Extrapolate::Enable`] repeats the surface along it,
+/// and the surface is C2 across the seam.
+///
+/// Only applicable for 2-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// // f(x, y) = x^2 + y
+/// let x = array![0., 1., 2., 3., 4.];
+/// let y = array![0., 1., 2.];
+/// let f_xy = Array2::from_shape_fn((5, 3), |(i, j)| x[i] * x[i] + y[j]);
+/// let interp: Interp2DOwned<f64, _> = Interp2D::new(
+///     x,
+///     y,
+///     f_xy,
+///     strategy::Bicubic::new(),
+///     Extrapolate::Enable,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[3., 1.]).unwrap(), 10.);
+/// assert!((interp.interpolate(&[2.5, 0.5]).unwrap() - 6.75).abs() < 0.05);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Bicubic<T> {
+    /// Axis along which splines are periodic, if any.
+    pub(crate) periodic_axis: Option<usize>,
+    /// Second derivatives along y of the spline through each row of values.
+    pub(crate) second_derivatives: Array2<T>,
+}
+
+impl<T> Bicubic<T> {
+    /// Bicubic spline interpolation.
+    pub fn new() -> Self {
+        Self {
+            periodic_axis: None,
+            second_derivatives: Array2::from_shape_vec((0, 0), Vec::new()).unwrap(),
+        }
+    }
+
+    /// Bicubic spline interpolation, periodic along `axis` with period equal to the extent of its grid.
+    ///
+    /// Values at the first and last grid points along `axis` must match (up to rounding),
+    /// e.g. at angles `0` and `2π`.
+    ///
+    /// # Example:
+    /// ```
+    /// use ndarray::prelude::*;
+    /// use ninterp::prelude::*;
+    /// use std::f64::consts::PI;
+    /// let theta = Array1::linspace(0., 2. * PI, 13);
+    /// let r = array![1., 2.];
+    /// let f_xy = Array2::from_shape_fn((13, 2), |(i, j)| r[j] * theta[i].cos());
+    /// let interp: Interp2DOwned<f64, _> = Interp2D::new(
+    ///     theta,
+    ///     r,
+    ///     f_xy,
+    ///     strategy::Bicubic::periodic(0),
+    ///     Extrapolate::Enable,
+    /// )
+    /// .unwrap();
+    /// let wrapped = interp.interpolate(&[-0.1, 1.5]).unwrap();
+    /// assert!((wrapped - interp.interpolate(&[2. * PI - 0.1, 1.5]).unwrap()).abs() < 1e-12);
+    /// assert!((wrapped - 1.5 * 0.1f64.cos()).abs() < 1e-3);
+    /// ```
+    pub fn periodic(axis: usize) -> Self {
+        Self {
+            periodic_axis: Some(axis),
+            ..Self::new()
+        }
+    }
+}
+
+/// Piecewise cubic Hermite interpolation, preserving monotonicity (PCHIP):
+/// <https://en.wikipedia.org/wiki/Monotone_cubic_interpolation>
+///
+/// The derivative at each grid point is chosen upon initialization per Fritsch & Carlson,
+/// as a weighted harmonic mean of the adjacent secant slopes,
+/// or zero at local extrema and plateaus.
+/// The interpolant is therefore C1, and monotone between each pair of grid points
+/// wherever the data are, so it does not overshoot local extrema at the grid points.
+///
+/// Beyond the grid, the edge values are extrapolated linearly with the edge derivatives,
+/// so monotonicity is preserved there too.
+///
+/// Only applicable for 1-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// let interp: Interp1DOwned<f64, _> = Interp1D::new(
+///     array![0., 1., 2., 3.],
+///     array![0., 0., 1., 1.],
+///     strategy::Pchip::new(),
+///     Extrapolate::Enable,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[1.5]).unwrap(), 0.5);
+/// // Flat at the plateaus, without overshoot
+/// assert_eq!(interp.interpolate(&[0.5]).unwrap(), 0.);
+/// assert_eq!(interp.interpolate(&[4.]).unwrap(), 1.);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Pchip<T> {
+    /// Derivative of the interpolant at each grid point.
+    pub(crate) slopes: Vec<T>,
+}
+
+impl<T> Pchip<T> {
+    /// Piecewise cubic Hermite interpolation, preserving monotonicity.
+    pub fn new() -> Self {
+        Self { slopes: Vec::new() }
+    }
+}
+
+/// Linear interpolation within bins, with a discontinuous jump at each bin edge,
+/// e.g. tax brackets or gear-shift torque curves.
+///
+/// Each of [`StaircaseLinear::breakpoints`] is a grid index `i` starting a new bin:
+/// the segment from `x[i - 1]` to `x[i]` is not blended across,
+/// instead holding the left value `f[i - 1]` up to the jump to `f[i]` at `x[i]`.
+/// The interpolant is therefore right-continuous at `x[i]`, with left limit `f[i - 1]`.
+/// Breakpoints must lie within `1..n` for `n` grid points, which is checked upon initialization.
+/// For a strictly decreasing grid, breakpoints index the grid as supplied,
+/// and are remapped when [`Interp1D::new`] reverses it, with the jump then held from the lower `x`.
+///
+/// Beyond the grid, edge segments are extrapolated linearly, or held if they are jumps.
+///
+/// Only applicable for 1-D interpolation.
+///
+/// # Example:
+/// ```
+/// use ndarray::prelude::*;
+/// use ninterp::prelude::*;
+/// let interp = Interp1D::new(
+///     array![0., 10., 10.5, 20.],
+///     array![0., 100., 200., 300.],
+///     strategy::StaircaseLinear::new(vec![2]),
+///     Extrapolate::Error,
+/// )
+/// .unwrap();
+/// assert_eq!(interp.interpolate(&[5.]).unwrap(), 50.);
+/// assert_eq!(interp.interpolate(&[10.25]).unwrap(), 100.);
+/// assert_eq!(interp.interpolate(&[10.5]).unwrap(), 200.);
+/// assert_eq!(interp.interpolate(&[15.25]).unwrap(), 250.);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct StaircaseLinear {
+    /// Grid indices at which a new bin starts, sorted and deduplicated upon initialization.
+    pub breakpoints: Vec<usize>,
+}
+
+impl StaircaseLinear {
+    /// Linear interpolation with jumps at the supplied grid indices.
+    pub fn new(breakpoints: Vec<usize>) -> Self {
+        Self { breakpoints }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
@@ -4,10 +4,15 @@ use super::*;
 
 /// Find nearest index in `arr` left of `target`
 ///
+/// Returns `0` if `arr` has fewer than 2 points, as no bracketing pair exists.
+///
 /// This method contains code from RouteE Compass, another open-source NREL-developed tool
 /// <https://www.nrel.gov/transportation/route-energy-prediction-model.html>
 /// <https://github.com/NREL/routee-compass/>
 pub fn find_nearest_index<T: PartialOrd>(arr: ArrayView1<T>, target: &T) -> usize {
+    if arr.len() < 2 {
+        return 0;
+    }
     if target == arr.last().unwrap() {
         return arr.len() - 2;
     }
@@ -32,10 +37,65 @@ pub fn find_nearest_index<T: PartialOrd>(arr: ArrayView1<T>, target: &T) -> usiz
     }
 }
 
+/// Check that every grid axis has at least 2 points to bracket a point between.
+///
+/// Strategies call this before indexing the upper bracketing point,
+/// so single-point axes produce an error rather than an out-of-bounds panic.
+pub(crate) fn check_brackets<S>(grid: &[ArrayBase<S, Ix1>]) -> Result<(), InterpolateError>
+where
+    S: Data,
+{
+    match grid.iter().position(|g| g.len() < 2) {
+        Some(dim) => Err(InterpolateError::Other(format!(
+            "at least 2 grid points are required to bracket a point: dim {dim}"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Find the lower bracketing grid index of `point` in `data`,
+/// and the fractional position `t` of `point` between it and the next grid point.
+///
+/// `t` is `0` at the lower grid point and `1` at the upper.
+/// Points beyond the grid use the nearest edge segment, yielding `t` outside `[0, 1]`.
+///
+/// Errors if the grid has fewer than 2 points.
+pub fn find_bracket<D>(
+    data: &InterpData1D<D>,
+    point: &D::Elem,
+) -> Result<(usize, D::Elem), InterpolateError>
+where
+    D: Data + RawDataClone,
+    D::Elem: Num + PartialOrd + Copy + Debug,
+{
+    check_brackets(&data.grid)?;
+    let x = &data.grid[0];
+    let x_l = if point < x.first().unwrap() {
+        0
+    } else if point > x.last().unwrap() {
+        x.len() - 2
+    } else {
+        find_nearest_index(x.view(), point)
+    };
+    Ok((x_l, (*point - x[x_l]) / (x[x_l + 1] - x[x_l])))
+}
+
+/// Blend of the values at the two grid points bracketing a point,
+/// for building custom 1-D strategies with [`BaseStrategy1D`].
+pub trait Blend1D<T>: Debug + Clone {
+    /// Does [`Blend1D::blend`] provision for extrapolation, i.e. `t` outside `[0, 1]`?
+    /// See [`Strategy1D::allow_extrapolate`].
+    const EXTRAPOLATE: bool = false;
+
+    /// Blend the values `f_l` and `f_u` at the lower and upper bracketing grid points,
+    /// given the fractional position `t` of the point between them (see [`find_bracket`]).
+    fn blend(&self, f_l: T, f_u: T, t: T) -> T;
+}
+
 /// 1-D interpolation strategy.
 pub trait Strategy1D<D>: Debug + DynClone
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Initialize strategy struct, with access to interpolation data.
@@ -50,15 +110,125 @@ where
         point: &[D::Elem; 1],
     ) -> Result<D::Elem, InterpolateError>;
 
+    /// Execute interpolation at each of `points` (after handling [`Extrapolate`] setting).
+    ///
+    /// Used by [`Interp1D::interpolate_batch`](crate::interpolator::Interp1D::interpolate_batch).
+    /// Strategies can override this to hoist per-point work out of the loop,
+    /// but results must match calling [`Strategy1D::interpolate`] on each point.
+    /// Defaults to calling [`Strategy1D::interpolate`] on each point.
+    fn interpolate_batch(
+        &self,
+        data: &InterpData1D<D>,
+        points: &ArrayView1<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        points
+            .iter()
+            .map(|point| self.interpolate(data, std::array::from_ref(point)))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Array1::from_vec)
+    }
+
+    /// Execute interpolation (after handling [`Extrapolate`] setting),
+    /// given the `bracket` of `point` as found by [`find_bracket`],
+    /// e.g. to share a single bracket search across interpolators over the same grid.
+    ///
+    /// Used by [`StackedInterp1D`](crate::interpolator::stacked::StackedInterp1D).
+    /// Strategies can override this to skip their own bracket search,
+    /// but results must match calling [`Strategy1D::interpolate`].
+    /// Defaults to calling [`Strategy1D::interpolate`], ignoring `bracket`.
+    fn interpolate_bracketed(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+        _bracket: (usize, D::Elem),
+    ) -> Result<D::Elem, InterpolateError> {
+        self.interpolate(data, point)
+    }
+
+    /// Analytic derivative `df/dx` of the interpolant at `point` (after handling [`Extrapolate`] setting).
+    ///
+    /// Used by [`Interp1D::gradient`](crate::interpolator::Interp1D::gradient).
+    /// Where the interpolant has a kink, e.g. at interior grid points for [`Linear`],
+    /// the derivative of the segment below is returned.
+    /// Defaults to an error, for strategies without an analytic derivative.
+    fn derivative(
+        &self,
+        _data: &InterpData1D<D>,
+        _point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        Err(InterpolateError::Other(
+            "derivative is not implemented for this strategy".into(),
+        ))
+    }
+
+    /// Analytic definite integral of the interpolant from `lower` to `upper`, where `lower <= upper`.
+    ///
+    /// Used by [`Interp1D::integrate`](crate::interpolator::Interp1D::integrate),
+    /// which handles the [`Extrapolate`] setting, so bounds lie beyond the grid only
+    /// if the strategy's own extrapolation is to be integrated.
+    /// Defaults to an error, for strategies without an analytic integral.
+    fn integral(
+        &self,
+        _data: &InterpData1D<D>,
+        _lower: D::Elem,
+        _upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        Err(InterpolateError::Other(
+            "integral is not implemented for this strategy".into(),
+        ))
+    }
+
+    /// Remap any grid indices held by the strategy (e.g. [`StaircaseLinear::breakpoints`])
+    /// for the reversal of a strictly decreasing grid of `len` points at construction
+    /// (see [`InterpData1D::new`]), so that they keep referring to the grid as supplied.
+    ///
+    /// Used by [`Interp1D::new`](crate::interpolator::Interp1D::new), before [`Strategy1D::init`].
+    /// Defaults to doing nothing, for strategies without grid indices.
+    fn reverse_indices(&mut self, _len: usize) {}
+
+    /// Remap any grid indices held by the strategy (e.g. [`StaircaseLinear::breakpoints`])
+    /// for the removal of grid points, keeping those at the sorted indices `kept`,
+    /// so that they keep referring to the same grid points.
+    ///
+    /// Used by [`Interp1D::retain`](crate::interpolator::Interp1D::retain), before [`Strategy1D::init`].
+    /// Defaults to doing nothing, for strategies without grid indices.
+    fn retain_indices(&mut self, _kept: &[usize]) {}
+
     /// Does this type's [`Strategy1D::interpolate`] provision for extrapolation?
     fn allow_extrapolate(&self) -> bool;
+
+    /// Has this strategy been initialized (see [`Strategy1D::init`])?
+    ///
+    /// Interpolators return [`InterpolateError::Uninitialized`] if this is `false`.
+    /// Defaults to `true`, for strategies that do not require initialization.
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    /// Smoothness class of the interpolant produced by this strategy:
+    /// - `0`: discontinuous
+    /// - `1`: continuous (C0)
+    /// - `2`: continuously differentiable (C1) or smoother
+    ///
+    /// Defaults to `0`, assuming no continuity.
+    fn continuity(&self) -> u8 {
+        0
+    }
+
+    /// This strategy as [`Any`](std::any::Any), for downcasting a boxed strategy to its concrete type,
+    /// e.g. to convert it into a [`Strategy1DEnum`](crate::strategy::enums::Strategy1DEnum).
+    ///
+    /// Defaults to `None`. Strategies available as enum variants return `Some`.
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        None
+    }
 }
 
 clone_trait_object!(<D> Strategy1D<D>);
 
 impl<D> Strategy1D<D> for Box<dyn Strategy1D<D>>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Initialize strategy struct, with access to interpolation data.
@@ -76,16 +246,79 @@ where
         (**self).interpolate(data, point)
     }
 
+    #[inline]
+    fn interpolate_batch(
+        &self,
+        data: &InterpData1D<D>,
+        points: &ArrayView1<D::Elem>,
+    ) -> Result<Array1<D::Elem>, InterpolateError> {
+        (**self).interpolate_batch(data, points)
+    }
+
+    #[inline]
+    fn interpolate_bracketed(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+        bracket: (usize, D::Elem),
+    ) -> Result<D::Elem, InterpolateError> {
+        (**self).interpolate_bracketed(data, point, bracket)
+    }
+
+    #[inline]
+    fn derivative(
+        &self,
+        data: &InterpData1D<D>,
+        point: &[D::Elem; 1],
+    ) -> Result<D::Elem, InterpolateError> {
+        (**self).derivative(data, point)
+    }
+
+    #[inline]
+    fn integral(
+        &self,
+        data: &InterpData1D<D>,
+        lower: D::Elem,
+        upper: D::Elem,
+    ) -> Result<D::Elem, InterpolateError> {
+        (**self).integral(data, lower, upper)
+    }
+
+    #[inline]
+    fn reverse_indices(&mut self, len: usize) {
+        (**self).reverse_indices(len)
+    }
+
+    #[inline]
+    fn retain_indices(&mut self, kept: &[usize]) {
+        (**self).retain_indices(kept)
+    }
+
     #[inline]
     fn allow_extrapolate(&self) -> bool {
         (**self).allow_extrapolate()
     }
+
+    #[inline]
+    fn is_initialized(&self) -> bool {
+        (**self).is_initialized()
+    }
+
+    #[inline]
+    fn continuity(&self) -> u8 {
+        (**self).continuity()
+    }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        (**self).as_any()
+    }
 }
 
 /// 2-D interpolation strategy.
 pub trait Strategy2D<D>: Debug + DynClone
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Initialize strategy struct, with access to interpolation data.
@@ -100,15 +333,38 @@ where
         point: &[D::Elem; 2],
     ) -> Result<D::Elem, InterpolateError>;
 
+    /// Swap any axis indices held by the strategy (e.g. [`Bicubic::periodic`](crate::strategy::Bicubic::periodic)),
+    /// so that they keep referring to the same axes of transposed data.
+    ///
+    /// Used by [`Interp2D::transpose`](crate::interpolator::Interp2D::transpose), before [`Strategy2D::init`].
+    /// Defaults to doing nothing, for strategies without axis indices.
+    fn transpose_axes(&mut self) {}
+
     /// Does this type's [`Strategy2D::interpolate`] provision for extrapolation?
     fn allow_extrapolate(&self) -> bool;
+
+    /// Has this strategy been initialized (see [`Strategy2D::init`])?
+    ///
+    /// Interpolators return [`InterpolateError::Uninitialized`] if this is `false`.
+    /// Defaults to `true`, for strategies that do not require initialization.
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    /// This strategy as [`Any`](std::any::Any), for downcasting a boxed strategy to its concrete type,
+    /// e.g. to convert it into a [`Strategy2DEnum`](crate::strategy::enums::Strategy2DEnum).
+    ///
+    /// Defaults to `None`. Strategies available as enum variants return `Some`.
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        None
+    }
 }
 
 clone_trait_object!(<D> Strategy2D<D>);
 
 impl<D> Strategy2D<D> for Box<dyn Strategy2D<D>>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Initialize strategy struct, with access to interpolation data.
@@ -126,16 +382,31 @@ where
         (**self).interpolate(data, point)
     }
 
+    #[inline]
+    fn transpose_axes(&mut self) {
+        (**self).transpose_axes()
+    }
+
     #[inline]
     fn allow_extrapolate(&self) -> bool {
         (**self).allow_extrapolate()
     }
+
+    #[inline]
+    fn is_initialized(&self) -> bool {
+        (**self).is_initialized()
+    }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        (**self).as_any()
+    }
 }
 
 /// 3-D interpolation strategy.
 pub trait Strategy3D<D>: Debug + DynClone
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Initialize strategy struct, with access to interpolation data.
@@ -152,13 +423,29 @@ where
 
     /// Does this type's [`Strategy3D::interpolate`] provision for extrapolation?
     fn allow_extrapolate(&self) -> bool;
+
+    /// Has this strategy been initialized (see [`Strategy3D::init`])?
+    ///
+    /// Interpolators return [`InterpolateError::Uninitialized`] if this is `false`.
+    /// Defaults to `true`, for strategies that do not require initialization.
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    /// This strategy as [`Any`](std::any::Any), for downcasting a boxed strategy to its concrete type,
+    /// e.g. to convert it into a [`Strategy3DEnum`](crate::strategy::enums::Strategy3DEnum).
+    ///
+    /// Defaults to `None`. Strategies available as enum variants return `Some`.
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        None
+    }
 }
 
 clone_trait_object!(<D> Strategy3D<D>);
 
 impl<D> Strategy3D<D> for Box<dyn Strategy3D<D>>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Initialize strategy struct, with access to interpolation data.
@@ -180,12 +467,22 @@ where
     fn allow_extrapolate(&self) -> bool {
         (**self).allow_extrapolate()
     }
+
+    #[inline]
+    fn is_initialized(&self) -> bool {
+        (**self).is_initialized()
+    }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        (**self).as_any()
+    }
 }
 
 /// N-D interpolation strategy.
 pub trait StrategyND<D>: Debug + DynClone
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     /// Initialize strategy struct, with access to interpolation data.
@@ -200,15 +497,47 @@ where
         point: &[D::Elem],
     ) -> Result<D::Elem, InterpolateError>;
 
+    /// Execute interpolation (after handling [`Extrapolate`] setting),
+    /// reusing the lower bracket indices in `hint` from a previous call where possible.
+    ///
+    /// `hint` holds one lower index per dimension and is updated in place.
+    /// Used by [`InterpND::interpolate_path`](crate::interpolator::InterpND::interpolate_path)
+    /// to exploit locality between consecutive points.
+    /// Defaults to ignoring the hint and calling [`StrategyND::interpolate`].
+    fn interpolate_with_hint(
+        &self,
+        data: &InterpDataND<D>,
+        point: &[D::Elem],
+        _hint: &mut [usize],
+    ) -> Result<D::Elem, InterpolateError> {
+        self.interpolate(data, point)
+    }
+
     /// Does this type's [`StrategyND::interpolate`] provision for extrapolation?
     fn allow_extrapolate(&self) -> bool;
+
+    /// Has this strategy been initialized (see [`StrategyND::init`])?
+    ///
+    /// Interpolators return [`InterpolateError::Uninitialized`] if this is `false`.
+    /// Defaults to `true`, for strategies that do not require initialization.
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    /// This strategy as [`Any`](std::any::Any), for downcasting a boxed strategy to its concrete type,
+    /// e.g. to convert it into a [`StrategyNDEnum`](crate::strategy::enums::StrategyNDEnum).
+    ///
+    /// Defaults to `None`. Strategies available as enum variants return `Some`.
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        None
+    }
 }
 
 clone_trait_object!(<D> StrategyND<D>);
 
 impl<D> StrategyND<D> for Box<dyn StrategyND<D>>
 where
-    D: Data + RawDataClone + Clone,
+    D: Data + RawDataClone,
     D::Elem: PartialEq + Debug,
 {
     #[inline]
@@ -225,8 +554,28 @@ where
         (**self).interpolate(data, point)
     }
 
+    #[inline]
+    fn interpolate_with_hint(
+        &self,
+        data: &InterpDataND<D>,
+        point: &[D::Elem],
+        hint: &mut [usize],
+    ) -> Result<D::Elem, InterpolateError> {
+        (**self).interpolate_with_hint(data, point, hint)
+    }
+
     #[inline]
     fn allow_extrapolate(&self) -> bool {
         (**self).allow_extrapolate()
     }
+
+    #[inline]
+    fn is_initialized(&self) -> bool {
+        (**self).is_initialized()
+    }
+
+    #[inline]
+    fn as_any(&self) -> Option<&dyn std::any::Any> {
+        (**self).as_any()
+    }
 }